@@ -64,6 +64,26 @@ pub enum CustomIconName {
     Binary,
     ALargeSmall,
     ListChecvronsDownUp,
+    Radio,
+    Braces,
+    Layers,
+    LocateFixed,
+    SlidersHorizontal,
+    Unplug,
+    FunnelX,
+    Funnel,
+    Sigma,
+    FileDown,
+    FileUp,
+    CopyPlus,
+    SquareTerminal,
+    Gauge,
+    HardDriveDownload,
+    HardDriveUpload,
+    History,
+    ArrowDownToLine,
+    FileDiff,
+    Scissors,
 }
 
 impl CustomIconName {
@@ -93,6 +113,26 @@ impl CustomIconName {
             CustomIconName::Binary => "icons/binary.svg",
             CustomIconName::ALargeSmall => "icons/a-large-small.svg",
             CustomIconName::ListChecvronsDownUp => "icons/list-chevrons-down-up.svg",
+            CustomIconName::Radio => "icons/radio.svg",
+            CustomIconName::Braces => "icons/braces.svg",
+            CustomIconName::Layers => "icons/layers.svg",
+            CustomIconName::LocateFixed => "icons/locate-fixed.svg",
+            CustomIconName::SlidersHorizontal => "icons/sliders-horizontal.svg",
+            CustomIconName::Unplug => "icons/unplug.svg",
+            CustomIconName::FunnelX => "icons/funnel-x.svg",
+            CustomIconName::Funnel => "icons/funnel.svg",
+            CustomIconName::Sigma => "icons/sigma.svg",
+            CustomIconName::FileDown => "icons/file-down.svg",
+            CustomIconName::FileUp => "icons/file-up.svg",
+            CustomIconName::CopyPlus => "icons/copy-plus.svg",
+            CustomIconName::SquareTerminal => "icons/square-terminal.svg",
+            CustomIconName::Gauge => "icons/gauge.svg",
+            CustomIconName::HardDriveDownload => "icons/hard-drive-download.svg",
+            CustomIconName::HardDriveUpload => "icons/hard-drive-upload.svg",
+            CustomIconName::History => "icons/history.svg",
+            CustomIconName::ArrowDownToLine => "icons/arrow-down-to-line.svg",
+            CustomIconName::FileDiff => "icons/file-diff.svg",
+            CustomIconName::Scissors => "icons/scissors.svg",
         }
         .into()
     }
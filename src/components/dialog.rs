@@ -59,8 +59,12 @@ pub struct FormField {
     label: SharedString,
     /// Placeholder of the field.
     placeholder: SharedString,
+    /// Initial value of the field.
+    value: SharedString,
     /// Whether to focus the field when the dialog opens.
     focus: bool,
+    /// Whether to mask the input, e.g. for passwords.
+    masked: bool,
     /// Options of the field.
     options: Option<Vec<SharedString>>,
     /// Handler to validate the field.
@@ -80,11 +84,21 @@ impl FormField {
         self.focus = true;
         self
     }
+    /// Masks the input text, with a toggle to show it.
+    pub fn with_masked(mut self) -> Self {
+        self.masked = true;
+        self
+    }
     /// Sets a placeholder text for input fields.
     pub fn with_placeholder(mut self, placeholder: SharedString) -> Self {
         self.placeholder = placeholder;
         self
     }
+    /// Sets an initial value for input fields.
+    pub fn with_value(mut self, value: SharedString) -> Self {
+        self.value = value;
+        self
+    }
     /// Configures the field as a RadioGroup with the provided options.
     pub fn with_options(mut self, options: Vec<SharedString>) -> Self {
         self.field_type = FormFieldType::RadioGroup;
@@ -132,6 +146,8 @@ pub fn open_add_form_dialog(params: FormDialog, window: &mut Window, cx: &mut Ap
                     InputState::new(window, cx)
                         .clean_on_escape()
                         .placeholder(field.placeholder.clone())
+                        .default_value(field.value.clone())
+                        .masked(field.masked)
                         .validate(move |s, _| validator.as_ref().is_none_or(|v| v(s)))
                 });
 
@@ -187,11 +203,12 @@ pub fn open_add_form_dialog(params: FormDialog, window: &mut Window, cx: &mut Ap
                                 let entity = entity.clone();
                                 entity.update(cx, |this, cx| this.focus(window, cx));
                             }
-                            form = form.child(
-                                field()
-                                    .label(def.label.clone())
-                                    .child(Input::new(entity).cleanable(true)),
-                            );
+                            let input = Input::new(entity).cleanable(true);
+                            form = form.child(field().label(def.label.clone()).child(if def.masked {
+                                input.mask_toggle()
+                            } else {
+                                input
+                            }));
                         }
                         (FieldState::Radio(cell), FormFieldType::RadioGroup) => {
                             let cell = cell.clone();
@@ -13,22 +13,34 @@
 // limitations under the License.
 
 use crate::assets::CustomIconName;
-use crate::states::{RedisValue, ZedisGlobalStore, ZedisServerState, i18n_common};
+use crate::helpers::get_font_family;
+use crate::states::{RedisValue, SortOrder, ZedisGlobalStore, ZedisServerState, i18n_common, i18n_kv_table};
 use crate::views::{KvTableColumn, KvTableColumnType};
-use gpui::{App, Edges, Entity, SharedString, Window, div, prelude::*, px};
+use gpui::{App, ClipboardItem, Edges, Entity, SharedString, Window, div, prelude::*, px};
 use gpui_component::{
     ActiveTheme, Disableable, Icon, IconName, Sizable, StyledExt, WindowExt,
     button::{Button, ButtonVariants},
     h_flex,
-    input::{Input, InputState},
+    input::{Input, InputState, TabSize},
     label::Label,
+    notification::Notification,
     table::{Column, TableDelegate, TableState},
 };
 use rust_i18n::t;
-use std::{cell::Cell, collections::HashMap, rc::Rc, sync::Arc};
+use std::{
+    cell::Cell,
+    collections::{BTreeMap, HashMap},
+    rc::Rc,
+    sync::Arc,
+};
 
 pub const INDEX_COLUMN_NAME: &str = "#";
 
+// Constants for the JSON editor dialog
+const JSON_EDITOR_DIALOG_WIDTH: f32 = 800.0;
+const JSON_EDITOR_HEIGHT: f32 = 480.0;
+const JSON_EDITOR_TAB_SIZE: usize = 2;
+
 /// Trait defining the data fetching and manipulation interface for Key-Value data.
 /// Implementers allow the `ZedisKvDelegate` to display and edit various Redis data types (Hash, Set, List, ZSet).
 pub trait ZedisKvFetcher: 'static {
@@ -79,10 +91,122 @@ pub trait ZedisKvFetcher: 'static {
     /// Updates values for a specific row.
     fn handle_update_value(&self, _row_ix: usize, _values: Vec<SharedString>, _window: &mut Window, _cx: &mut App) {}
 
+    /// Returns whether edits of several rows can be staged and saved at once.
+    fn can_batch_update(&self) -> bool {
+        false
+    }
+
+    /// Saves the staged edits of several rows with a single command.
+    ///
+    /// Each row is identified by the original value of its first value column (e.g. the hash field).
+    fn handle_batch_update_values(
+        &self,
+        _rows: Vec<(SharedString, Vec<SharedString>)>,
+        _window: &mut Window,
+        _cx: &mut App,
+    ) {
+    }
+
+    /// Returns whether a value can be inserted next to a row.
+    fn can_insert(&self) -> bool {
+        false
+    }
+
+    /// Opens a dialog to insert a new value next to a specific row.
+    fn handle_insert_value(&self, _row_ix: usize, _window: &mut Window, _cx: &mut App) {}
+
+    /// Returns whether the value can be trimmed to a range of rows.
+    fn can_trim(&self) -> bool {
+        false
+    }
+
+    /// Opens a dialog to trim the value to a range of rows.
+    fn handle_trim(&self, _window: &mut Window, _cx: &mut App) {}
+
+    /// Returns the pretty-printed JSON of a row if it can be opened in the JSON editor.
+    fn json_value(&self, _row_ix: usize) -> Option<SharedString> {
+        None
+    }
+
+    /// Saves the value edited in the JSON editor for a specific row.
+    fn handle_update_json_value(&self, _row_ix: usize, _value: SharedString, _window: &mut Window, _cx: &mut App) {}
+
+    /// Returns the field name of a row if it can be copied on its own.
+    fn copy_field(&self, _row_ix: usize) -> Option<SharedString> {
+        None
+    }
+
+    /// Returns the value of a row if it can be copied on its own.
+    fn copy_value(&self, _row_ix: usize) -> Option<SharedString> {
+        None
+    }
+
+    /// Returns the sort order of the values if they can be sorted.
+    fn sort_order(&self) -> Option<SortOrder> {
+        None
+    }
+
+    /// Switches the sort order of the values between ascending and descending.
+    fn toggle_sort_order(&self, _cx: &mut App) {}
+
     /// Factory method to create a new instance.
     fn new(server_state: Entity<ZedisServerState>, value: RedisValue) -> Self;
 }
 
+/// Opens a dialog with a full JSON editor for the value of a row.
+///
+/// The edited text must parse as JSON, it is saved back in compact form
+/// through `ZedisKvFetcher::handle_update_json_value`.
+fn open_json_editor_dialog<T: ZedisKvFetcher>(
+    row_ix: usize,
+    json: SharedString,
+    fetcher: Arc<T>,
+    window: &mut Window,
+    cx: &mut App,
+) {
+    let editor = cx.new(|cx| {
+        InputState::new(window, cx)
+            .code_editor("json")
+            .line_number(true)
+            .indent_guides(true)
+            .tab_size(TabSize {
+                tab_size: JSON_EDITOR_TAB_SIZE,
+                hard_tabs: false,
+            })
+            .searchable(true)
+            .default_value(json)
+    });
+    editor.update(cx, |state, cx| state.focus(window, cx));
+    let title = i18n_kv_table(cx, "edit_json_title");
+
+    window.open_dialog(cx, move |dialog, _window, _cx| {
+        let fetcher = fetcher.clone();
+        let editor_for_ok = editor.clone();
+        dialog
+            .title(title.clone())
+            .w(px(JSON_EDITOR_DIALOG_WIDTH))
+            .confirm()
+            .child(
+                div().h(px(JSON_EDITOR_HEIGHT)).child(
+                    Input::new(&editor)
+                        .h_full()
+                        .font_family(get_font_family())
+                        .focus_bordered(false),
+                ),
+            )
+            .on_ok(move |_, window, cx| {
+                let value = editor_for_ok.read(cx).value();
+                // Keep the dialog open until the value is valid JSON
+                let Ok(json) = serde_json::from_str::<serde_json::Value>(&value) else {
+                    window.push_notification(Notification::error(i18n_kv_table(cx, "invalid_json")), cx);
+                    return false;
+                };
+                fetcher.handle_update_json_value(row_ix, json.to_string().into(), window, cx);
+                true
+            })
+    });
+}
+
 /// A Table Delegate that manages the display and editing of Key-Value pairs.
 /// It bridges the UI (Table) and the Data Source (ZedisKvFetcher).
 pub struct ZedisKvDelegate<T: ZedisKvFetcher> {
@@ -100,6 +224,11 @@ pub struct ZedisKvDelegate<T: ZedisKvFetcher> {
     value_states: HashMap<usize, Entity<InputState>>,
     /// Flag to ensure focus is applied only once when entering edit mode.
     edit_focus_done: bool,
+    /// Whether row edits are staged instead of being saved one by one.
+    batch_mode: bool,
+    /// Staged values of the edited rows, keyed by the original value of the first value column,
+    /// so the edits still match their rows after the rows are reloaded or filtered.
+    staged_rows: BTreeMap<SharedString, Vec<SharedString>>,
 }
 
 impl<T: ZedisKvFetcher> ZedisKvDelegate<T> {
@@ -149,6 +278,8 @@ impl<T: ZedisKvFetcher> ZedisKvDelegate<T> {
             processing: Rc::new(Cell::new(false)),
             editing_row: Cell::new(None),
             edit_focus_done: false,
+            batch_mode: false,
+            staged_rows: BTreeMap::new(),
         }
     }
 
@@ -164,6 +295,52 @@ impl<T: ZedisKvFetcher> ZedisKvDelegate<T> {
         self.processing = Rc::new(Cell::new(false));
     }
 
+    /// Returns whether row edits are staged instead of being saved one by one.
+    pub fn batch_mode(&self) -> bool {
+        self.batch_mode
+    }
+
+    /// Switches the batch edit mode, leaving it discards the staged edits.
+    pub fn set_batch_mode(&mut self, batch_mode: bool) {
+        self.batch_mode = batch_mode;
+        if !batch_mode {
+            self.staged_rows.clear();
+        }
+    }
+
+    /// Returns the number of rows with staged edits.
+    pub fn staged_count(&self) -> usize {
+        self.staged_rows.len()
+    }
+
+    /// Drops the staged edits, e.g. when the value is reloaded or the key changes.
+    pub fn discard_staged(&mut self) {
+        self.staged_rows.clear();
+    }
+
+    /// Saves all staged edits at once through the fetcher.
+    pub fn save_staged(&mut self, window: &mut Window, cx: &mut App) {
+        let rows: Vec<(SharedString, Vec<SharedString>)> = std::mem::take(&mut self.staged_rows).into_iter().collect();
+        if rows.is_empty() {
+            return;
+        }
+        self.fetcher().handle_batch_update_values(rows, window, cx);
+    }
+
+    /// Returns the staged value of a cell, the position of the column among the value columns
+    /// matches the order the values are collected in `handle_update_row`.
+    fn staged_value(&self, row_ix: usize, col_ix: usize) -> Option<SharedString> {
+        let values = self.staged_rows.get(&self.row_id(row_ix)?)?;
+        let position = self.value_states.keys().filter(|ix| **ix < col_ix).count();
+        values.get(position).cloned()
+    }
+
+    /// Returns the value of the first value column of the row, which identifies the staged edits.
+    fn row_id(&self, row_ix: usize) -> Option<SharedString> {
+        let col_ix = self.value_states.keys().min()?;
+        self.fetcher.get(row_ix, *col_ix)
+    }
+
     /// Exits edit mode and resets related state flags.
     fn reset_edit(&mut self) {
         self.edit_focus_done = false;
@@ -181,7 +358,10 @@ impl<T: ZedisKvFetcher> ZedisKvDelegate<T> {
         // Populate input fields with current values from fetcher
         let fetcher = self.fetcher();
         for (col_ix, state) in &self.value_states {
-            if let Some(value) = fetcher.get(row_ix, *col_ix) {
+            if let Some(value) = self
+                .staged_value(row_ix, *col_ix)
+                .or_else(|| fetcher.get(row_ix, *col_ix))
+            {
                 state.update(cx, |input, cx| input.set_value(value, window, cx));
             }
         }
@@ -205,6 +385,14 @@ impl<T: ZedisKvFetcher> ZedisKvDelegate<T> {
                 .collect()
         };
 
+        // Keep the edit until the staged rows are saved together
+        if self.batch_mode {
+            if let Some(id) = self.row_id(row_ix) {
+                self.staged_rows.insert(id, values);
+            }
+            return;
+        }
+
         self.fetcher().handle_update_value(row_ix, values, window, cx);
     }
 
@@ -257,6 +445,75 @@ impl<T: ZedisKvFetcher> ZedisKvDelegate<T> {
             base = base.child(update_btn);
         }
 
+        // JSON editor button (only shown if the value parses as JSON)
+        if !is_editing && let Some(json) = self.fetcher.json_value(row_ix) {
+            let fetcher = self.fetcher.clone();
+            let json_btn = Button::new(("zedis-editor-table-action-json-btn", row_ix))
+                .small()
+                .ghost()
+                .mr_2()
+                .icon(Icon::new(CustomIconName::Braces))
+                .tooltip(i18n_kv_table(cx, "edit_json_tooltip"))
+                .disabled(processing.get())
+                .on_click(cx.listener(move |_, _, window, cx| {
+                    cx.stop_propagation();
+                    open_json_editor_dialog(row_ix, json.clone(), fetcher.clone(), window, cx);
+                }));
+            base = base.child(json_btn);
+        }
+
+        // Insert button (only shown if fetcher supports inserting next to a row)
+        if !is_editing && self.fetcher.can_insert() {
+            let fetcher = self.fetcher.clone();
+            let insert_btn = Button::new(("zedis-editor-table-action-insert-btn", row_ix))
+                .small()
+                .ghost()
+                .mr_2()
+                .icon(Icon::new(IconName::Plus))
+                .tooltip(i18n_kv_table(cx, "insert_value_tooltip"))
+                .disabled(processing.get())
+                .on_click(cx.listener(move |_, _, window, cx| {
+                    cx.stop_propagation();
+                    fetcher.handle_insert_value(row_ix, window, cx);
+                }));
+            base = base.child(insert_btn);
+        }
+
+        // Copy field name/value buttons
+        if !is_editing {
+            let copy_items = [
+                (
+                    "zedis-editor-table-action-copy-field-btn",
+                    Icon::new(CustomIconName::Key),
+                    "copy_field_tooltip",
+                    self.fetcher.copy_field(row_ix),
+                ),
+                (
+                    "zedis-editor-table-action-copy-value-btn",
+                    Icon::new(IconName::Copy),
+                    "copy_value_tooltip",
+                    self.fetcher.copy_value(row_ix),
+                ),
+            ];
+            for (id, icon, tooltip, content) in copy_items {
+                let Some(content) = content else {
+                    continue;
+                };
+                let copy_btn = Button::new((id, row_ix))
+                    .small()
+                    .ghost()
+                    .mr_2()
+                    .icon(icon)
+                    .tooltip(i18n_kv_table(cx, tooltip))
+                    .on_click(cx.listener(move |_, _, window, cx| {
+                        cx.stop_propagation();
+                        cx.write_to_clipboard(ClipboardItem::new_string(content.to_string()));
+                        window.push_notification(Notification::info(i18n_kv_table(cx, "copied_to_clipboard")), cx);
+                    }));
+                base = base.child(copy_btn);
+            }
+        }
+
         // Cancel/Delete button
         if is_editing {
             // Cancel button (exits edit mode without saving)
@@ -390,6 +647,15 @@ impl<T: ZedisKvFetcher + 'static> TableDelegate for ZedisKvDelegate<T> {
             return base.child(Input::new(value_state).small().cleanable(true));
         }
 
+        // Staged edits are highlighted until they are saved
+        if let Some(value) = self.staged_value(row_ix, col_ix) {
+            return base.child(
+                Label::new(value)
+                    .text_align(column.align)
+                    .text_color(cx.theme().warning),
+            );
+        }
+
         // Default: Render value as label
         let value = self.fetcher.get(row_ix, col_ix).unwrap_or_else(|| "--".into());
         base.child(Label::new(value).text_align(column.align))
@@ -12,14 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::manager::key_hash_slot;
 use crate::error::Error;
+use dashmap::DashMap;
 use futures::future::try_join_all;
 use redis::{
-    Client, Cmd, FromRedisValue, Pipeline, RedisFuture, Value,
+    Arg, Client, Cmd, FromRedisValue, Pipeline, RedisFuture, Value,
     aio::{ConnectionLike, MultiplexedConnection},
     cluster_async::ClusterConnection,
 };
-use std::{sync::LazyLock, time::Duration};
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, LazyLock},
+    time::Duration,
+};
 
 type Result<T, E = Error> = std::result::Result<T, E>;
 
@@ -28,15 +34,170 @@ static DELAY: LazyLock<Option<Duration>> = LazyLock::new(|| {
     humantime::parse_duration(&value).ok()
 });
 
+// Commands taking several keys, split by shard and the integer replies summed up
+const MULTI_KEY_COMMANDS: [&[u8]; 4] = [b"DEL", b"UNLINK", b"EXISTS", b"TOUCH"];
+// Commands creating their second key on the shard of the first one
+const RENAME_COMMANDS: [&[u8]; 3] = [b"RENAME", b"RENAMENX", b"COPY"];
+// Commands without a key sent to every shard, e.g. SCRIPT LOAD before EVALSHA on any shard
+const BROADCAST_COMMANDS: [&[u8]; 1] = [b"SCRIPT"];
+
+/// Returns the first key of the command, None if it has no argument.
+///
+/// Subcommands like `OBJECT ENCODING key` and scripts with keys are handled,
+/// the other commands are expected to have the key as first argument.
+fn command_key(cmd: &Cmd) -> Option<&[u8]> {
+    let args: Vec<&[u8]> = cmd
+        .args_iter()
+        .filter_map(|arg| match arg {
+            Arg::Simple(arg) => Some(arg),
+            Arg::Cursor => None,
+        })
+        .collect();
+    let name = args.first()?.to_ascii_uppercase();
+    let index = match name.as_slice() {
+        b"OBJECT" | b"MEMORY" | b"XINFO" => 2,
+        b"EVAL" | b"EVALSHA" => {
+            let numkeys = std::str::from_utf8(args.get(2)?).ok()?.parse::<usize>().ok()?;
+            if numkeys == 0 {
+                return None;
+            }
+            3
+        }
+        _ => 1,
+    };
+    args.get(index).copied()
+}
+
+/// How a `ShardedConnection` finds the shard of a key
+#[derive(Clone)]
+pub enum ShardRouting {
+    /// The shard the key has been scanned from, keys not scanned yet (e.g. new keys) use the first shard
+    Scanned(Arc<DashMap<Vec<u8>, usize>>),
+    /// The shard serving the hash slot of the key, as (first slot, last slot, shard index)
+    Slots(Arc<Vec<(u16, u16, usize)>>),
+}
+
+/// Connections to several masters, each key lives on one of them.
+///
+/// Used for a sentinel browsing several masters as shards, and for a cluster
+/// reached through a proxy or an SSH tunnel, where the redirects of the cluster
+/// client would connect to the nodes directly.
+#[derive(Clone)]
+pub struct ShardedConnection {
+    shards: Vec<MultiplexedConnection>,
+    routing: ShardRouting,
+}
+
+impl ShardedConnection {
+    /// Creates the connection, the shards are in the order of the routing indexes.
+    pub fn new(shards: Vec<MultiplexedConnection>, routing: ShardRouting) -> Self {
+        Self { shards, routing }
+    }
+    /// Returns the index of the shard serving the key.
+    fn shard_index(&self, key: Option<&[u8]>) -> usize {
+        key.and_then(|key| match &self.routing {
+            ShardRouting::Scanned(key_shards) => key_shards.get(key).map(|index| *index),
+            ShardRouting::Slots(slots) => {
+                let slot = key_hash_slot(key);
+                slots
+                    .iter()
+                    .find(|(start, end, _)| (*start..=*end).contains(&slot))
+                    .map(|(_, _, index)| *index)
+            }
+        })
+        .filter(|index| *index < self.shards.len())
+        .unwrap_or_default()
+    }
+    /// Splits a multi-key command by shard, None if all keys are on the same shard.
+    fn split_multi_key_command(&self, cmd: &Cmd) -> Option<Vec<(usize, Cmd)>> {
+        let mut args = cmd.args_iter().filter_map(|arg| match arg {
+            Arg::Simple(arg) => Some(arg),
+            Arg::Cursor => None,
+        });
+        let name = args.next()?;
+        if !MULTI_KEY_COMMANDS.iter().any(|item| name.eq_ignore_ascii_case(item)) {
+            return None;
+        }
+        let mut groups: BTreeMap<usize, Cmd> = BTreeMap::new();
+        for key in args {
+            groups
+                .entry(self.shard_index(Some(key)))
+                .or_insert_with(|| redis::cmd(&String::from_utf8_lossy(name)))
+                .arg(key);
+        }
+        (groups.len() > 1).then(|| groups.into_iter().collect())
+    }
+    fn req_packed_command<'a>(&'a mut self, cmd: &'a Cmd) -> RedisFuture<'a, Value> {
+        if let Some(groups) = self.split_multi_key_command(cmd) {
+            let tasks = groups.into_iter().map(|(index, cmd)| {
+                let mut conn = self.shards[index].clone();
+                async move { cmd.query_async::<i64>(&mut conn).await }
+            });
+            return Box::pin(async move {
+                let counts = try_join_all(tasks).await?;
+                Ok(Value::Int(counts.iter().sum()))
+            });
+        }
+        let name = cmd.args_iter().next().and_then(|arg| match arg {
+            Arg::Simple(arg) => Some(arg),
+            Arg::Cursor => None,
+        });
+        if name.is_some_and(|name| BROADCAST_COMMANDS.iter().any(|item| name.eq_ignore_ascii_case(item))) {
+            let tasks = self.shards.iter().map(|conn| {
+                let mut conn = conn.clone();
+                async move { conn.req_packed_command(cmd).await }
+            });
+            return Box::pin(async move {
+                let values = try_join_all(tasks).await?;
+                Ok(values.into_iter().next().unwrap_or(Value::Nil))
+            });
+        }
+        let index = self.shard_index(command_key(cmd));
+        self.track_destination_key(cmd, index);
+        self.shards[index].req_packed_command(cmd)
+    }
+    /// Records the new key of RENAME and COPY, it is created on the shard of the source key.
+    fn track_destination_key(&self, cmd: &Cmd, index: usize) {
+        let ShardRouting::Scanned(key_shards) = &self.routing else {
+            return;
+        };
+        let mut args = cmd.args_iter().filter_map(|arg| match arg {
+            Arg::Simple(arg) => Some(arg),
+            Arg::Cursor => None,
+        });
+        let Some(name) = args.next() else {
+            return;
+        };
+        if !RENAME_COMMANDS.iter().any(|item| name.eq_ignore_ascii_case(item)) {
+            return;
+        }
+        if let Some(destination) = args.nth(1) {
+            key_shards.insert(destination.to_vec(), index);
+        }
+    }
+    fn req_packed_commands<'a>(
+        &'a mut self,
+        cmd: &'a Pipeline,
+        offset: usize,
+        count: usize,
+    ) -> RedisFuture<'a, Vec<Value>> {
+        // A pipeline (or transaction) works on the keys of a single value
+        let index = self.shard_index(cmd.cmd_iter().next().and_then(command_key));
+        self.shards[index].req_packed_commands(cmd, offset, count)
+    }
+}
+
 /// A wrapper enum for Redis asynchronous connections.
 ///
-/// This unifies `MultiplexedConnection` (for single nodes) and
-/// `ClusterConnection` (for clusters) under a single type,
+/// This unifies `MultiplexedConnection` (for single nodes),
+/// `ClusterConnection` (for clusters) and `ShardedConnection`
+/// (for sentinels with several masters) under a single type,
 /// allowing generic usage across the application.
 #[derive(Clone)]
 pub enum RedisAsyncConn {
     Single(MultiplexedConnection),
     Cluster(ClusterConnection),
+    Sharded(ShardedConnection),
 }
 
 impl ConnectionLike for RedisAsyncConn {
@@ -45,6 +206,7 @@ impl ConnectionLike for RedisAsyncConn {
         let cmd_future = match self {
             RedisAsyncConn::Single(conn) => conn.req_packed_command(cmd),
             RedisAsyncConn::Cluster(conn) => conn.req_packed_command(cmd),
+            RedisAsyncConn::Sharded(conn) => conn.req_packed_command(cmd),
         };
         if let Some(delay) = *DELAY {
             return Box::pin(async move {
@@ -64,6 +226,7 @@ impl ConnectionLike for RedisAsyncConn {
         let cmd_future = match self {
             RedisAsyncConn::Single(conn) => conn.req_packed_commands(cmd, offset, count),
             RedisAsyncConn::Cluster(conn) => conn.req_packed_commands(cmd, offset, count),
+            RedisAsyncConn::Sharded(conn) => conn.req_packed_commands(cmd, offset, count),
         };
         if let Some(delay) = *DELAY {
             return Box::pin(async move {
@@ -78,6 +241,7 @@ impl ConnectionLike for RedisAsyncConn {
         match self {
             RedisAsyncConn::Single(conn) => conn.get_db(),
             RedisAsyncConn::Cluster(_) => 0,
+            RedisAsyncConn::Sharded(conn) => conn.shards.first().map_or(0, |conn| conn.get_db()),
         }
     }
 }
@@ -118,3 +282,24 @@ pub(crate) async fn query_async_masters<T: FromRedisValue>(addrs: Vec<&str>, cmd
 
     Ok(values)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use redis::cmd;
+
+    #[test]
+    fn test_command_key() {
+        assert_eq!(command_key(cmd("GET").arg("user:1")), Some(b"user:1".as_slice()));
+        assert_eq!(
+            command_key(cmd("OBJECT").arg("ENCODING").arg("user:1")),
+            Some(b"user:1".as_slice())
+        );
+        assert_eq!(
+            command_key(cmd("EVAL").arg("return 1").arg(1).arg("user:1")),
+            Some(b"user:1".as_slice())
+        );
+        assert_eq!(command_key(cmd("EVAL").arg("return 1").arg(0)), None);
+        assert_eq!(command_key(&cmd("PING")), None);
+    }
+}
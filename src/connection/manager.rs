@@ -13,24 +13,37 @@
 // limitations under the License.
 
 use super::{
-    async_connection::{RedisAsyncConn, query_async_masters},
-    config::get_config,
+    async_connection::{RedisAsyncConn, ShardRouting, ShardedConnection, query_async_masters},
+    config::{RedisServer, get_config},
+    proxy::{close_proxy_forwarders, get_proxy_address},
+    ssh::{SshTunnelConfig, close_ssh_tunnels, get_ssh_tunnel_address},
 };
 use crate::error::Error;
 use dashmap::DashMap;
 use gpui::SharedString;
-use redis::{AsyncConnectionConfig, Client, Cmd, FromRedisValue, InfoDict, Role, cluster, cmd};
+use redis::{
+    AsyncConnectionConfig, Client, Cmd, ErrorKind, FromRedisValue, InfoDict, Role,
+    aio::{MultiplexedConnection, PubSub},
+    cluster, cmd,
+};
 use semver::Version;
+use smol::lock::Mutex;
 use std::{
     collections::{HashMap, HashSet},
-    sync::LazyLock,
-    time::Duration,
+    sync::{Arc, LazyLock},
+    time::{Duration, Instant},
 };
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 use url::Url;
 
 type Result<T, E = Error> = std::result::Result<T, E>;
 
+// Server id used by connection tests of unsaved configs
+const TEST_CONNECTION_ID: &str = "__test_connection__";
+
+// Minimum interval between the sentinel failover checks of a server that answers
+const FAILOVER_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
 // Global singleton for ConnectionManager
 static CONNECTION_MANAGER: LazyLock<ConnectionManager> = LazyLock::new(ConnectionManager::new);
 
@@ -65,6 +78,11 @@ struct RedisNode {
     connection_url: String,
     role: NodeRole,
     master_name: Option<String>,
+    // Slot ranges served by a cluster master
+    slots: Vec<(u16, u16)>,
+    // Cluster node id, and the node id of the master a replica follows
+    node_id: String,
+    master_id: Option<String>,
 }
 
 impl RedisNode {
@@ -84,9 +102,60 @@ impl RedisNode {
 // Information parsed from `CLUSTER NODES` command
 #[derive(Debug, Clone)]
 pub struct ClusterNodeInfo {
+    pub id: String,
+    pub master_id: Option<String>,
     pub ip: String,
     pub port: u16,
     pub role: NodeRole,
+    pub slots: Vec<(u16, u16)>,
+}
+
+// Number of hash slots of a Redis cluster
+const CLUSTER_SLOTS: u16 = 16384;
+
+/// CRC16 (XMODEM) used by Redis cluster to hash keys.
+fn crc16(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0;
+    for byte in data {
+        crc ^= (*byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+/// Computes the cluster hash slot of a key.
+///
+/// Only the hash tag is hashed if the key has a non-empty `{...}`,
+/// so keys like `{user:1}:name` and `{user:1}:age` share a slot.
+pub fn key_hash_slot(key: &[u8]) -> u16 {
+    let tag = key.iter().position(|b| *b == b'{').and_then(|start| {
+        let rest = &key[start + 1..];
+        rest.iter()
+            .position(|b| *b == b'}')
+            .filter(|end| *end > 0)
+            .map(|end| &rest[..end])
+    });
+    crc16(tag.unwrap_or(key)) % CLUSTER_SLOTS
+}
+
+/// Parses the slot ranges of a `CLUSTER NODES` line, e.g. `0-5460 5461`.
+///
+/// Importing and migrating slots (`[slot-<-node]`) are skipped.
+fn parse_slots(parts: &[&str]) -> Vec<(u16, u16)> {
+    parts
+        .iter()
+        .filter(|part| !part.starts_with('['))
+        .filter_map(|part| match part.split_once('-') {
+            Some((start, end)) => Some((start.parse().ok()?, end.parse().ok()?)),
+            None => part.parse().ok().map(|slot| (slot, slot)),
+        })
+        .collect()
 }
 
 /// Parses a Redis address string like "ip:port@cport" or just "ip:port".
@@ -134,53 +203,135 @@ fn parse_cluster_nodes(raw_data: &str) -> Result<Vec<ClusterNodeInfo>> {
 
         // Parse flags to determine role
         let flags: HashSet<String> = parts[2].split(',').map(String::from).collect();
-        let role = if flags.contains("master") {
+        // A failed master keeps its "master" flag, it is not reachable anyway
+        let role = if flags.contains("fail") {
+            NodeRole::Fail
+        } else if flags.contains("master") {
             NodeRole::Master
         } else if flags.contains("slave") {
             NodeRole::Slave
-        } else if flags.contains("fail") {
-            NodeRole::Fail
         } else {
             NodeRole::Unknown
         };
 
-        nodes.push(ClusterNodeInfo { ip, port, role });
+        // A master has "-" instead of the id of its master
+        let master_id = Some(parts[3]).filter(|id| *id != "-").map(String::from);
+        let slots = parse_slots(&parts[8..]);
+        nodes.push(ClusterNodeInfo {
+            id: parts[0].to_string(),
+            master_id,
+            ip,
+            port,
+            role,
+            slots,
+        });
     }
 
     Ok(nodes)
 }
 
-const CONNECTION_TIMEOUT: Duration = Duration::from_secs(30);
-const RESPONSE_TIMEOUT: Duration = Duration::from_secs(60);
+/// Converts a timeout into an error that names the server and the configured timeout.
+fn map_timeout_error(err: redis::RedisError, config: &RedisServer) -> Error {
+    if !err.is_timeout() {
+        return err.into();
+    }
+    Error::Invalid {
+        message: format!(
+            "{} timed out (connect {}ms, response {}ms)",
+            config.address(),
+            config.connect_timeout().as_millis(),
+            config.response_timeout().as_millis()
+        ),
+    }
+}
+
+/// Opens a multiplexed connection with the timeouts of the server.
+async fn get_multiplexed_connection(client: &Client, config: &RedisServer) -> Result<MultiplexedConnection> {
+    let cfg = AsyncConnectionConfig::default()
+        .set_connection_timeout(Some(config.connect_timeout()))
+        .set_response_timeout(Some(config.response_timeout()));
+    client
+        .get_multiplexed_async_connection_with_config(&cfg)
+        .await
+        .map_err(|e| map_timeout_error(e, config))
+}
 
 /// Establishes an asynchronous connection based on the client type.
-async fn get_async_connection(client: &RClient) -> Result<RedisAsyncConn> {
+async fn get_async_connection(client: &RClient, config: &RedisServer) -> Result<RedisAsyncConn> {
     match client {
         RClient::Single(client) => {
-            let cfg = AsyncConnectionConfig::default()
-                .set_connection_timeout(Some(CONNECTION_TIMEOUT))
-                .set_response_timeout(Some(RESPONSE_TIMEOUT));
-            let conn = client.get_multiplexed_async_connection_with_config(&cfg).await?;
+            let conn = get_multiplexed_connection(client, config).await?;
             Ok(RedisAsyncConn::Single(conn))
         }
         RClient::Cluster(client) => {
             let cfg = cluster::ClusterConfig::default()
-                .set_connection_timeout(CONNECTION_TIMEOUT)
-                .set_response_timeout(RESPONSE_TIMEOUT);
-            let conn = client.get_async_connection_with_config(cfg).await?;
+                .set_connection_timeout(config.connect_timeout())
+                .set_response_timeout(config.response_timeout());
+            let conn = client
+                .get_async_connection_with_config(cfg)
+                .await
+                .map_err(|e| map_timeout_error(e, config))?;
             Ok(RedisAsyncConn::Cluster(conn))
         }
     }
 }
 
+/// Connects to every master of a sharded server, see `ShardedConnection`.
+///
+/// Returns the connection for the commands and the one for reading values,
+/// each shard reads from its replica in `read_nodes` if there is one.
+async fn get_sharded_connections(
+    master_nodes: &[RedisNode],
+    read_nodes: &[RedisNode],
+    routing: ShardRouting,
+    config: &RedisServer,
+) -> Result<(RedisAsyncConn, RedisAsyncConn)> {
+    let mut masters = Vec::with_capacity(master_nodes.len());
+    for node in master_nodes {
+        let client = Client::open(node.connection_url.clone())?;
+        masters.push(get_multiplexed_connection(&client, config).await?);
+    }
+    let mut readers = Vec::with_capacity(masters.len());
+    for (node, master) in read_nodes.iter().zip(masters.iter()) {
+        if node.role != NodeRole::Slave {
+            readers.push(master.clone());
+            continue;
+        }
+        let replica_connection = async {
+            let mut conn = get_multiplexed_connection(&Client::open(node.connection_url.clone())?, config).await?;
+            // A cluster replica redirects the reads to its master unless it is in readonly mode
+            if matches!(routing, ShardRouting::Slots(_)) {
+                let _: () = cmd("READONLY").query_async(&mut conn).await?;
+            }
+            Ok::<_, Error>(conn)
+        }
+        .await;
+        readers.push(replica_connection.unwrap_or_else(|e| {
+            warn!(error = %e, replica = node.host_port(), "connect to replica fail, read from master");
+            master.clone()
+        }));
+    }
+    Ok((
+        RedisAsyncConn::Sharded(ShardedConnection::new(masters, routing.clone())),
+        RedisAsyncConn::Sharded(ShardedConnection::new(readers, routing)),
+    ))
+}
+
 // TODO 是否在client中保存connection
 #[derive(Clone)]
 pub struct RedisClient {
     server_type: ServerType,
     nodes: Vec<RedisNode>,
     master_nodes: Vec<RedisNode>,
+    // One node per master to run SCAN on, a replica of it if preferred
+    read_nodes: Vec<RedisNode>,
+    password_ignored: bool,
     version: Version,
     connection: RedisAsyncConn,
+    // Connection for reading values, the same as `connection` if no replica is used
+    read_connection: RedisAsyncConn,
+    // The master index of each scanned key, used to route the commands of a sharded sentinel
+    key_shards: Arc<DashMap<Vec<u8>, usize>>,
 }
 #[derive(Debug, Clone, Default)]
 pub struct RedisClientDescription {
@@ -195,6 +346,18 @@ impl RedisClient {
     pub fn version(&self) -> String {
         self.version.to_string()
     }
+    /// Returns true if the server does not require the configured password.
+    pub fn password_ignored(&self) -> bool {
+        self.password_ignored
+    }
+    /// Returns true if the client is connected to a Redis cluster.
+    pub fn is_cluster(&self) -> bool {
+        self.server_type == ServerType::Cluster
+    }
+    /// Returns true if the client browses several sentinel masters as shards.
+    fn is_sharded(&self) -> bool {
+        self.server_type == ServerType::Sentinel && self.master_nodes.len() > 1
+    }
 
     pub fn nodes_description(&self) -> RedisClientDescription {
         let master_nodes: Vec<String> = self.master_nodes.iter().map(|node| node.host_port()).collect();
@@ -204,8 +367,19 @@ impl RedisClient {
             .filter(|node| !master_nodes.contains(&node.host_port()))
             .map(|node| node.host_port().clone())
             .collect();
+        // Show which master the sentinel resolved to
+        let master_names: Vec<&str> = self
+            .master_nodes
+            .iter()
+            .filter_map(|node| node.master_name.as_deref())
+            .collect();
+        let server_type = if self.server_type == ServerType::Sentinel && !master_names.is_empty() {
+            format!("{:?} ({})", self.server_type, master_names.join(","))
+        } else {
+            format!("{:?}", self.server_type)
+        };
         RedisClientDescription {
-            server_type: format!("{:?}", self.server_type).into(),
+            server_type: server_type.into(),
             master_nodes: master_nodes.join(",").into(),
             slave_nodes: slave_nodes.join(",").into(),
         }
@@ -216,6 +390,13 @@ impl RedisClient {
     pub fn connection(&self) -> RedisAsyncConn {
         self.connection.clone()
     }
+    /// Returns the connection for read-only commands such as TYPE, GET and LRANGE.
+    ///
+    /// It reads from a replica if the server prefers replicas, values may lag
+    /// behind the master slightly. Writes must use `connection`.
+    pub fn read_connection(&self) -> RedisAsyncConn {
+        self.read_connection.clone()
+    }
     /// Checks if the client version is at least the given version.
     /// # Arguments
     /// * `version` - The version to check.
@@ -224,6 +405,45 @@ impl RedisClient {
     pub fn is_at_least_version(&self, version: &str) -> bool {
         self.version >= Version::parse(version).unwrap_or(Version::new(0, 0, 0))
     }
+    /// Checks if SCAN supports the TYPE option (Redis 6.0+).
+    pub fn supports_scan_type(&self) -> bool {
+        self.is_at_least_version("6.0.0")
+    }
+    /// Checks if SET supports the KEEPTTL option (Redis 6.0+).
+    pub fn supports_keepttl(&self) -> bool {
+        self.is_at_least_version("6.0.0")
+    }
+    /// Checks if the UNLINK command is available (Redis 4.0+).
+    pub fn supports_unlink(&self) -> bool {
+        self.is_at_least_version("4.0.0")
+    }
+    /// Checks if HSET accepts multiple field/value pairs (Redis 4.0+), HMSET is used before.
+    pub fn supports_multi_field_hset(&self) -> bool {
+        self.is_at_least_version("4.0.0")
+    }
+    /// Checks if the MEMORY USAGE command is available (Redis 4.0+).
+    pub fn supports_memory_usage(&self) -> bool {
+        self.is_at_least_version("4.0.0")
+    }
+    /// Returns the hash slot of the key and the `host:port` of its master in cluster mode.
+    ///
+    /// The node is None if no master serves the slot, e.g. during resharding.
+    pub fn key_slot_node(&self, key: &[u8]) -> Option<(u16, Option<String>)> {
+        if !self.is_cluster() {
+            return None;
+        }
+        let slot = key_hash_slot(key);
+        let node = self
+            .master_nodes
+            .iter()
+            .find(|node| node.slots.iter().any(|(start, end)| (*start..=*end).contains(&slot)))
+            .map(|node| node.host_port());
+        Some((slot, node))
+    }
+    /// Checks if the COPY command is available (Redis 6.2+).
+    pub fn supports_copy(&self) -> bool {
+        self.is_at_least_version("6.2.0")
+    }
 
     /// Executes commands on all master nodes concurrently.
     /// # Arguments
@@ -246,29 +466,32 @@ impl RedisClient {
         let list = self.query_async_masters(vec![cmd("DBSIZE")]).await?;
         Ok(list.iter().sum())
     }
-    /// Pings the server to check connectivity.
-    pub async fn ping(&self) -> Result<()> {
-        let mut conn = self.connection.clone();
-        let _: () = cmd("PING").query_async(&mut conn).await?;
-        Ok(())
-    }
     /// Returns the number of master nodes.
     /// # Returns
-    /// * `usize` - The number of master nodes.
+    /// * `usize` - The number of master nodes, an error if no master is reachable.
     pub fn count_masters(&self) -> Result<usize> {
+        if self.master_nodes.is_empty() {
+            return Err(no_reachable_master_error());
+        }
         Ok(self.master_nodes.len())
     }
     /// Initiates a SCAN operation across all masters.
     /// # Arguments
     /// * `pattern` - The pattern to match keys.
     /// * `count` - The count of keys to return.
+    /// * `key_type` - Only return keys of this type (SCAN TYPE, Redis 6.0+).
     /// # Returns
     /// * `(Vec<u64>, Vec<SharedString>)` - A tuple containing the new cursors and the keys.
-    pub async fn first_scan(&self, pattern: &str, count: u64) -> Result<(Vec<u64>, Vec<SharedString>)> {
+    pub async fn first_scan(
+        &self,
+        pattern: &str,
+        count: u64,
+        key_type: Option<&str>,
+    ) -> Result<(Vec<u64>, Vec<SharedString>)> {
         let master_count = self.count_masters()?;
         let cursors = vec![0; master_count];
 
-        let (cursors, keys) = self.scan(cursors, pattern, count).await?;
+        let (cursors, keys) = self.scan(cursors, pattern, count, key_type).await?;
         Ok((cursors, keys))
     }
     /// Continues a SCAN operation.
@@ -276,40 +499,120 @@ impl RedisClient {
     /// * `cursors` - A vector of cursors for each master.
     /// * `pattern` - The pattern to match keys.
     /// * `count` - The count of keys to return.
+    /// * `key_type` - Only return keys of this type (SCAN TYPE, Redis 6.0+).
     /// # Returns
     /// * `(Vec<u64>, Vec<SharedString>)` - A tuple containing the new cursors and the keys.
-    pub async fn scan(&self, cursors: Vec<u64>, pattern: &str, count: u64) -> Result<(Vec<u64>, Vec<SharedString>)> {
-        debug!("scan, cursors: {cursors:?}, pattern: {pattern}, count: {count}");
+    pub async fn scan(
+        &self,
+        cursors: Vec<u64>,
+        pattern: &str,
+        count: u64,
+        key_type: Option<&str>,
+    ) -> Result<(Vec<u64>, Vec<SharedString>)> {
+        debug!("scan, cursors: {cursors:?}, pattern: {pattern}, count: {count}, type: {key_type:?}");
+        if cursors.is_empty() {
+            return Err(no_reachable_master_error());
+        }
         let cmds: Vec<Cmd> = cursors
             .iter()
             .map(|cursor| {
-                cmd("SCAN")
+                let mut scan_cmd = cmd("SCAN");
+                scan_cmd
                     .cursor_arg(*cursor)
                     .arg("MATCH")
                     .arg(pattern)
                     .arg("COUNT")
-                    .arg(count)
-                    .clone()
+                    .arg(count);
+                if let Some(key_type) = key_type {
+                    scan_cmd.arg("TYPE").arg(key_type);
+                }
+                scan_cmd
             })
             .collect();
-        let values: Vec<(u64, Vec<Vec<u8>>)> = self.query_async_masters(cmds).await?;
-        let mut cursors = Vec::with_capacity(values.len());
-        let mut keys = Vec::with_capacity(values[0].1.len() * values.len());
-        for (cursor, keys_in_node) in values {
-            cursors.push(cursor);
-            keys.extend(
-                keys_in_node
-                    .iter()
-                    .map(|k| String::from_utf8_lossy(k).to_string().into()),
-            );
-        }
-        keys.sort_unstable();
+        // The cursors are bound to the node, so each master is always scanned on the same node
+        let addrs: Vec<_> = self
+            .read_nodes
+            .iter()
+            .map(|item| item.connection_url.as_str())
+            .collect();
+        let values: Vec<(u64, Vec<Vec<u8>>)> = query_async_masters(addrs, cmds).await?;
+        let is_sharded = self.is_sharded();
+        let (cursors, keys) = merge_scan_values(values, |index, key| {
+            // Remember the master of the key, its commands are sent there
+            if is_sharded {
+                self.key_shards.insert(key.as_bytes().to_vec(), index);
+            }
+        });
         Ok((cursors, keys))
     }
 }
 
+/// Merges the SCAN replies of the masters into the new cursors and the sorted keys.
+///
+/// `on_key` is called with the index of the master and each key it returned.
+fn merge_scan_values(
+    values: Vec<(u64, Vec<Vec<u8>>)>,
+    mut on_key: impl FnMut(usize, &str),
+) -> (Vec<u64>, Vec<SharedString>) {
+    let mut cursors = Vec::with_capacity(values.len());
+    let capacity = values.iter().map(|(_, keys)| keys.len()).sum();
+    let mut keys = Vec::with_capacity(capacity);
+    for (index, (cursor, keys_in_node)) in values.into_iter().enumerate() {
+        cursors.push(cursor);
+        keys.extend(keys_in_node.iter().map(|k| {
+            let key = String::from_utf8_lossy(k).to_string();
+            on_key(index, &key);
+            SharedString::from(key)
+        }));
+    }
+    keys.sort_unstable();
+    (cursors, keys)
+}
+
 pub struct ConnectionManager {
     clients: DashMap<String, RedisClient>,
+    // Per-server locks, so concurrent callers share one in-flight client build
+    building: DashMap<String, Arc<Mutex<()>>>,
+    // Passwords entered at connect time, never written to the config file
+    passwords: DashMap<String, String>,
+    // Last sentinel failover check of each server
+    failover_checks: DashMap<String, Instant>,
+}
+
+/// Gets the connection url of the server, routed through the SSH tunnel or the SOCKS5 proxy if configured.
+async fn get_connection_url(config: &RedisServer) -> Result<String> {
+    if let Some(destination) = config.ssh_tunnel.as_ref().filter(|destination| !destination.is_empty()) {
+        let ssh = SshTunnelConfig {
+            destination: destination.clone(),
+            key_path: config.ssh_key_path.clone(),
+            password: config.ssh_password.clone(),
+        };
+        let (host, port) = get_ssh_tunnel_address(&config.id, &ssh, &config.host, config.port).await?;
+        let mut tmp_config = config.clone();
+        tmp_config.host = host;
+        tmp_config.port = port;
+        return Ok(tmp_config.get_connection_url());
+    }
+    let Some(proxy) = config.proxy.as_ref().filter(|proxy| !proxy.is_empty()) else {
+        return Ok(config.get_connection_url());
+    };
+    let (host, port) = get_proxy_address(&config.id, proxy, &config.host, config.port).await?;
+    let mut tmp_config = config.clone();
+    tmp_config.host = host;
+    tmp_config.port = port;
+    Ok(tmp_config.get_connection_url())
+}
+
+/// Error returned when no master node is available, e.g. all cluster nodes are flagged as failed.
+fn no_reachable_master_error() -> Error {
+    Error::Invalid {
+        message: "no reachable master nodes".to_string(),
+    }
+}
+
+/// Checks whether the error is an authentication failure reported by Redis.
+fn is_authentication_failed(err: &Error) -> bool {
+    matches!(err, Error::Redis { source } if source.kind() == ErrorKind::AuthenticationFailed)
 }
 
 /// Detects the type of Redis server (Sentinel, Cluster, or Standalone).
@@ -318,8 +621,8 @@ pub struct ConnectionManager {
 /// * `client` - The Redis client to check the server type.
 /// # Returns
 /// * `ServerType` - The type of the Redis server.
-async fn detect_server_type(client: &Client) -> Result<ServerType> {
-    let mut conn = client.get_multiplexed_async_connection().await?;
+async fn detect_server_type(client: &Client, config: &RedisServer) -> Result<ServerType> {
+    let mut conn = get_multiplexed_connection(client, config).await?;
     // Check if it's a Sentinel
     // Note: `ROLE` command might not exist on old Redis versions, consider fallback if needed.
     // Assuming modern Redis here.
@@ -340,160 +643,418 @@ async fn detect_server_type(client: &Client) -> Result<ServerType> {
     }
 }
 
-impl ConnectionManager {
-    pub fn new() -> Self {
-        Self {
-            clients: DashMap::new(),
+/// Picks the masters to connect to from the `SENTINEL MASTERS` response, as (name, ip, port).
+///
+/// Only the configured names are kept, in the configured order, the first master
+/// serves the keys not scanned yet. Several masters without a configured name
+/// are ambiguous, the error lists them to let the user pick one.
+fn select_sentinel_masters(
+    masters_response: Vec<HashMap<String, String>>,
+    master_names: &[&str],
+) -> Result<Vec<(String, String, u16)>> {
+    let mut masters = vec![];
+    for item in masters_response {
+        let ip = item.get("ip").ok_or_else(|| Error::Invalid {
+            message: "ip is not found".to_string(),
+        })?;
+        let port: u16 = item
+            .get("port")
+            .ok_or_else(|| Error::Invalid {
+                message: "port is not found".to_string(),
+            })?
+            .parse()
+            .map_err(|e| Error::Invalid {
+                message: format!("Invalid port {e:?}"),
+            })?;
+        let name = item.get("name").ok_or_else(|| Error::Invalid {
+            message: "master_name is not found".to_string(),
+        })?;
+        // Filter by master name if configured
+        if !master_names.is_empty() && !master_names.contains(&name.as_str()) {
+            continue;
         }
+        masters.push((name.clone(), ip.clone(), port));
     }
-    /// Discovers Redis nodes and server type based on initial configuration.
-    async fn get_redis_nodes(&self, name: &str) -> Result<(Vec<RedisNode>, ServerType)> {
-        let config = get_config(name)?;
-        let url = config.get_connection_url();
-        let mut client = Client::open(url.clone())?;
-        // Attempt to connect and detect server type
-        // Handles logic to retry without password if authentication fails
-        let server_type = match detect_server_type(&client).await {
-            Ok(server_type) => server_type,
-            Err(e) => {
-                // Retry without password if auth failed and config might allow empty password
-                // or simply to handle sentinel cases which often have no auth
-                if config.password.is_none() || !e.to_string().contains("AuthenticationFailed") {
-                    return Err(e);
-                }
-                let mut tmp_config = config.clone();
-                tmp_config.password = None;
-                client = Client::open(tmp_config.get_connection_url())?;
-                detect_server_type(&client).await?
+    if master_names.is_empty() && masters.len() > 1 {
+        let mut names: Vec<String> = masters.into_iter().map(|(name, _, _)| name).collect();
+        names.sort_unstable();
+        names.dedup();
+        return Err(Error::SentinelMasters { names });
+    }
+    masters.sort_by_key(|(name, _, _)| {
+        master_names
+            .iter()
+            .position(|configured| *configured == name.as_str())
+            .unwrap_or_default()
+    });
+    Ok(masters)
+}
+
+/// Discovers Redis nodes and server type based on initial configuration.
+///
+/// The returned flag is true if the configured password was not needed and has been ignored.
+async fn discover_redis_nodes(
+    mut config: RedisServer,
+    retry_without_password: bool,
+) -> Result<(Vec<RedisNode>, ServerType, bool)> {
+    // Detect the server type without SELECT, cluster and sentinel servers reject it
+    let db = config.db.take();
+    let mut client = Client::open(get_connection_url(&config).await?)?;
+    let mut password_ignored = false;
+    // Attempt to connect and detect server type
+    // Handles logic to retry without password if authentication fails
+    let server_type = match detect_server_type(&client, &config).await {
+        Ok(server_type) => server_type,
+        Err(e) => {
+            // Retry without password if auth failed and config might allow empty password
+            // or simply to handle sentinel cases which often have no auth
+            if !retry_without_password || config.password.is_none() || !is_authentication_failed(&e) {
+                return Err(e);
             }
-        };
-        match server_type {
-            ServerType::Cluster => {
-                let mut conn = client.get_multiplexed_async_connection().await?;
-                // Fetch cluster topology
-                let nodes: String = cmd("CLUSTER").arg("NODES").query_async(&mut conn).await?;
-                // Parse nodes and convert to RedisNode
-                let nodes = parse_cluster_nodes(&nodes)?
-                    .iter()
-                    .map(|item| {
-                        let mut tmp_config = config.clone();
-                        tmp_config.port = item.port;
-                        tmp_config.host = item.ip.clone();
-
-                        RedisNode {
-                            connection_url: tmp_config.get_connection_url(),
-                            role: item.role.clone(),
-                            ..Default::default()
-                        }
-                    })
-                    .collect();
-                Ok((nodes, server_type))
+            // The ACL username is only used together with the password
+            let mut tmp_config = config.clone();
+            tmp_config.password = None;
+            tmp_config.username = None;
+            client = Client::open(get_connection_url(&tmp_config).await?)?;
+            let server_type = detect_server_type(&client, &tmp_config).await?;
+            // Sentinel often has no auth while its masters still need the password
+            if server_type != ServerType::Sentinel {
+                config = tmp_config;
+                password_ignored = true;
+            }
+            server_type
+        }
+    };
+    match server_type {
+        ServerType::Cluster => {
+            let mut conn = get_multiplexed_connection(&client, &config).await?;
+            // Fetch cluster topology
+            let nodes: String = cmd("CLUSTER").arg("NODES").query_async(&mut conn).await?;
+            // Parse nodes and convert to RedisNode
+            let mut redis_nodes = vec![];
+            for item in parse_cluster_nodes(&nodes)? {
+                let mut tmp_config = config.clone();
+                tmp_config.port = item.port;
+                tmp_config.host = item.ip.clone();
+
+                redis_nodes.push(RedisNode {
+                    connection_url: get_connection_url(&tmp_config).await?,
+                    role: item.role.clone(),
+                    slots: item.slots.clone(),
+                    node_id: item.id.clone(),
+                    master_id: item.master_id.clone(),
+                    ..Default::default()
+                });
             }
-            ServerType::Sentinel => {
-                let mut conn = client.get_multiplexed_async_connection().await?;
-                // Fetch masters from Sentinel
-                let masters_response: Vec<HashMap<String, String>> =
-                    cmd("SENTINEL").arg("MASTERS").query_async(&mut conn).await?;
-                let mut nodes = vec![];
-
-                for item in masters_response {
-                    let ip = item.get("ip").ok_or_else(|| Error::Invalid {
-                        message: "ip is not found".to_string(),
-                    })?;
-                    let port: u16 = item
-                        .get("port")
-                        .ok_or_else(|| Error::Invalid {
-                            message: "port is not found".to_string(),
-                        })?
-                        .parse()
-                        .map_err(|e| Error::Invalid {
-                            message: format!("Invalid port {e:?}"),
-                        })?;
-                    let name = item.get("name").ok_or_else(|| Error::Invalid {
-                        message: "master_name is not found".to_string(),
-                    })?;
-                    // Filter by master name if configured
-                    if let Some(master_name) = &config.master_name
-                        && name != master_name
-                    {
+            Ok((redis_nodes, server_type, password_ignored))
+        }
+        ServerType::Sentinel => {
+            let mut conn = get_multiplexed_connection(&client, &config).await?;
+            // Fetch masters from Sentinel
+            let masters_response: Vec<HashMap<String, String>> =
+                cmd("SENTINEL").arg("MASTERS").query_async(&mut conn).await?;
+            // A comma separated list browses several masters as shards
+            let masters = select_sentinel_masters(masters_response, &config.master_names())?;
+            let mut nodes = vec![];
+
+            for (name, ip, port) in masters {
+                let mut tmp_config = config.clone();
+                tmp_config.host = ip;
+                tmp_config.port = port;
+                tmp_config.db = db;
+
+                nodes.push(RedisNode {
+                    connection_url: get_connection_url(&tmp_config).await?,
+                    role: NodeRole::Master,
+                    master_name: Some(name.clone()),
+                    ..Default::default()
+                });
+                if !config.prefer_replica.unwrap_or(false) {
+                    continue;
+                }
+                // Replicas are only needed to read from them
+                let replicas: Vec<HashMap<String, String>> = cmd("SENTINEL")
+                    .arg("REPLICAS")
+                    .arg(&name)
+                    .query_async(&mut conn)
+                    .await?;
+                for replica in replicas {
+                    let flags = replica.get("flags").map(String::as_str).unwrap_or_default();
+                    if flags.contains("down") || flags.contains("disconnected") {
                         continue;
                     }
+                    let (Some(ip), Some(Ok(port))) =
+                        (replica.get("ip"), replica.get("port").map(|port| port.parse::<u16>()))
+                    else {
+                        continue;
+                    };
                     let mut tmp_config = config.clone();
                     tmp_config.host = ip.clone();
                     tmp_config.port = port;
-
+                    tmp_config.db = db;
                     nodes.push(RedisNode {
-                        connection_url: tmp_config.get_connection_url(),
-                        role: NodeRole::Master,
+                        connection_url: get_connection_url(&tmp_config).await?,
+                        role: NodeRole::Slave,
                         master_name: Some(name.clone()),
+                        ..Default::default()
                     });
                 }
-                // Check for ambiguous master configuration
-                let unique_masters: HashSet<_> = nodes.iter().filter_map(|n| n.master_name.as_ref()).collect();
-                if unique_masters.len() > 1 {
-                    return Err(Error::Invalid {
-                        message: "Multiple masters found in Sentinel, please specify master_name".into(),
-                    });
-                }
+            }
+            Ok((nodes, server_type, password_ignored))
+        }
+        _ => Ok((
+            vec![RedisNode {
+                connection_url: get_connection_url(&RedisServer { db, ..config }).await?,
+                role: NodeRole::Master,
+                ..Default::default()
+            }],
+            server_type,
+            password_ignored,
+        )),
+    }
+}
+
+/// Returns the cached value of the id, or builds and caches it.
+///
+/// Concurrent calls for the same id wait for the first build instead of running it again.
+async fn get_or_build<T, F, Fut>(
+    cache: &DashMap<String, T>,
+    building: &DashMap<String, Arc<Mutex<()>>>,
+    id: &str,
+    build: F,
+) -> Result<T>
+where
+    T: Clone,
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    if let Some(value) = cache.get(id) {
+        return Ok(value.clone());
+    }
+    let lock = building.entry(id.to_string()).or_default().clone();
+    let _guard = lock.lock().await;
+    // Built by another caller while waiting for the lock
+    if let Some(value) = cache.get(id) {
+        return Ok(value.clone());
+    }
+    let value = build().await?;
+    cache.insert(id.to_string(), value.clone());
+    Ok(value)
+}
 
-                Ok((nodes, server_type))
+impl ConnectionManager {
+    pub fn new() -> Self {
+        Self {
+            clients: DashMap::new(),
+            building: DashMap::new(),
+            passwords: DashMap::new(),
+            failover_checks: DashMap::new(),
+        }
+    }
+    /// Discovers Redis nodes and server type of the configured server.
+    ///
+    /// The returned flag is true if the configured password was not needed and has been ignored.
+    async fn get_redis_nodes(&self, name: &str) -> Result<(Vec<RedisNode>, ServerType, bool)> {
+        let mut config = get_config(name)?;
+        let prompt_password = config.prompt_password.unwrap_or(false);
+        if prompt_password {
+            let Some(password) = self.passwords.get(name).map(|password| password.clone()) else {
+                return Err(Error::PasswordRequired);
+            };
+            config.password = Some(password);
+        }
+        match discover_redis_nodes(config, !prompt_password).await {
+            // Ask again if the entered password is wrong
+            Err(e) if prompt_password && is_authentication_failed(&e) => {
+                self.passwords.remove(name);
+                Err(Error::PasswordRequired)
             }
-            _ => Ok((
-                vec![RedisNode {
-                    connection_url: url,
-                    role: NodeRole::Master,
-                    ..Default::default()
-                }],
-                server_type,
-            )),
+            result => result,
+        }
+    }
+    /// Tests the connection of a server config that may not be saved yet.
+    ///
+    /// The nodes are discovered and the first master is pinged, nothing is cached.
+    /// Returns the detected server type and the version of the server.
+    pub async fn test_connection(&self, mut config: RedisServer) -> Result<(String, String)> {
+        // SSH tunnels of the test must not replace the ones of a connected server
+        config.id = TEST_CONNECTION_ID.to_string();
+        // A wrong prompted password is reported like on connect, without retrying without it
+        let retry_without_password = !config.prompt_password.unwrap_or(false);
+        let result = async {
+            let (nodes, server_type, _) = discover_redis_nodes(config.clone(), retry_without_password).await?;
+            let node = nodes
+                .iter()
+                .find(|node| node.role == NodeRole::Master)
+                .ok_or_else(no_reachable_master_error)?;
+            let client = Client::open(node.connection_url.clone())?;
+            let mut conn = get_multiplexed_connection(&client, &config).await?;
+            let _: () = cmd("PING").query_async(&mut conn).await?;
+            let info: InfoDict = cmd("INFO").arg("server").query_async(&mut conn).await?;
+            let version = info.get::<String>("redis_version").unwrap_or_default();
+            Ok((format!("{server_type:?}"), version))
         }
+        .await;
+        close_ssh_tunnels(TEST_CONNECTION_ID);
+        close_proxy_forwarders(TEST_CONNECTION_ID);
+        result
+    }
+    /// Returns true if a client of the server is cached.
+    pub fn has_client(&self, server_id: &str) -> bool {
+        self.clients.contains_key(server_id)
     }
+    /// Removes the cached client and the password entered at connect time.
     pub fn remove_client(&self, name: &str) {
         self.clients.remove(name);
+        self.passwords.remove(name);
+        self.failover_checks.remove(name);
+        close_ssh_tunnels(name);
+        close_proxy_forwarders(name);
+    }
+    /// Removes the cached client only, a reconnect does not ask for the password again.
+    pub fn reset_client(&self, name: &str) {
+        self.clients.remove(name);
+    }
+    /// Keeps the password entered at connect time in memory for the session.
+    pub fn set_password(&self, name: &str, password: String) {
+        self.passwords.insert(name.to_string(), password);
     }
     /// Retrieves or creates a RedisClient for the given configuration name.
+    ///
+    /// Concurrent calls for an uncached server wait for the first build
+    /// instead of running the discovery again.
     pub async fn get_client(&self, server_id: &str) -> Result<RedisClient> {
-        if let Some(client) = self.clients.get(server_id) {
-            return Ok(client.clone());
-        }
-        let (nodes, server_type) = self.get_redis_nodes(server_id).await?;
+        get_or_build(&self.clients, &self.building, server_id, || {
+            self.build_client(server_id)
+        })
+        .await
+    }
+    /// Discovers the nodes and connects to the server.
+    async fn build_client(&self, server_id: &str) -> Result<RedisClient> {
+        let (nodes, server_type, password_ignored) = self.get_redis_nodes(server_id).await?;
+        let config = get_config(server_id)?;
+        let prefer_replica = config.prefer_replica.unwrap_or(false);
         let client = match server_type {
             ServerType::Cluster => {
                 let addrs: Vec<String> = nodes.iter().map(|n| n.connection_url.clone()).collect();
-                let client = cluster::ClusterClient::new(addrs)?;
-                RClient::Cluster(client)
+                RClient::Cluster(cluster::ClusterClient::new(addrs)?)
             }
             _ => {
-                let client = Client::open(nodes[0].connection_url.clone())?;
+                // The node list of a sentinel may be empty while it fails over
+                let node = nodes
+                    .iter()
+                    .find(|node| node.role == NodeRole::Master)
+                    .ok_or_else(no_reachable_master_error)?;
+                let client = Client::open(node.connection_url.clone())?;
                 RClient::Single(client)
             }
         };
-        let master_nodes = nodes
+        let master_nodes: Vec<RedisNode> = nodes
             .iter()
             .filter(|node| node.role == NodeRole::Master)
             .cloned()
             .collect();
         info!(master_nodes = ?master_nodes, "server master nodes");
-        let connection = get_async_connection(&client).await?;
+        let read_nodes: Vec<RedisNode> = master_nodes
+            .iter()
+            .map(|master| {
+                let replica = nodes.iter().find(|node| {
+                    node.role == NodeRole::Slave
+                        && match server_type {
+                            ServerType::Cluster => node.master_id.as_ref() == Some(&master.node_id),
+                            _ => node.master_name == master.master_name,
+                        }
+                });
+                match replica {
+                    Some(replica) if prefer_replica => replica.clone(),
+                    _ => master.clone(),
+                }
+            })
+            .collect();
+        let key_shards = Arc::new(DashMap::new());
+        // Redirects of the cluster client would connect to the nodes directly,
+        // so the commands are routed by hash slot through the forwarded node addresses
+        let is_forwarded = config.ssh_tunnel.as_ref().is_some_and(|v| !v.is_empty())
+            || config.proxy.as_ref().is_some_and(|v| !v.is_empty());
+        let (connection, read_connection) = if server_type == ServerType::Sentinel && master_nodes.len() > 1 {
+            let routing = ShardRouting::Scanned(key_shards.clone());
+            get_sharded_connections(&master_nodes, &read_nodes, routing, &config).await?
+        } else if server_type == ServerType::Cluster && is_forwarded {
+            let slots = master_nodes
+                .iter()
+                .enumerate()
+                .flat_map(|(index, node)| node.slots.iter().map(move |(start, end)| (*start, *end, index)))
+                .collect();
+            let routing = ShardRouting::Slots(Arc::new(slots));
+            get_sharded_connections(&master_nodes, &read_nodes, routing, &config).await?
+        } else {
+            let connection = get_async_connection(&client, &config).await?;
+            // Only the read connection uses the replicas, the main connection reads
+            // from the masters, e.g. a value just written by the app.
+            // A cluster client routes the read-only commands to the replicas,
+            // a single server needs its own connection to the replica.
+            let read_node = read_nodes.first().filter(|node| node.role == NodeRole::Slave);
+            let read_connection = match (&server_type, read_node) {
+                (ServerType::Cluster, _) if prefer_replica => {
+                    let addrs: Vec<String> = nodes.iter().map(|n| n.connection_url.clone()).collect();
+                    let replica_connection =
+                        match cluster::ClusterClientBuilder::new(addrs).read_from_replicas().build() {
+                            Ok(replica) => get_async_connection(&RClient::Cluster(replica), &config).await,
+                            Err(e) => Err(e.into()),
+                        };
+                    replica_connection.unwrap_or_else(|e| {
+                        warn!(error = %e, "connect to replicas fail, read from masters");
+                        connection.clone()
+                    })
+                }
+                (ServerType::Cluster, _) => connection.clone(),
+                (_, Some(node)) => {
+                    let replica_connection = match Client::open(node.connection_url.clone()) {
+                        Ok(replica) => get_async_connection(&RClient::Single(replica), &config).await,
+                        Err(e) => Err(e.into()),
+                    };
+                    replica_connection.unwrap_or_else(|e| {
+                        warn!(error = %e, replica = node.host_port(), "connect to replica fail, read from master");
+                        connection.clone()
+                    })
+                }
+                _ => connection.clone(),
+            };
+            (connection, read_connection)
+        };
         let mut client = RedisClient {
             server_type: server_type.clone(),
+            password_ignored,
             nodes,
             master_nodes,
+            read_nodes,
             version: Version::new(0, 0, 0),
             connection,
+            read_connection,
+            key_shards,
         };
         let mut conn = client.connection.clone();
         client.version = match server_type {
             ServerType::Cluster => {
                 let info: redis::Value = cmd("INFO").arg("server").query_async(&mut conn).await?;
                 let mut version = "unknown".to_string();
-                if let redis::Value::Map(items) = info {
-                    for (_, node_info_val) in items {
-                        if let Ok(info) = InfoDict::from_redis_value(node_info_val)
+                match info {
+                    redis::Value::Map(items) => {
+                        for (_, node_info_val) in items {
+                            if let Ok(info) = InfoDict::from_redis_value(node_info_val)
+                                && let Some(v) = info.get::<String>("redis_version")
+                            {
+                                version = v;
+                                break;
+                            }
+                        }
+                    }
+                    // A forwarded cluster only asks the first master
+                    info => {
+                        if let Ok(info) = InfoDict::from_redis_value(info)
                             && let Some(v) = info.get::<String>("redis_version")
                         {
                             version = v;
-                            break;
                         }
                     }
                 }
@@ -505,18 +1066,276 @@ impl ConnectionManager {
                 Version::parse(&version).unwrap_or(Version::new(0, 0, 0))
             }
         };
-        // Cache the client
-        self.clients.insert(server_id.to_string(), client.clone());
         Ok(client)
     }
     /// Shorthand to get an async connection directly.
+    ///
+    /// Only the connection of a cached client is cloned, the client is
+    /// created if it is not cached yet.
     pub async fn get_connection(&self, server_id: &str) -> Result<RedisAsyncConn> {
+        let connection = self.clients.get(server_id).map(|client| client.connection.clone());
+        if let Some(connection) = connection {
+            return Ok(connection);
+        }
         let client = self.get_client(server_id).await?;
         Ok(client.connection.clone())
     }
+
+    /// Shorthand to get the connection for read-only commands, see `RedisClient::read_connection`.
+    pub async fn get_read_connection(&self, server_id: &str) -> Result<RedisAsyncConn> {
+        let connection = self.clients.get(server_id).map(|client| client.read_connection.clone());
+        if let Some(connection) = connection {
+            return Ok(connection);
+        }
+        let client = self.get_client(server_id).await?;
+        Ok(client.read_connection.clone())
+    }
+
+    /// Checks whether the sentinel has failed over to another master.
+    ///
+    /// The masters are resolved again through the sentinel, the cached client
+    /// is rebuilt if the master address changed. Returns the new client in that
+    /// case, None if the server is not a sentinel or the master is unchanged.
+    ///
+    /// Asking the sentinel is skipped if it was done less than `FAILOVER_CHECK_INTERVAL`
+    /// ago, unless `force` is set, e.g. after the master failed to answer.
+    pub async fn check_sentinel_failover(&self, server_id: &str, force: bool) -> Result<Option<RedisClient>> {
+        let current_masters = self.clients.get(server_id).and_then(|client| {
+            (client.server_type == ServerType::Sentinel).then(|| {
+                client
+                    .master_nodes
+                    .iter()
+                    .map(|node| node.host_port())
+                    .collect::<Vec<_>>()
+            })
+        });
+        let Some(current_masters) = current_masters else {
+            return Ok(None);
+        };
+        let checked_recently = self
+            .failover_checks
+            .get(server_id)
+            .is_some_and(|checked_at| checked_at.elapsed() < FAILOVER_CHECK_INTERVAL);
+        if checked_recently && !force {
+            return Ok(None);
+        }
+        self.failover_checks.insert(server_id.to_string(), Instant::now());
+        let (nodes, _, _) = self.get_redis_nodes(server_id).await?;
+        let masters: Vec<String> = nodes
+            .iter()
+            .filter(|node| node.role == NodeRole::Master)
+            .map(|node| node.host_port())
+            .collect();
+        if masters == current_masters {
+            return Ok(None);
+        }
+        info!(server_id, from = ?current_masters, to = ?masters, "sentinel failover detected");
+        self.clients.remove(server_id);
+        let client = self.get_client(server_id).await?;
+        Ok(Some(client))
+    }
+
+    /// Measures the PING latency of the server.
+    ///
+    /// The cached connection is used if there is one, otherwise a short-lived
+    /// connection is opened so the server list can check every server cheaply.
+    /// Servers behind an SSH tunnel are only pinged while connected, None is
+    /// returned instead of starting an ssh process for the ping.
+    pub async fn ping(&self, server_id: &str) -> Result<Option<Duration>> {
+        let connection = self.clients.get(server_id).map(|client| client.connection.clone());
+        let start = Instant::now();
+        if let Some(mut conn) = connection {
+            let _: () = cmd("PING").query_async(&mut conn).await?;
+            return Ok(Some(start.elapsed()));
+        }
+        let mut config = get_config(server_id)?;
+        if config
+            .ssh_tunnel
+            .as_ref()
+            .is_some_and(|destination| !destination.is_empty())
+        {
+            return Ok(None);
+        }
+        if config.prompt_password.unwrap_or(false) {
+            let Some(password) = self.passwords.get(server_id).map(|password| password.clone()) else {
+                return Err(Error::PasswordRequired);
+            };
+            config.password = Some(password);
+        }
+        config.db = None;
+        let client = Client::open(get_connection_url(&config).await?)?;
+        let mut conn = get_multiplexed_connection(&client, &config).await?;
+        // Only the round trip is measured, not the connection handshake
+        let start = Instant::now();
+        let _: () = cmd("PING").query_async(&mut conn).await?;
+        Ok(Some(start.elapsed()))
+    }
+    /// Executes commands on all master nodes of the server concurrently.
+    ///
+    /// Only the master addresses of a cached client are cloned, the client is
+    /// created if it is not cached yet.
+    pub async fn query_async_masters<T: FromRedisValue>(&self, server_id: &str, cmds: Vec<Cmd>) -> Result<Vec<T>> {
+        let addrs = self.clients.get(server_id).map(|client| {
+            client
+                .master_nodes
+                .iter()
+                .map(|item| item.connection_url.clone())
+                .collect::<Vec<_>>()
+        });
+        if let Some(addrs) = addrs {
+            let values = query_async_masters(addrs.iter().map(|addr| addr.as_str()).collect(), cmds).await?;
+            return Ok(values);
+        }
+        let client = self.get_client(server_id).await?;
+        client.query_async_masters(cmds).await
+    }
+    /// Returns the `host:port` of the master nodes of a cached client.
+    ///
+    /// The order matches the results of `query_async_masters`, an empty list
+    /// is returned if the client is not cached.
+    pub fn master_host_ports(&self, server_id: &str) -> Vec<String> {
+        self.clients
+            .get(server_id)
+            .map(|client| client.master_nodes.iter().map(|node| node.host_port()).collect())
+            .unwrap_or_default()
+    }
+    /// Opens a dedicated pub/sub connection for the given server.
+    ///
+    /// The multiplexed connection can not enter the subscribed state, so a new
+    /// connection to the first master node is created. Messages published in a
+    /// cluster are broadcast to every node, so one node is enough.
+    pub async fn get_pubsub(&self, server_id: &str) -> Result<PubSub> {
+        let client = self.get_client(server_id).await?;
+        let Some(node) = client.master_nodes.first() else {
+            return Err(Error::Invalid {
+                message: "master node is not found".to_string(),
+            });
+        };
+        let pubsub = Client::open(node.connection_url.clone())?.get_async_pubsub().await?;
+        Ok(pubsub)
+    }
 }
 
 /// Global accessor for the connection manager.
 pub fn get_connection_manager() -> &'static ConnectionManager {
     &CONNECTION_MANAGER
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_is_authentication_failed() {
+        let err = Error::from(redis::RedisError::from((
+            ErrorKind::AuthenticationFailed,
+            "Authentication failed",
+        )));
+        assert!(is_authentication_failed(&err));
+
+        // The kind is checked, not the text of the message
+        let err = Error::from(redis::RedisError::from(std::io::Error::other("AuthenticationFailed")));
+        assert!(!is_authentication_failed(&err));
+        assert!(!is_authentication_failed(&Error::PasswordRequired));
+    }
+
+    #[test]
+    fn test_concurrent_get_or_build_builds_once() {
+        let cache = DashMap::new();
+        let building = DashMap::new();
+        let builds = AtomicUsize::new(0);
+        let counter = &builds;
+        let build = move || async move {
+            counter.fetch_add(1, Ordering::SeqCst);
+            // Keep the build in flight while the other call arrives
+            smol::Timer::after(Duration::from_millis(20)).await;
+            Ok(1)
+        };
+        let (first, second) = smol::block_on(smol::future::zip(
+            get_or_build(&cache, &building, "server", build),
+            get_or_build(&cache, &building, "server", build),
+        ));
+        assert_eq!(first.unwrap(), 1);
+        assert_eq!(second.unwrap(), 1);
+        assert_eq!(builds.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_merge_scan_values() {
+        let (cursors, keys) = merge_scan_values(vec![], |_, _| {});
+        assert!(cursors.is_empty());
+        assert!(keys.is_empty());
+
+        let mut shards = vec![];
+        let values = vec![(0, vec![]), (12, vec![b"user:2".to_vec(), b"user:1".to_vec()])];
+        let (cursors, keys) = merge_scan_values(values, |index, key| shards.push((index, key.to_string())));
+        assert_eq!(cursors, vec![0, 12]);
+        assert_eq!(keys, vec![SharedString::from("user:1"), SharedString::from("user:2")]);
+        assert_eq!(shards, vec![(1, "user:2".to_string()), (1, "user:1".to_string())]);
+    }
+
+    #[test]
+    fn test_select_sentinel_masters() {
+        let master = |name: &str, port: &str| {
+            HashMap::from([
+                ("name".to_string(), name.to_string()),
+                ("ip".to_string(), "127.0.0.1".to_string()),
+                ("port".to_string(), port.to_string()),
+            ])
+        };
+        let response = vec![master("orders", "6380"), master("cache", "6379")];
+
+        let err = select_sentinel_masters(response.clone(), &[]).unwrap_err();
+        assert!(
+            matches!(err, Error::SentinelMasters { names } if names == vec!["cache".to_string(), "orders".to_string()])
+        );
+
+        let masters = select_sentinel_masters(response.clone(), &["cache"]).unwrap();
+        assert_eq!(masters, vec![("cache".to_string(), "127.0.0.1".to_string(), 6379)]);
+
+        // Both masters as shards, in the configured order
+        let masters = select_sentinel_masters(response, &["cache", "orders"]).unwrap();
+        assert_eq!(
+            masters
+                .iter()
+                .map(|(name, _, port)| (name.as_str(), *port))
+                .collect::<Vec<_>>(),
+            vec![("cache", 6379), ("orders", 6380)]
+        );
+    }
+
+    #[test]
+    fn test_all_failed_cluster_has_no_master() {
+        let raw = "\
+07c37dfeb235213a872192d90877d0cd55635b91 127.0.0.1:30004@31004 slave,fail e7d1eecce10fd6bb5eb35b9f99a514335d9ba9ca 1426238317239 0 1426238316232 4 disconnected
+67ed2db8d677e59ec4a4cefb06858cf2a1a89fa1 127.0.0.1:30002@31002 master,fail - 0 1426238316232 2 disconnected 5461-10922
+e7d1eecce10fd6bb5eb35b9f99a514335d9ba9ca 127.0.0.1:30001@31001 master,fail - 0 0 1 disconnected 0-5460
+";
+        let nodes = parse_cluster_nodes(raw).unwrap();
+        assert_eq!(nodes.len(), 3);
+        assert!(nodes.iter().all(|node| node.role == NodeRole::Fail));
+        assert!(!nodes.iter().any(|node| node.role == NodeRole::Master));
+        assert_eq!(
+            no_reachable_master_error().to_string(),
+            "Invalid: no reachable master nodes"
+        );
+    }
+
+    #[test]
+    fn test_parse_cluster_nodes() {
+        let raw = "\
+07c37dfeb235213a872192d90877d0cd55635b91 127.0.0.1:30004@31004 slave e7d1eecce10fd6bb5eb35b9f99a514335d9ba9ca 0 1426238317239 4 connected
+e7d1eecce10fd6bb5eb35b9f99a514335d9ba9ca 127.0.0.1:30001@31001 myself,master - 0 0 1 connected 0-5460 5462
+";
+        let nodes = parse_cluster_nodes(raw).unwrap();
+        assert_eq!(nodes[0].role, NodeRole::Slave);
+        assert_eq!(
+            nodes[0].master_id.as_deref(),
+            Some("e7d1eecce10fd6bb5eb35b9f99a514335d9ba9ca")
+        );
+        assert_eq!(nodes[1].role, NodeRole::Master);
+        assert_eq!(nodes[1].port, 30001);
+        assert_eq!(nodes[1].slots, vec![(0, 5460), (5462, 5462)]);
+    }
+}
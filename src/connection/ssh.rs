@@ -0,0 +1,302 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! SSH tunnel support.
+//!
+//! Like the SOCKS5 proxy, the redis client is pointed at a local port on
+//! 127.0.0.1. The port is forwarded to the target through the jump host by the
+//! system `ssh` client (`ssh -N -L`), so keys, the ssh agent and `~/.ssh/config`
+//! work as usual. A password is handed to ssh through `SSH_ASKPASS`.
+
+use crate::error::Error;
+use crate::helpers::get_or_create_config_dir;
+use dashmap::DashMap;
+use smol::{
+    Timer,
+    io::{AsyncBufReadExt, BufReader},
+    lock::Mutex,
+    net::TcpStream,
+    stream::StreamExt,
+};
+use std::{
+    path::PathBuf,
+    process::{Child, Command, Stdio},
+    sync::{Arc, LazyLock},
+    time::{Duration, Instant},
+};
+use tracing::{info, warn};
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+// Max time to wait for ssh to authenticate and open the forwarded port
+const SSH_CONNECT_TIMEOUT: Duration = Duration::from_secs(15);
+const SSH_POLL_INTERVAL: Duration = Duration::from_millis(100);
+// Environment variable read by the askpass script
+const SSH_PASSWORD_ENV: &str = "ZEDIS_SSH_PASSWORD";
+// Attempts to start ssh, the reserved local port may be taken before ssh binds it
+const SSH_START_ATTEMPTS: usize = 3;
+// Max number of stderr lines kept to report why ssh exited
+const SSH_STDERR_LINES: usize = 20;
+
+// Running tunnels keyed by "server_id->destination->host:port"
+static SSH_TUNNELS: LazyLock<DashMap<String, SshTunnel>> = LazyLock::new(DashMap::new);
+// Per-tunnel locks, so concurrent callers share one ssh process
+static SSH_STARTING: LazyLock<DashMap<String, Arc<Mutex<()>>>> = LazyLock::new(DashMap::new);
+
+/// A running `ssh -N -L` process, killed when the tunnel is dropped
+struct SshTunnel {
+    local_port: u16,
+    child: Child,
+}
+
+impl SshTunnel {
+    fn is_alive(&mut self) -> bool {
+        matches!(self.child.try_wait(), Ok(None))
+    }
+}
+
+impl Drop for SshTunnel {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// SSH settings of a server
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SshTunnelConfig {
+    /// Jump host, e.g. `user@bastion:22`
+    pub destination: String,
+    pub key_path: Option<String>,
+    pub password: Option<String>,
+}
+
+fn ssh_error(message: impl Into<String>) -> Error {
+    Error::Ssh {
+        message: message.into(),
+    }
+}
+
+/// Splits `user@host:port` into the ssh destination and the optional port.
+fn parse_destination(destination: &str) -> Result<(String, Option<u16>)> {
+    let destination = destination.trim().trim_start_matches("ssh://");
+    if destination.is_empty() {
+        return Err(ssh_error("ssh host is empty"));
+    }
+    match destination.rsplit_once(':') {
+        Some((host, port)) => {
+            let port = port
+                .parse::<u16>()
+                .map_err(|_| ssh_error(format!("invalid ssh port: {port}")))?;
+            Ok((host.to_string(), Some(port)))
+        }
+        None => Ok((destination.to_string(), None)),
+    }
+}
+
+/// Writes the askpass script that prints the password from the environment.
+///
+/// The password itself is never written to disk.
+fn get_or_create_askpass() -> Result<PathBuf> {
+    let dir = get_or_create_config_dir()?;
+    // `echo %VAR%` expands the value before parsing, so `&|<>^%` in the password
+    // would be run as commands, PowerShell prints the variable as is
+    #[cfg(windows)]
+    let (path, content) = (
+        dir.join("ssh-askpass.cmd"),
+        format!(
+            "@echo off\r\npowershell -NoProfile -NonInteractive -Command \"[Console]::Out.WriteLine($env:{SSH_PASSWORD_ENV})\"\r\n"
+        ),
+    );
+    #[cfg(not(windows))]
+    let (path, content) = (
+        dir.join("ssh-askpass.sh"),
+        format!("#!/bin/sh\nprintf '%s\\n' \"${SSH_PASSWORD_ENV}\"\n"),
+    );
+    // Rewrite scripts of older versions
+    let outdated = !std::fs::read_to_string(&path).is_ok_and(|value| value == content);
+    if outdated {
+        std::fs::write(&path, content)?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o700))?;
+        }
+    }
+    Ok(path)
+}
+
+/// Returns the local address that forwards to `host:port` through the ssh jump host.
+///
+/// The tunnel is started on first use and shared afterwards, it is restarted
+/// if the ssh process has exited. Authentication errors of ssh are returned
+/// to the caller.
+pub(crate) async fn get_ssh_tunnel_address(
+    server_id: &str,
+    ssh: &SshTunnelConfig,
+    host: &str,
+    port: u16,
+) -> Result<(String, u16)> {
+    let key = format!("{server_id}->{}->{host}:{port}", ssh.destination);
+    if let Some(local_port) = get_alive_tunnel_port(&key) {
+        return Ok(("127.0.0.1".to_string(), local_port));
+    }
+    let lock = SSH_STARTING.entry(key.clone()).or_default().clone();
+    let _guard = lock.lock().await;
+    // Started by another caller while waiting for the lock
+    if let Some(local_port) = get_alive_tunnel_port(&key) {
+        return Ok(("127.0.0.1".to_string(), local_port));
+    }
+    SSH_TUNNELS.remove(&key);
+
+    let mut attempt = 1;
+    loop {
+        match start_ssh_tunnel(ssh, host, port).await {
+            Ok(tunnel) => {
+                let local_port = tunnel.local_port;
+                SSH_TUNNELS.insert(key, tunnel);
+                return Ok(("127.0.0.1".to_string(), local_port));
+            }
+            // Another process took the local port between reserving it and ssh binding it
+            Err(SshStartError::PortInUse) if attempt < SSH_START_ATTEMPTS => {
+                attempt += 1;
+            }
+            Err(SshStartError::PortInUse) => {
+                return Err(ssh_error("ssh could not listen on a local port"));
+            }
+            Err(SshStartError::Other(e)) => return Err(e),
+        }
+    }
+}
+
+/// Returns the local port of the tunnel if its ssh process is still running.
+fn get_alive_tunnel_port(key: &str) -> Option<u16> {
+    let mut tunnel = SSH_TUNNELS.get_mut(key)?;
+    tunnel.is_alive().then_some(tunnel.local_port)
+}
+
+enum SshStartError {
+    /// The local port was taken before ssh could bind it
+    PortInUse,
+    Other(Error),
+}
+
+impl From<Error> for SshStartError {
+    fn from(e: Error) -> Self {
+        SshStartError::Other(e)
+    }
+}
+
+impl From<std::io::Error> for SshStartError {
+    fn from(e: std::io::Error) -> Self {
+        SshStartError::Other(e.into())
+    }
+}
+
+/// Starts `ssh -N -L` on a free local port and waits until the port is forwarded.
+///
+/// stderr of ssh is read for the whole life of the process, so ssh never blocks
+/// on a full pipe. The last lines are kept to report why ssh exited.
+async fn start_ssh_tunnel(ssh: &SshTunnelConfig, host: &str, port: u16) -> Result<SshTunnel, SshStartError> {
+    let (destination, ssh_port) = parse_destination(&ssh.destination)?;
+    // Reserve a free local port for ssh to listen on
+    let local_port = std::net::TcpListener::bind("127.0.0.1:0")?.local_addr()?.port();
+
+    let mut command = Command::new("ssh");
+    command
+        .arg("-N")
+        .arg("-L")
+        .arg(format!("127.0.0.1:{local_port}:{host}:{port}"))
+        .args(["-o", "ExitOnForwardFailure=yes", "-o", "ServerAliveInterval=30"]);
+    if let Some(ssh_port) = ssh_port {
+        command.arg("-p").arg(ssh_port.to_string());
+    }
+    if let Some(key_path) = ssh.key_path.as_ref().filter(|key_path| !key_path.is_empty()) {
+        command.arg("-i").arg(key_path);
+    }
+    match ssh.password.as_ref().filter(|password| !password.is_empty()) {
+        Some(password) => {
+            command
+                .env("SSH_ASKPASS", get_or_create_askpass()?)
+                .env("SSH_ASKPASS_REQUIRE", "force")
+                .env(SSH_PASSWORD_ENV, password)
+                .args(["-o", "NumberOfPasswordPrompts=1"]);
+        }
+        // Never wait for an interactive prompt
+        None => {
+            command.args(["-o", "BatchMode=yes"]);
+        }
+    }
+    let mut child = command
+        .arg(&destination)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| ssh_error(format!("failed to start ssh: {e}")))?;
+    info!(destination, host, port, local_port, "start ssh tunnel");
+
+    let stderr_lines: Arc<parking_lot::Mutex<Vec<String>>> = Arc::default();
+    let stderr_task = child.stderr.take().map(|stderr| {
+        let stderr_lines = stderr_lines.clone();
+        let destination = destination.clone();
+        smol::spawn(async move {
+            let mut lines = BufReader::new(smol::Unblock::new(stderr)).lines();
+            while let Some(Ok(line)) = lines.next().await {
+                warn!(destination, line, "ssh stderr");
+                let mut stderr_lines = stderr_lines.lock();
+                if stderr_lines.len() >= SSH_STDERR_LINES {
+                    stderr_lines.remove(0);
+                }
+                stderr_lines.push(line);
+            }
+        })
+    });
+
+    // ssh only listens on the local port once it is authenticated
+    let started = Instant::now();
+    loop {
+        if let Some(status) = child.try_wait()? {
+            // All output has been written once ssh has exited
+            if let Some(stderr_task) = stderr_task {
+                stderr_task.await;
+            }
+            let message = stderr_lines.lock().join("\n");
+            if message.contains("Address already in use") {
+                return Err(SshStartError::PortInUse);
+            }
+            return Err(ssh_error(format!("ssh exited with {status}: {}", message.trim())).into());
+        }
+        if TcpStream::connect(("127.0.0.1", local_port)).await.is_ok() {
+            break;
+        }
+        if started.elapsed() > SSH_CONNECT_TIMEOUT {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(ssh_error(format!("ssh tunnel to {destination} timed out")).into());
+        }
+        Timer::after(SSH_POLL_INTERVAL).await;
+    }
+    if let Some(stderr_task) = stderr_task {
+        stderr_task.detach();
+    }
+    Ok(SshTunnel { local_port, child })
+}
+
+/// Stops all tunnels of the server.
+pub(crate) fn close_ssh_tunnels(server_id: &str) {
+    let prefix = format!("{server_id}->");
+    SSH_TUNNELS.retain(|key, _| !key.starts_with(&prefix));
+    SSH_STARTING.retain(|key, _| !key.starts_with(&prefix));
+}
@@ -13,11 +13,46 @@
 // limitations under the License.
 
 use snafu::Snafu;
+use std::fmt;
+
+/// Redirection replied by a cluster node for a slot it does not serve
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RedirectKind {
+    /// The slot has been moved to another node
+    Moved,
+    /// The slot is being migrated, only the next command is redirected
+    Ask,
+}
+
+impl fmt::Display for RedirectKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RedirectKind::Moved => write!(f, "MOVED"),
+            RedirectKind::Ask => write!(f, "ASK"),
+        }
+    }
+}
 
 #[derive(Debug, Snafu)]
 pub enum Error {
     #[snafu(display("Invalid: {message}"))]
     Invalid { message: String },
+    #[snafu(display("Multiple masters found in Sentinel ({}), please specify master_name", names.join(", ")))]
+    SentinelMasters { names: Vec<String> },
+    #[snafu(display("Password is required to connect"))]
+    PasswordRequired,
+    #[snafu(display("Proxy error: {message}"))]
+    Proxy { message: String },
+    #[snafu(display("SSH tunnel error: {message}"))]
+    Ssh { message: String },
+    #[snafu(display(
+        "Slot {slot} is served by {address} ({kind}), the server is a cluster node or is resharding, please reconnect"
+    ))]
+    Redirect {
+        kind: RedirectKind,
+        slot: u16,
+        address: String,
+    },
     #[snafu(display("Redis error: {source}"))]
     Redis { source: redis::RedisError },
     #[snafu(display("IO error: {source}"))]
@@ -30,9 +65,27 @@ pub enum Error {
     TomlSe { source: toml::ser::Error },
 }
 
+/// Builds the MOVED/ASK redirection from the error code and the redirect node
+/// (address, slot) of a server error, e.g. `MOVED 3999 127.0.0.1:6381`.
+///
+/// Only the code of the reply is checked, a message that mentions MOVED is not a redirection.
+fn parse_redirect(code: Option<&str>, node: Option<(&str, u16)>) -> Option<(RedirectKind, u16, String)> {
+    let kind = match code? {
+        "MOVED" => RedirectKind::Moved,
+        "ASK" => RedirectKind::Ask,
+        _ => return None,
+    };
+    let (address, slot) = node?;
+    Some((kind, slot, address.to_string()))
+}
+
 impl From<redis::RedisError> for Error {
     fn from(source: redis::RedisError) -> Self {
-        Error::Redis { source }
+        // A single connection does not follow redirections, explain them instead
+        match parse_redirect(source.code(), source.redirect_node()) {
+            Some((kind, slot, address)) => Error::Redirect { kind, slot, address },
+            None => Error::Redis { source },
+        }
     }
 }
 
@@ -59,3 +112,28 @@ impl From<toml::ser::Error> for Error {
         Error::TomlSe { source }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_redirect() {
+        assert_eq!(
+            parse_redirect(Some("MOVED"), Some(("127.0.0.1:6381", 3999))),
+            Some((RedirectKind::Moved, 3999, "127.0.0.1:6381".to_string()))
+        );
+        assert_eq!(
+            parse_redirect(Some("ASK"), Some(("127.0.0.1:6382", 12))),
+            Some((RedirectKind::Ask, 12, "127.0.0.1:6382".to_string()))
+        );
+        assert_eq!(parse_redirect(Some("ERR"), None), None);
+        assert_eq!(parse_redirect(None, None), None);
+    }
+
+    #[test]
+    fn test_message_mentioning_moved_is_not_redirect() {
+        let source = redis::RedisError::from(std::io::Error::other("value MOVED 3999 127.0.0.1:6381"));
+        assert!(matches!(Error::from(source), Error::Redis { .. }));
+    }
+}
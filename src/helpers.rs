@@ -24,7 +24,7 @@ mod validate;
 
 pub use action::*;
 pub use common::*;
-pub use font::get_font_family;
+pub use font::{get_editor_font_family, get_font_family, get_installed_font_names};
 pub use fs::get_or_create_config_dir;
 pub use fs::is_app_store_build;
 pub use string::*;
@@ -29,6 +29,19 @@ pub enum EditorAction {
     Save,
     Reload,
     UpdateTtl,
+    PersistTtl,
+    FocusSearch,
+    QuickOpen,
+}
+
+/// Keyboard navigation of the key tree, only active while the tree is focused
+#[derive(Clone, Copy, PartialEq, Debug, Deserialize, JsonSchema, Action)]
+pub enum KeyTreeAction {
+    SelectPrev,
+    SelectNext,
+    Expand,
+    Collapse,
+    Confirm,
 }
 
 pub fn humanize_keystroke(keystroke: &str) -> String {
@@ -116,5 +129,13 @@ pub fn new_hot_keys() -> Vec<KeyBinding> {
         KeyBinding::new("cmd-r", EditorAction::Reload, None),
         KeyBinding::new("cmd-n", EditorAction::Create, None),
         KeyBinding::new("cmd-t", EditorAction::UpdateTtl, None),
+        // Not available while typing in another input
+        KeyBinding::new("cmd-l", EditorAction::FocusSearch, Some("!Input")),
+        KeyBinding::new("cmd-p", EditorAction::QuickOpen, None),
+        KeyBinding::new("up", KeyTreeAction::SelectPrev, Some("KeyTree")),
+        KeyBinding::new("down", KeyTreeAction::SelectNext, Some("KeyTree")),
+        KeyBinding::new("right", KeyTreeAction::Expand, Some("KeyTree")),
+        KeyBinding::new("left", KeyTreeAction::Collapse, Some("KeyTree")),
+        KeyBinding::new("enter", KeyTreeAction::Confirm, Some("KeyTree")),
     ]
 }
@@ -15,9 +15,11 @@
 use crate::constants::KEY_TREE_MAX_WIDTH;
 use crate::constants::KEY_TREE_MIN_WIDTH;
 use crate::error::Error;
-use gpui::{Pixels, px};
+use gpui::{App, Hsla, Pixels, SharedString, px, rgb};
+use gpui_component::ActiveTheme;
 use ruzstd::decoding::StreamingDecoder;
 use std::io::Read;
+use std::time::Duration;
 
 type Result<T, E = Error> = std::result::Result<T, E>;
 
@@ -36,6 +38,40 @@ pub fn decompress_zstd(bytes: &[u8]) -> Result<Vec<u8>> {
     Ok(decompressed_vec)
 }
 
+/// Parses a hex color such as `#e5484d`, returns None if it is invalid.
+pub fn parse_hex_color(value: &str) -> Option<Hsla> {
+    let hex = value.trim().strip_prefix('#')?;
+    if hex.len() != 6 {
+        return None;
+    }
+    let value = u32::from_str_radix(hex, 16).ok()?;
+    Some(rgb(value).into())
+}
+
+/// Formats the latency string and determines the color based on the delay.
+pub fn format_latency(latency: Option<Duration>, cx: &App) -> (SharedString, Hsla) {
+    if let Some(latency) = latency {
+        let ms = latency.as_millis();
+        let theme = cx.theme();
+        // Determine color based on latency thresholds
+        let color = if ms < 50 {
+            theme.green
+        } else if ms < 500 {
+            theme.yellow
+        } else {
+            theme.red
+        };
+        // Format string
+        if ms < 1000 {
+            (format!("{ms}ms").into(), color)
+        } else {
+            (format!("{:.2}s", ms as f64 / 1000.0).into(), color)
+        }
+    } else {
+        ("--".to_string().into(), cx.theme().primary)
+    }
+}
+
 #[inline]
 pub fn is_linux() -> bool {
     cfg!(target_os = "linux")
@@ -12,6 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::states::ZedisGlobalStore;
+use gpui::{App, SharedString};
+use parking_lot::Mutex;
+use std::sync::OnceLock;
+
+static INSTALLED_FONT_NAMES: OnceLock<Vec<SharedString>> = OnceLock::new();
+// The editor font setting and the font family it was resolved to
+static EDITOR_FONT_FAMILY: Mutex<Option<(Option<String>, SharedString)>> = Mutex::new(None);
+
 pub fn get_font_family() -> String {
     if cfg!(target_os = "macos") {
         "Monaco".to_string()
@@ -21,3 +30,40 @@ pub fn get_font_family() -> String {
         "monospace".to_string()
     }
 }
+
+/// Names of the installed fonts, sorted and loaded once
+pub fn get_installed_font_names(cx: &App) -> &'static [SharedString] {
+    INSTALLED_FONT_NAMES.get_or_init(|| {
+        let mut names = cx.text_system().all_font_names();
+        names.sort_unstable();
+        names.dedup();
+        names.into_iter().map(SharedString::from).collect()
+    })
+}
+
+/// Font family of the code editor and the hex viewer
+///
+/// Uses the font chosen in settings if it is installed, otherwise the
+/// platform default of `get_font_family`. The font is only resolved again
+/// when the setting changes, not on every render.
+pub fn get_editor_font_family(cx: &App) -> SharedString {
+    let setting = cx.global::<ZedisGlobalStore>().read(cx).editor_font_family();
+    let mut resolved = EDITOR_FONT_FAMILY.lock();
+    if let Some((name, font_family)) = resolved.as_ref()
+        && name.as_deref() == setting
+    {
+        return font_family.clone();
+    }
+    let font_family: SharedString = match setting {
+        Some(name)
+            if get_installed_font_names(cx)
+                .iter()
+                .any(|installed| &**installed == name) =>
+        {
+            name.to_string().into()
+        }
+        _ => get_font_family().into(),
+    };
+    *resolved = Some((setting.map(|name| name.to_string()), font_family.clone()));
+    font_family
+}
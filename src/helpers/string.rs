@@ -18,6 +18,8 @@
 //! - Fast case-insensitive substring searching with ASCII optimization
 //! - AES-256-GCM encryption and decryption for sensitive data (e.g., passwords)
 //! - Base64 encoding/decoding for storage and transport
+//! - Pretty-printing of JSON values
+//! - Grouping the digits of plain numbers with thousands separators
 
 use crate::error::Error;
 use aes_gcm::{
@@ -25,6 +27,8 @@ use aes_gcm::{
     aead::{Aead, AeadCore, KeyInit, Nonce, OsRng},
 };
 use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use gpui::SharedString;
+use serde_json::Value;
 
 type Result<T, E = Error> = std::result::Result<T, E>;
 
@@ -33,6 +37,9 @@ type Result<T, E = Error> = std::result::Result<T, E>;
 /// WARNING: In production, this should be stored securely (e.g., keychain, env var)
 /// rather than hardcoded in the binary.
 const MASTER_KEY: &[u8; 32] = b"9dFVxjgeQTPfOXCoDdjpgMOlPhy2HE9E";
+// Fuzzy match scores, subsequence matches always rank after substring matches
+const FUZZY_SUBSEQUENCE_PENALTY: usize = 100_000;
+const FUZZY_GAP_PENALTY: usize = 100;
 /// Performs fast case-insensitive substring search with ASCII optimization.
 ///
 /// This function is optimized for performance with two strategies:
@@ -76,6 +83,36 @@ pub fn fast_contains_ignore_case(haystack: &str, needle_lower: &str) -> bool {
     haystack.to_lowercase().contains(needle_lower)
 }
 
+/// Scores a case-insensitive fuzzy match of `needle_lower` in `haystack`, lower is better.
+///
+/// Substring matches rank before scattered (subsequence) matches, which are
+/// ranked by the number of skipped characters. Shorter haystacks rank first.
+/// Returns None if the characters of the needle are not found in order.
+pub fn fuzzy_match(haystack: &str, needle_lower: &str) -> Option<usize> {
+    if needle_lower.is_empty() || fast_contains_ignore_case(haystack, needle_lower) {
+        return Some(haystack.len());
+    }
+    let mut needle = needle_lower.chars().peekable();
+    let mut started = false;
+    let mut gaps = 0;
+    for c in haystack.chars().flat_map(char::to_lowercase) {
+        let Some(expected) = needle.peek() else {
+            break;
+        };
+        if *expected == c {
+            needle.next();
+            started = true;
+        } else if started {
+            // Only gaps after the first matched character count
+            gaps += 1;
+        }
+    }
+    if needle.peek().is_some() {
+        return None;
+    }
+    Some(FUZZY_SUBSEQUENCE_PENALTY + gaps * FUZZY_GAP_PENALTY + haystack.len())
+}
+
 /// Encrypts a plaintext string using AES-256-GCM encryption.
 ///
 /// The encrypted data is encoded as Base64 for easy storage and transport.
@@ -169,3 +206,87 @@ pub fn decrypt(cipher_text: &str) -> Result<String> {
     // Convert decrypted bytes to UTF-8 string
     String::from_utf8(plaintext_bytes).map_err(|e| Error::Invalid { message: e.to_string() })
 }
+
+/// Attempts to format a string as pretty-printed JSON.
+/// Returns None if the string is not valid JSON or doesn't look like JSON.
+pub fn pretty_json(value: &str) -> Option<SharedString> {
+    let trimmed = value.trim();
+    if !((trimmed.starts_with('{') && trimmed.ends_with('}')) || (trimmed.starts_with('[') && trimmed.ends_with(']'))) {
+        return None;
+    }
+    let json_value = serde_json::from_str::<Value>(value).ok()?;
+    let pretty_str = serde_json::to_string_pretty(&json_value).ok()?;
+
+    Some(pretty_str.into())
+}
+
+/// Formats a plain integer or float with thousands separators, e.g. `1000000.5` to `1,000,000.5`.
+/// Returns None if the string is not a plain number or has no digits to group.
+pub fn format_number_with_separators(value: &str) -> Option<String> {
+    let value = value.trim();
+    let (sign, unsigned) = match value.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", value.strip_prefix('+').unwrap_or(value)),
+    };
+    let (integer, fraction) = match unsigned.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (unsigned, None),
+    };
+    if integer.len() <= 3 || !integer.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    if fraction.is_some_and(|fraction| fraction.is_empty() || !fraction.bytes().all(|b| b.is_ascii_digit())) {
+        return None;
+    }
+    let mut grouped = String::with_capacity(value.len() + integer.len() / 3);
+    grouped.push_str(sign);
+    for (index, digit) in integer.chars().enumerate() {
+        if index > 0 && (integer.len() - index) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    if let Some(fraction) = fraction {
+        grouped.push('.');
+        grouped.push_str(fraction);
+    }
+    Some(grouped)
+}
+
+/// Quotes an argument for redis-cli, escaping quotes, backslashes and non-printable bytes as `\xHH`.
+pub fn quote_redis_arg(value: &[u8]) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    match std::str::from_utf8(value) {
+        Ok(text) => {
+            for ch in text.chars() {
+                match ch {
+                    '"' => quoted.push_str("\\\""),
+                    '\\' => quoted.push_str("\\\\"),
+                    '\n' => quoted.push_str("\\n"),
+                    '\r' => quoted.push_str("\\r"),
+                    '\t' => quoted.push_str("\\t"),
+                    ch if ch.is_control() => {
+                        let mut buf = [0; 4];
+                        for b in ch.encode_utf8(&mut buf).bytes() {
+                            quoted.push_str(&format!("\\x{b:02x}"));
+                        }
+                    }
+                    ch => quoted.push(ch),
+                }
+            }
+        }
+        Err(_) => {
+            for &b in value {
+                match b {
+                    b'"' => quoted.push_str("\\\""),
+                    b'\\' => quoted.push_str("\\\\"),
+                    0x20..=0x7e => quoted.push(b as char),
+                    _ => quoted.push_str(&format!("\\x{b:02x}")),
+                }
+            }
+        }
+    }
+    quoted.push('"');
+    quoted
+}
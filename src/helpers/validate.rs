@@ -19,6 +19,11 @@ pub fn validate_ttl(s: &str) -> bool {
     humantime::parse_duration(s).is_ok()
 }
 
+/// TTL of an existing key, also accepts an RFC3339 time while it is typed
+pub fn validate_expire(s: &str) -> bool {
+    validate_ttl(s) || s.chars().all(|c| c.is_ascii_digit() || "-:+.TZ ".contains(c))
+}
+
 pub fn validate_long_string(s: &str) -> bool {
     s.len() <= 4096
 }
@@ -30,3 +35,16 @@ pub fn validate_common_string(s: &str) -> bool {
 pub fn validate_host(s: &str) -> bool {
     s.len() <= 255 && s.is_ascii()
 }
+
+/// Partial hex color while typing, e.g. `#e548`
+pub fn validate_hex_color(s: &str) -> bool {
+    s.len() <= 7
+        && s.chars()
+            .enumerate()
+            .all(|(index, c)| (index == 0 && c == '#') || c.is_ascii_hexdigit())
+}
+
+/// Empty means the default port, otherwise it must be in 1..=65535
+pub fn validate_port(s: &str) -> bool {
+    s.is_empty() || s.parse::<u16>().is_ok_and(|port| port != 0)
+}
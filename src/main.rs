@@ -1,18 +1,21 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
+use crate::components::{FormDialog, FormField, open_add_form_dialog};
 use crate::connection::get_servers;
 use crate::constants::SIDEBAR_WIDTH;
 use crate::helpers::{MemuAction, is_app_store_build, is_development, is_linux, new_hot_keys};
 use crate::states::{
-    FontSize, FontSizeAction, LocaleAction, NotificationCategory, Route, ServerEvent, SettingsAction, ThemeAction,
-    ZedisAppState, ZedisGlobalStore, ZedisServerState, save_app_state, update_app_state_and_save,
+    FontSize, FontSizeAction, ImportValueRequest, LocaleAction, NotificationCategory, Route, ServerEvent,
+    SettingsAction, ThemeAction, ZedisAppState, ZedisGlobalStore, ZedisServerState, i18n_common, i18n_servers,
+    save_app_state, update_app_state_and_save,
 };
 use crate::views::{ZedisContent, ZedisSidebar, ZedisTitleBar, open_about_window};
 use gpui::{
-    App, Application, Bounds, Entity, Menu, MenuItem, Pixels, Task, TitlebarOptions, Window, WindowAppearance,
-    WindowBounds, WindowOptions, div, prelude::*, px, size,
+    App, Application, Bounds, Entity, Menu, MenuItem, Pixels, SharedString, Task, TitlebarOptions, Window,
+    WindowAppearance, WindowBounds, WindowOptions, div, prelude::*, px, size,
 };
 use gpui_component::{ActiveTheme, Root, Theme, ThemeMode, WindowExt, h_flex, notification::Notification, v_flex};
-use std::{env, str::FromStr};
+use rust_i18n::t;
+use std::{env, rc::Rc, str::FromStr};
 use tracing::{Level, error, info};
 use tracing_subscriber::FmtSubscriber;
 
@@ -35,7 +38,15 @@ mod views;
 
 pub struct Zedis {
     pending_notification: Option<Notification>,
+    // Server id and master names of a sentinel monitoring multiple masters
+    pending_sentinel_masters: Option<(SharedString, Vec<SharedString>)>,
+    pending_password_prompt: Option<SharedString>,
+    // File import waiting for the confirmation to overwrite an existing key
+    pending_import_value: Option<ImportValueRequest>,
+    server_state: Entity<ZedisServerState>,
     last_bounds: Bounds<Pixels>,
+    // Title of the window, "name - host:port" of the selected server
+    window_title: SharedString,
     save_task: Option<Task<()>>,
     // views
     sidebar: Entity<ZedisSidebar>,
@@ -65,6 +76,17 @@ impl Zedis {
                 ServerEvent::ErrorOccurred(error) => {
                     this.pending_notification = Some(Notification::error(error.message.clone()));
                 }
+                ServerEvent::SentinelMastersFound(server_id, names) => {
+                    this.pending_sentinel_masters = Some((server_id.clone(), names.clone()));
+                }
+                ServerEvent::PasswordRequired(server_id) => {
+                    this.pending_password_prompt = Some(server_id.clone());
+                }
+                ServerEvent::ImportValueKeyExists(request) => {
+                    this.pending_import_value = Some(request.clone());
+                }
+                // Re-render to update the window title
+                ServerEvent::ServerSelected(_) | ServerEvent::ServerListUpdated => {}
                 _ => {
                     return;
                 }
@@ -79,21 +101,155 @@ impl Zedis {
             }
         })
         .detach();
+        // Re-ping and reload the selected key when the window regains focus (opt-in)
+        cx.observe_window_activation(window, |this, window, cx| {
+            if !window.is_window_active() || !cx.global::<ZedisGlobalStore>().read(cx).refresh_on_focus() {
+                return;
+            }
+            this.server_state.update(cx, |state, cx| {
+                state.refresh_on_focus(cx);
+            });
+        })
+        .detach();
         let title_bar = if is_linux() {
             None
         } else {
             Some(cx.new(|cx| ZedisTitleBar::new(window, cx)))
         };
+        Self::restore_last_server(&server_state, cx);
 
         Self {
             sidebar,
             save_task: None,
             content,
             pending_notification: None,
+            pending_sentinel_masters: None,
+            pending_password_prompt: None,
+            pending_import_value: None,
+            server_state,
             title_bar,
             last_bounds: Bounds::default(),
+            window_title: SharedString::default(),
         }
     }
+    /// Reconnect to the last selected server and open the editor (opt-in)
+    ///
+    /// The remembered id is cleared if the server has been removed since.
+    fn restore_last_server(server_state: &Entity<ZedisServerState>, cx: &mut Context<Self>) {
+        let store = cx.global::<ZedisGlobalStore>().read(cx);
+        if !store.restore_last_server() {
+            return;
+        }
+        let Some(server_id) = store.last_server_id().map(|id| id.to_string()) else {
+            return;
+        };
+        if server_state.read(cx).server(&server_id).is_none() {
+            info!(server_id, "last server has been removed");
+            update_app_state_and_save(cx, "clear_last_server_id", move |state, _cx| {
+                state.set_last_server_id(None);
+            });
+            return;
+        }
+        server_state.update(cx, |state, cx| {
+            state.select(server_id.into(), cx);
+        });
+        cx.update_global::<ZedisGlobalStore, ()>(|store, cx| {
+            store.update(cx, |state, cx| {
+                state.go_to(Route::Editor, cx);
+            });
+        });
+    }
+    /// Ask which master to connect to when the sentinel monitors multiple masters
+    fn open_sentinel_masters_dialog(
+        &mut self,
+        server_id: SharedString,
+        names: Vec<SharedString>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let server_state = self.server_state.clone();
+        // The last option browses all masters as shards of one server
+        let mut names = names;
+        let all_names = names.iter().map(|name| name.as_str()).collect::<Vec<_>>().join(",");
+        names.push(all_names.into());
+        let options = names.clone();
+        let handle_submit = Rc::new(move |values: Vec<SharedString>, window: &mut Window, cx: &mut App| {
+            // values[0] is the index of the selected master
+            let Some(master_name) = values
+                .first()
+                .and_then(|value| value.parse::<usize>().ok())
+                .and_then(|index| names.get(index))
+                .cloned()
+            else {
+                return false;
+            };
+            server_state.update(cx, |state, cx| {
+                state.select_sentinel_master(server_id.clone(), master_name, cx);
+            });
+            window.close_dialog(cx);
+            true
+        });
+        open_add_form_dialog(
+            FormDialog {
+                title: i18n_servers(cx, "sentinel_master_title"),
+                fields: vec![FormField::new(i18n_servers(cx, "master_name")).with_options(options)],
+                handle_submit,
+            },
+            window,
+            cx,
+        );
+    }
+    /// Ask for the password of a server that does not store it
+    fn open_password_dialog(&mut self, server_id: SharedString, window: &mut Window, cx: &mut Context<Self>) {
+        let server_state = self.server_state.clone();
+        let handle_submit = Rc::new(move |values: Vec<SharedString>, window: &mut Window, cx: &mut App| {
+            let Some(password) = values.first().filter(|value| !value.is_empty()).cloned() else {
+                return false;
+            };
+            server_state.update(cx, |state, cx| {
+                state.connect_with_password(server_id.clone(), password, cx);
+            });
+            window.close_dialog(cx);
+            true
+        });
+        open_add_form_dialog(
+            FormDialog {
+                title: i18n_servers(cx, "password_prompt_title"),
+                fields: vec![
+                    FormField::new(i18n_common(cx, "password"))
+                        .with_placeholder(i18n_common(cx, "password_placeholder"))
+                        .with_masked()
+                        .with_focus(),
+                ],
+                handle_submit,
+            },
+            window,
+            cx,
+        );
+    }
+    /// Confirm overwriting an existing key with an imported file
+    fn open_import_value_dialog(&mut self, request: ImportValueRequest, window: &mut Window, cx: &mut Context<Self>) {
+        let server_state = self.server_state.clone();
+        window.open_dialog(cx, move |dialog, _, cx| {
+            let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+            let message = t!(
+                "key_tree.import_value_overwrite_prompt",
+                key = request.key,
+                locale = locale
+            )
+            .to_string();
+            let server_state = server_state.clone();
+            let request = request.clone();
+            dialog.confirm().child(message).on_ok(move |_, window, cx| {
+                let request = request.clone();
+                server_state.update(cx, move |state, cx| {
+                    state.import_value(request, true, cx);
+                });
+                window.close_dialog(cx);
+                true
+            })
+        });
+    }
     fn persist_window_state(&mut self, new_bounds: Bounds<Pixels>, cx: &mut Context<Self>) {
         self.last_bounds = new_bounds;
         let store = cx.global::<ZedisGlobalStore>().clone();
@@ -125,6 +281,18 @@ impl Zedis {
         });
         self.save_task = Some(task);
     }
+    /// Show the name and address of the selected server in the window title
+    fn update_window_title(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let state = self.server_state.read(cx);
+        let title: SharedString = match state.server(state.server_id()) {
+            Some(server) => format!("{} - {}", server.name, server.display_address()).into(),
+            None => "Zedis".into(),
+        };
+        if title != self.window_title {
+            window.set_window_title(&title);
+            self.window_title = title;
+        }
+    }
     fn render_titlebar(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
         let Some(title_bar) = self.title_bar.as_ref() else {
             return h_flex().into_any_element();
@@ -144,6 +312,16 @@ impl Render for Zedis {
         if let Some(notification) = self.pending_notification.take() {
             window.push_notification(notification, cx);
         }
+        if let Some((server_id, names)) = self.pending_sentinel_masters.take() {
+            self.open_sentinel_masters_dialog(server_id, names, window, cx);
+        }
+        if let Some(server_id) = self.pending_password_prompt.take() {
+            self.open_password_dialog(server_id, window, cx);
+        }
+        if let Some(request) = self.pending_import_value.take() {
+            self.open_import_value_dialog(request, window, cx);
+        }
+        self.update_window_title(window, cx);
         if let Some(font_size) = cx.global::<ZedisGlobalStore>().read(cx).font_size().to_pixels() {
             window.set_rem_size(font_size);
         }
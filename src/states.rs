@@ -18,19 +18,26 @@ mod server;
 
 pub use app::*;
 pub use i18n::i18n_common;
+pub use i18n::i18n_config;
+pub use i18n::i18n_dashboard;
 pub use i18n::i18n_editor;
 pub use i18n::i18n_hash_editor;
 pub use i18n::i18n_key_tree;
 pub use i18n::i18n_kv_table;
 pub use i18n::i18n_list_editor;
+pub use i18n::i18n_pubsub;
 pub use i18n::i18n_servers;
 pub use i18n::i18n_set_editor;
 pub use i18n::i18n_settings;
 pub use i18n::i18n_sidebar;
 pub use i18n::i18n_status_bar;
+pub use i18n::i18n_stream_editor;
 pub use i18n::i18n_zset_editor;
 pub use server::ErrorMessage;
 pub use server::ServerEvent;
 pub use server::ServerTask;
 pub use server::ZedisServerState;
+pub use server::audit::AuditEntry;
+pub use server::export::{ImportValueMode, ImportValueRequest};
+pub use server::stat::ServerPing;
 pub use server::value::*;
@@ -15,12 +15,14 @@
 use crate::constants::SIDEBAR_WIDTH;
 use crate::error::Error;
 use crate::helpers::{get_key_tree_widths, get_or_create_config_dir};
-use gpui::{Action, App, AppContext, Bounds, Context, Entity, Global, Pixels};
+use crate::states::KeyType;
+use gpui::{Action, App, AppContext, Bounds, Context, Entity, Global, Pixels, SharedString};
 use gpui_component::{PixelsExt, ThemeMode};
 use locale_config::Locale;
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
+use std::collections::HashMap;
 use std::path::PathBuf;
 use tracing::{error, info};
 
@@ -32,6 +34,9 @@ pub enum Route {
     Home,
     Editor,
     Settings,
+    PubSub,
+    Config,
+    Dashboard,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
@@ -49,6 +54,24 @@ impl FontSize {
             FontSize::Large => Some(18.0),
         }
     }
+    /// Font size in pixels of the code editor and the hex viewer
+    pub fn editor_pixels(self) -> f32 {
+        match self {
+            FontSize::Small => 12.0,
+            FontSize::Medium => 14.0,
+            FontSize::Large => 16.0,
+        }
+    }
+}
+
+/// Row density of the tables and the hex viewer
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum RowDensity {
+    /// Smaller rows to fit more data on screen
+    Compact,
+    /// Default rows with more spacing
+    #[default]
+    Comfortable,
 }
 
 /// Theme selection actions for the settings menu
@@ -84,6 +107,12 @@ pub enum SettingsAction {
 
 const LIGHT_THEME_MODE: &str = "light";
 const DARK_THEME_MODE: &str = "dark";
+// Auto-expand the key tree if fewer than this many keys are scanned
+const DEFAULT_AUTO_EXPAND_THRESHOLD: usize = 100;
+const DEFAULT_MAX_IMPORT_SIZE_MB: u64 = 10;
+const DEFAULT_MAX_STRING_LOAD_SIZE_MB: u64 = 1;
+// Max expanded folders stored per server
+const MAX_EXPANDED_FOLDERS: usize = 100;
 
 fn get_or_create_server_config() -> Result<PathBuf> {
     let config_dir = get_or_create_config_dir()?;
@@ -103,7 +132,32 @@ pub struct ZedisAppState {
     key_tree_width: Pixels,
     theme: Option<String>,
     font_size: Option<FontSize>,
+    // Font family of the code editor and the hex viewer
+    editor_font_family: Option<String>,
     max_key_tree_depth: Option<usize>,
+    auto_expand_threshold: Option<usize>,
+    refresh_on_focus: Option<bool>,
+    number_separators: Option<bool>,
+    row_density: Option<RowDensity>,
+    zebra_stripes: Option<bool>,
+    // Filter the loaded keys of the key tree while typing the keyword
+    live_filter: Option<bool>,
+    // Reconnect to the last selected server on launch
+    restore_last_server: Option<bool>,
+    // Id of the last selected server
+    last_server_id: Option<String>,
+    // Max size in MB of a file imported as a value
+    max_import_size_mb: Option<u64>,
+    // Strings larger than this size in MB are not loaded until requested
+    max_string_load_size_mb: Option<u64>,
+    // Bytes per line of the hex viewer, fitted to the viewport when unset
+    hex_bytes_per_line: Option<usize>,
+    // Soft wrap string values made of a single long line, follows the soft wrap of the server when unset
+    wrap_long_line: Option<bool>,
+    // Last used view mode per key type, e.g. "string" -> "Hex"
+    view_modes: Option<HashMap<String, String>>,
+    // Expanded folders of the key tree per server id, as their prefix e.g. "user:"
+    expanded_folders: Option<HashMap<String, Vec<String>>>,
 }
 
 #[derive(Debug, Clone)]
@@ -186,6 +240,12 @@ impl ZedisAppState {
     pub fn font_size(&self) -> FontSize {
         self.font_size.unwrap_or(FontSize::Medium)
     }
+    pub fn editor_font_family(&self) -> Option<&str> {
+        self.editor_font_family.as_deref()
+    }
+    pub fn set_editor_font_family(&mut self, editor_font_family: Option<String>) {
+        self.editor_font_family = editor_font_family;
+    }
     pub fn max_key_tree_depth(&self) -> usize {
         self.max_key_tree_depth.unwrap_or(5)
     }
@@ -196,6 +256,107 @@ impl ZedisAppState {
         }
         self.max_key_tree_depth = Some(max_key_tree_depth);
     }
+    pub fn auto_expand_threshold(&self) -> usize {
+        self.auto_expand_threshold.unwrap_or(DEFAULT_AUTO_EXPAND_THRESHOLD)
+    }
+    pub fn set_auto_expand_threshold(&mut self, auto_expand_threshold: Option<usize>) {
+        self.auto_expand_threshold = auto_expand_threshold;
+    }
+    pub fn refresh_on_focus(&self) -> bool {
+        self.refresh_on_focus.unwrap_or(false)
+    }
+    pub fn set_refresh_on_focus(&mut self, refresh_on_focus: bool) {
+        self.refresh_on_focus = Some(refresh_on_focus);
+    }
+    pub fn number_separators(&self) -> bool {
+        self.number_separators.unwrap_or(false)
+    }
+    pub fn set_number_separators(&mut self, number_separators: bool) {
+        self.number_separators = Some(number_separators);
+    }
+    pub fn row_density(&self) -> RowDensity {
+        self.row_density.unwrap_or_default()
+    }
+    pub fn set_row_density(&mut self, row_density: RowDensity) {
+        self.row_density = Some(row_density);
+    }
+    pub fn zebra_stripes(&self) -> bool {
+        self.zebra_stripes.unwrap_or(true)
+    }
+    pub fn set_zebra_stripes(&mut self, zebra_stripes: bool) {
+        self.zebra_stripes = Some(zebra_stripes);
+    }
+    pub fn live_filter(&self) -> bool {
+        self.live_filter.unwrap_or(false)
+    }
+    pub fn set_live_filter(&mut self, live_filter: bool) {
+        self.live_filter = Some(live_filter);
+    }
+    pub fn restore_last_server(&self) -> bool {
+        self.restore_last_server.unwrap_or(false)
+    }
+    pub fn set_restore_last_server(&mut self, restore_last_server: bool) {
+        self.restore_last_server = Some(restore_last_server);
+    }
+    pub fn last_server_id(&self) -> Option<&str> {
+        self.last_server_id.as_deref()
+    }
+    pub fn set_last_server_id(&mut self, last_server_id: Option<String>) {
+        self.last_server_id = last_server_id;
+    }
+    pub fn max_import_size_mb(&self) -> u64 {
+        self.max_import_size_mb.unwrap_or(DEFAULT_MAX_IMPORT_SIZE_MB)
+    }
+    pub fn set_max_import_size_mb(&mut self, max_import_size_mb: Option<u64>) {
+        self.max_import_size_mb = max_import_size_mb.filter(|size| *size > 0);
+    }
+    pub fn max_string_load_size_mb(&self) -> u64 {
+        self.max_string_load_size_mb.unwrap_or(DEFAULT_MAX_STRING_LOAD_SIZE_MB)
+    }
+    pub fn set_max_string_load_size_mb(&mut self, max_string_load_size_mb: Option<u64>) {
+        self.max_string_load_size_mb = max_string_load_size_mb.filter(|size| *size > 0);
+    }
+    pub fn hex_bytes_per_line(&self) -> Option<usize> {
+        self.hex_bytes_per_line
+    }
+    pub fn set_hex_bytes_per_line(&mut self, hex_bytes_per_line: Option<usize>) {
+        self.hex_bytes_per_line = hex_bytes_per_line.filter(|width| *width > 0);
+    }
+    pub fn wrap_long_line(&self) -> Option<bool> {
+        self.wrap_long_line
+    }
+    pub fn set_wrap_long_line(&mut self, wrap_long_line: bool) {
+        self.wrap_long_line = Some(wrap_long_line);
+    }
+    pub fn view_mode(&self, key_type: KeyType) -> Option<&str> {
+        self.view_modes.as_ref()?.get(key_type.name()).map(|mode| mode.as_str())
+    }
+    pub fn set_view_mode(&mut self, key_type: KeyType, view_mode: &str) {
+        self.view_modes
+            .get_or_insert_default()
+            .insert(key_type.name().to_string(), view_mode.to_string());
+    }
+    pub fn expanded_folders(&self, server_id: &str) -> Vec<SharedString> {
+        self.expanded_folders
+            .as_ref()
+            .and_then(|folders| folders.get(server_id))
+            .map(|folders| folders.iter().map(|folder| folder.clone().into()).collect())
+            .unwrap_or_default()
+    }
+    /// Stores the expanded folders of the server, at most `MAX_EXPANDED_FOLDERS` are kept.
+    ///
+    /// Folders are sorted so parents are kept before their children.
+    pub fn set_expanded_folders(&mut self, server_id: &str, folders: Vec<SharedString>) {
+        let expanded_folders = self.expanded_folders.get_or_insert_default();
+        if folders.is_empty() {
+            expanded_folders.remove(server_id);
+            return;
+        }
+        let mut folders: Vec<String> = folders.iter().map(|folder| folder.to_string()).collect();
+        folders.sort_unstable();
+        folders.truncate(MAX_EXPANDED_FOLDERS);
+        expanded_folders.insert(server_id.to_string(), folders);
+    }
     pub fn set_font_size(&mut self, font_size: Option<FontSize>) {
         self.font_size = font_size;
     }
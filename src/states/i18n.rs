@@ -72,7 +72,27 @@ pub fn i18n_hash_editor<'a>(cx: &'a App, key: &'a str) -> SharedString {
     t!(format!("hash_editor.{key}"), locale = locale).into()
 }
 
+pub fn i18n_stream_editor<'a>(cx: &'a App, key: &'a str) -> SharedString {
+    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+    t!(format!("stream_editor.{key}"), locale = locale).into()
+}
+
 pub fn i18n_settings<'a>(cx: &'a App, key: &'a str) -> SharedString {
     let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
     t!(format!("settings.{key}"), locale = locale).into()
 }
+
+pub fn i18n_config<'a>(cx: &'a App, key: &'a str) -> SharedString {
+    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+    t!(format!("config.{key}"), locale = locale).into()
+}
+
+pub fn i18n_pubsub<'a>(cx: &'a App, key: &'a str) -> SharedString {
+    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+    t!(format!("pubsub.{key}"), locale = locale).into()
+}
+
+pub fn i18n_dashboard<'a>(cx: &'a App, key: &'a str) -> SharedString {
+    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+    t!(format!("dashboard.{key}"), locale = locale).into()
+}
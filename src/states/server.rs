@@ -20,26 +20,43 @@ use crate::connection::save_servers;
 use crate::error::Error;
 use crate::helpers::unix_ts;
 use crate::states::NotificationAction;
-use crate::states::server::stat::RedisInfo;
+use crate::states::ZedisGlobalStore;
+use crate::states::i18n_servers;
+use crate::states::server::config::ConfigState;
+use crate::states::server::pubsub::PubSubState;
+use crate::states::server::stat::{RedisInfo, ServerPing};
+use crate::states::update_app_state_and_save;
 use ahash::AHashMap;
 use ahash::AHashSet;
+use audit::AuditEntry;
 use chrono::Local;
+use export::ImportValueRequest;
 use gpui::EventEmitter;
 use gpui::SharedString;
 use gpui::prelude::*;
+use key::KeyCount;
+use list::RemovedListValue;
 use parking_lot::RwLock;
+use rust_i18n::t;
+use std::collections::VecDeque;
 use std::str::FromStr;
 use std::sync::Arc;
 use tracing::debug;
 use tracing::error;
+use tracing::info;
 use uuid::Uuid;
 use value::{KeyType, RedisValue, RedisValueData};
 
+pub mod audit;
+pub mod config;
+pub mod export;
 pub mod hash;
 pub mod key;
 pub mod list;
+pub mod pubsub;
 pub mod set;
 pub mod stat;
+pub mod stream;
 pub mod string;
 pub mod value;
 pub mod zset;
@@ -84,6 +101,12 @@ pub enum RedisServerStatus {
 pub struct ZedisServerState {
     redis_info: Option<RedisInfo>,
 
+    /// Whether a connection test of the server dialog is running
+    testing_connection: bool,
+
+    /// PING result of each configured server
+    server_latencies: AHashMap<String, ServerPing>,
+
     /// Currently selected server id
     server_id: SharedString,
 
@@ -113,9 +136,21 @@ pub struct ZedisServerState {
     /// Currently selected key name
     key: Option<SharedString>,
 
+    /// Incremented on every key selection, only the latest result is applied
+    select_generation: u64,
+
     /// Value data for the currently selected key
     value: Option<RedisValue>,
 
+    /// Key whose huge string value the user chose to load anyway
+    force_loaded_key: Option<SharedString>,
+
+    /// Whether the value has unsaved edits in the editor or staged table edits
+    unsaved_value_edits: bool,
+
+    /// Recently removed list items, the latest one of a key can be restored
+    removed_list_values: Vec<RemovedListValue>,
+
     // ===== Key scanning state =====
     /// Search keyword for filtering keys
     keyword: SharedString,
@@ -129,21 +164,74 @@ pub struct ZedisServerState {
     /// Whether the current scan has completed
     scan_completed: bool,
 
+    /// Keep loading pages until the scan completes, ignoring the page limit
+    scan_all: bool,
+
     /// Number of scan iterations performed
     scan_times: usize,
 
+    /// MATCH pattern sent with the current scan
+    scan_pattern: SharedString,
+
+    /// COUNT hint sent with each SCAN of the current scan
+    scan_batch_count: u64,
+
+    /// Number of SCAN round trips of the current scan
+    scan_requests: usize,
+
+    /// Only scan keys of this type (SCAN TYPE on Redis 6.0+)
+    scan_type: Option<KeyType>,
+
+    /// Send the keyword as a glob pattern instead of matching it literally
+    raw_glob: bool,
+
+    /// Count-only scan of a pattern, the keys are not loaded
+    key_count: Option<KeyCount>,
+
+    /// Incremented to cancel the running count-only scan
+    key_count_generation: u64,
+
+    /// Deleted and total number of the running bulk delete of loaded keys
+    delete_keys_progress: Option<(usize, usize)>,
+
+    /// Incremented to stop the running bulk delete
+    delete_keys_generation: u64,
+
     /// Unique ID for current key tree (changes when keys are reloaded)
     key_tree_id: SharedString,
 
     /// Set of prefixes that have been scanned (for lazy loading folders)
     loaded_prefixes: AHashSet<SharedString>,
 
+    /// Prefixes whose key types failed to resolve, they can be retried
+    key_type_failed_prefixes: AHashSet<SharedString>,
+
     /// Map of all loaded keys and their types
     keys: AHashMap<SharedString, KeyType>,
 
+    /// Expiration time (unix seconds) of loaded keys, resolved with their types
+    key_expire_ats: AHashMap<SharedString, i64>,
+
+    /// Loaded keys whose TTL has been resolved, keys of a SCAN TYPE have a type but no TTL yet
+    key_ttl_loaded: AHashSet<SharedString>,
+
+    /// Internal encoding (OBJECT ENCODING) of loaded keys, fetched on demand
+    key_encodings: AHashMap<SharedString, SharedString>,
+
+    // ===== Pub/Sub state =====
+    /// Dedicated pub/sub connection, subscribed channels and received messages
+    pubsub: PubSubState,
+
+    // ===== Config state =====
+    /// Parameters listed by CONFIG GET
+    config: ConfigState,
+
     // ===== Error tracking =====
     /// Recent error messages (limited to MAX_ERROR_MESSAGES)
     error_messages: Arc<RwLock<Vec<ErrorMessage>>>,
+
+    /// Recent write operations (limited to MAX_AUDIT_ENTRIES), saved to disk
+    audit_log: Arc<RwLock<VecDeque<AuditEntry>>>,
 }
 
 /// Background task types for Redis operations
@@ -169,35 +257,79 @@ pub enum ServerTask {
     /// Add new server or update existing server configuration
     UpdateOrInsertServer,
 
+    /// Test the connection of an unsaved server configuration
+    TestConnection,
+
+    /// Save the chosen master of a sentinel monitoring multiple masters
+    SelectSentinelMaster,
+
     /// Fill in key types for unknown keys
     FillKeyTypes,
 
+    /// Fill in internal encodings for visible keys
+    FillKeyEncodings,
+
     /// Load value data for a selected key
     Selectkey,
 
     /// Delete a key from Redis
     DeleteKey,
 
+    /// Rename a key
+    RenameKey,
+
+    /// Copy a key to a new name
+    CopyKey,
+
     /// Scan for keys matching pattern
     ScanKeys,
 
     /// Scan keys with a specific prefix (for lazy folder loading)
     ScanPrefix,
 
+    /// Preview the first page of a scan pattern
+    PreviewScan,
+
+    /// Count the keys matching a pattern without loading them
+    CountKeys,
+
+    /// Delete the loaded keys in batches
+    DeleteKeys,
+
     /// Add a new key
     AddKey,
+    /// Export a key with its type and TTL to a JSON file
+    ExportKey,
+    /// Import a key from a JSON file
+    ImportKey,
+    /// Export the raw value of a key to a file
+    ExportValue,
+    /// Import a file as the value of a key
+    ImportValue,
     /// Update TTL (time-to-live) for a key
     UpdateKeyTtl,
 
     /// Delete an item from a list
     RemoveListValue,
 
+    /// Restore the latest removed item of a list
+    UndoRemoveListValue,
+
     /// Update a value in a list
     UpdateListValue,
 
     /// Push a value to a list
     PushListValue,
 
+    /// Insert a value before or after an item of a list
+    InsertListValue,
+
+    /// Trim a list to a range of items
+    TrimListValue,
+
+    /// Update multiple fields of a hash at once
+    UpdateHashValues,
+
     /// Load more items
     LoadMoreValue,
 
@@ -211,11 +343,33 @@ pub enum ServerTask {
     /// Remove a value from a zset
     RemoveZsetValue,
 
+    /// Add or update a field of a hash
+    AddHashValue,
     /// Remove a value from a hash
     RemoveHashValue,
 
+    /// Add an entry to a stream
+    AddStreamValue,
+    /// Remove an entry from a stream
+    RemoveStreamValue,
+
     /// Save edited value back to Redis
     SaveValue,
+
+    /// Subscribe to a pub/sub channel
+    Subscribe,
+    /// Unsubscribe from a pub/sub channel
+    Unsubscribe,
+
+    /// Load INFO of all masters for the dashboard
+    LoadServerInfo,
+    /// Ping every configured server for the server list
+    PingServers,
+
+    /// Load server parameters (CONFIG GET)
+    LoadConfig,
+    /// Update a server parameter (CONFIG SET)
+    SetConfig,
 }
 
 impl ServerTask {
@@ -223,28 +377,53 @@ impl ServerTask {
     pub fn as_str(&self) -> &'static str {
         match self {
             ServerTask::RefreshRedisInfo => "refresh_redis_info",
+            ServerTask::LoadServerInfo => "load_server_info",
+            ServerTask::PingServers => "ping_servers",
             ServerTask::SelectServer => "select_server",
+            ServerTask::SelectSentinelMaster => "select_sentinel_master",
             ServerTask::RemoveServer => "remove_server",
             ServerTask::UpdateOrInsertServer => "update_or_insert_server",
+            ServerTask::TestConnection => "test_connection",
             ServerTask::FillKeyTypes => "fill_key_types",
+            ServerTask::FillKeyEncodings => "fill_key_encodings",
             ServerTask::Selectkey => "select_key",
             ServerTask::DeleteKey => "delete_key",
+            ServerTask::RenameKey => "rename_key",
+            ServerTask::CopyKey => "copy_key",
             ServerTask::ScanKeys => "scan_keys",
             ServerTask::ScanPrefix => "scan_prefix",
+            ServerTask::PreviewScan => "preview_scan",
+            ServerTask::CountKeys => "count_keys",
+            ServerTask::DeleteKeys => "delete_keys",
             ServerTask::AddKey => "add_key",
+            ServerTask::ExportKey => "export_key",
+            ServerTask::ImportKey => "import_key",
+            ServerTask::ExportValue => "export_value",
+            ServerTask::ImportValue => "import_value",
             ServerTask::UpdateKeyTtl => "update_key_ttl",
             ServerTask::RemoveListValue => "remove_list_value",
+            ServerTask::UndoRemoveListValue => "undo_remove_list_value",
             ServerTask::UpdateListValue => "update_list_value",
             ServerTask::LoadMoreValue => "load_more_value",
             ServerTask::SaveValue => "save_value",
             ServerTask::UpdateServerQueryMode => "update_server_query_mode",
             ServerTask::UpdateServerSoftWrap => "update_server_soft_wrap",
             ServerTask::PushListValue => "push_list_value",
+            ServerTask::InsertListValue => "insert_list_value",
+            ServerTask::TrimListValue => "trim_list_value",
+            ServerTask::UpdateHashValues => "update_hash_values",
             ServerTask::AddSetValue => "add_set_value",
             ServerTask::RemoveSetValue => "remove_set_value",
             ServerTask::AddZsetValue => "add_zset_value",
             ServerTask::RemoveZsetValue => "remove_zset_value",
+            ServerTask::AddHashValue => "add_hash_value",
             ServerTask::RemoveHashValue => "remove_hash_value",
+            ServerTask::AddStreamValue => "add_stream_value",
+            ServerTask::RemoveStreamValue => "remove_stream_value",
+            ServerTask::Subscribe => "subscribe",
+            ServerTask::Unsubscribe => "unsubscribe",
+            ServerTask::LoadConfig => "load_config",
+            ServerTask::SetConfig => "set_config",
         }
     }
 }
@@ -267,6 +446,10 @@ pub enum ServerEvent {
     KeyScanFinished(SharedString),
     /// Key collapse all
     KeyCollapseAll,
+    /// Reveal the key in the key tree
+    KeyRevealed(SharedString),
+    /// Progress of the bulk delete, deleted and total number of keys
+    KeysDeleteProgress(usize, usize),
 
     /// A key's value has been fetched (initial load).
     ValueLoaded(SharedString),
@@ -274,12 +457,16 @@ pub enum ServerEvent {
     ValueUpdated(SharedString),
     /// A key's value view mode has been updated
     ValueModeViewUpdated(SharedString),
+    /// The syntax highlighting language of a key's value has been changed
+    ValueLanguageUpdated(SharedString),
     /// Load more value
     ValuePaginationStarted(SharedString),
     /// Load more value
     ValuePaginationFinished(SharedString),
     /// Add a value to a set、list、hash、zset
     ValueAdded(SharedString),
+    /// An item has been removed from the list, the removal can be undone
+    ListValueRemoved(SharedString),
 
     /// User selected a different server
     ServerSelected(SharedString),
@@ -287,9 +474,20 @@ pub enum ServerEvent {
     ServerListUpdated,
     /// Server metadata (info/dbsize) has been refreshed.
     ServerInfoUpdated(SharedString),
+    /// The sentinel monitors multiple masters, one of them has to be chosen.
+    SentinelMastersFound(SharedString, Vec<SharedString>),
+    /// The server asks for the password at connect time.
+    PasswordRequired(SharedString),
+    /// The key of a file import already exists, the overwrite has to be confirmed.
+    ImportValueKeyExists(ImportValueRequest),
     /// Periodic redis info updated.
     ServerRedisInfoUpdated(SharedString),
 
+    /// A pub/sub message has been received on the channel.
+    PubSubMessage(SharedString),
+    /// Pub/sub subscriptions have been added or removed.
+    PubSubSubscriptionsUpdated,
+
     /// Soft wrap changed
     SoftWrapToggled(bool),
     /// An error occurred.
@@ -303,7 +501,10 @@ impl EventEmitter<ServerEvent> for ZedisServerState {}
 impl ZedisServerState {
     /// Create a new server state instance
     pub fn new() -> Self {
-        Self::default()
+        Self {
+            audit_log: Arc::new(RwLock::new(audit::load_audit_log())),
+            ..Default::default()
+        }
     }
 
     /// Reset all scan-related state (clears keys, cursors, etc.)
@@ -313,11 +514,19 @@ impl ZedisServerState {
         self.keyword = SharedString::default();
         self.cursors = None;
         self.keys.clear();
+        self.key_expire_ats.clear();
+        self.key_ttl_loaded.clear();
+        self.key_encodings.clear();
         self.key_tree_id = Uuid::now_v7().to_string().into();
         self.scaning = false;
         self.scan_completed = false;
+        self.scan_all = false;
         self.scan_times = 0;
+        self.scan_pattern = SharedString::default();
+        self.scan_batch_count = 0;
+        self.scan_requests = 0;
         self.loaded_prefixes.clear();
+        self.key_type_failed_prefixes.clear();
     }
 
     /// Reset all state when switching to a different server
@@ -330,6 +539,14 @@ impl ZedisServerState {
         self.key = None;
         self.redis_info = None;
         self.value = None;
+        self.removed_list_values.clear();
+        self.pubsub = PubSubState::default();
+        self.config = ConfigState::default();
+        self.scan_type = None;
+        self.key_count = None;
+        self.key_count_generation += 1;
+        self.delete_keys_progress = None;
+        self.delete_keys_generation += 1;
         self.reset_scan();
     }
 
@@ -353,6 +570,18 @@ impl ZedisServerState {
         }
     }
 
+    /// Add keys returned by SCAN TYPE, their type is known without a TYPE round trip
+    fn extend_typed_keys(&mut self, keys: Vec<SharedString>, key_type: KeyType) {
+        if keys.is_empty() {
+            return;
+        }
+        self.keys.reserve(keys.len());
+        for key in keys {
+            self.keys.insert(key, key_type);
+        }
+        self.key_tree_id = Uuid::now_v7().to_string().into();
+    }
+
     /// Add an error message to the history and emit error event
     ///
     /// Maintains a rolling window of MAX_ERROR_MESSAGES most recent errors
@@ -420,11 +649,21 @@ impl ZedisServerState {
         .detach();
     }
     /// Update and save server configuration
-    fn update_and_save_server_config<F>(&mut self, task_name: ServerTask, cx: &mut Context<Self>, modifier: F)
-    where
+    ///
+    /// `rollback` restores the in-memory state if the config can not be saved,
+    /// so the UI never shows a value that was not persisted.
+    fn update_and_save_server_config<F, R>(
+        &mut self,
+        task_name: ServerTask,
+        cx: &mut Context<Self>,
+        modifier: F,
+        rollback: R,
+    ) where
         F: FnOnce(&mut RedisServer),
+        R: FnOnce(&mut Self) -> Option<ServerEvent> + Send + 'static,
     {
         let mut servers = self.servers.clone().unwrap_or_default();
+        let server_id = self.server_id.clone();
 
         if let Some(s) = servers.iter_mut().find(|s| s.id == self.server_id) {
             modifier(s);
@@ -437,14 +676,34 @@ impl ZedisServerState {
                 Ok(servers)
             },
             move |this, result, cx| {
-                if let Ok(servers) = result {
-                    this.servers = Some(servers);
+                match result {
+                    Ok(servers) => {
+                        this.servers = Some(servers);
+                    }
+                    Err(_) => {
+                        if let Some(event) = this.rollback_unsaved_config(&server_id, rollback) {
+                            cx.emit(event);
+                        }
+                    }
                 }
                 cx.notify();
             },
             cx,
         );
     }
+    /// Restores the in-memory state of a config change that could not be saved.
+    ///
+    /// Ignored if the user switched to a different server while saving.
+    /// Returns the event to emit for the restored state.
+    fn rollback_unsaved_config<R>(&mut self, server_id: &str, rollback: R) -> Option<ServerEvent>
+    where
+        R: FnOnce(&mut Self) -> Option<ServerEvent>,
+    {
+        if self.server_id != server_id {
+            return None;
+        }
+        rollback(self)
+    }
 
     fn try_get_mut_key_value(&mut self) -> Option<(SharedString, &mut RedisValue)> {
         let key = self.key.as_ref().filter(|k| !k.is_empty())?.clone();
@@ -474,26 +733,84 @@ impl ZedisServerState {
 
     /// Set the query mode (All/Prefix/Exact)
     pub fn set_query_mode(&mut self, mode: QueryMode, cx: &mut Context<Self>) {
-        self.query_mode = mode;
+        let rollback = self.change_query_mode(mode);
+        cx.notify();
 
-        self.update_and_save_server_config(ServerTask::UpdateServerQueryMode, cx, move |server| {
-            server.query_mode = Some(mode.to_string());
-        });
+        self.update_and_save_server_config(
+            ServerTask::UpdateServerQueryMode,
+            cx,
+            move |server| {
+                server.query_mode = Some(mode.to_string());
+            },
+            rollback,
+        );
+    }
+    /// Changes the query mode in memory, returns the rollback to the previous mode.
+    fn change_query_mode(&mut self, mode: QueryMode) -> impl FnOnce(&mut Self) -> Option<ServerEvent> + Send + 'static {
+        let previous_mode = self.query_mode;
+        self.query_mode = mode;
+        move |this: &mut Self| {
+            this.query_mode = previous_mode;
+            None
+        }
     }
     /// Set whether to soft wrap the editor
     pub fn set_soft_wrap(&mut self, soft_wrap: bool, cx: &mut Context<Self>) {
+        let previous_soft_wrap = self.soft_wrap;
         self.soft_wrap = soft_wrap;
         cx.emit(ServerEvent::SoftWrapToggled(self.soft_wrap));
 
-        self.update_and_save_server_config(ServerTask::UpdateServerSoftWrap, cx, move |server| {
-            server.soft_wrap = Some(soft_wrap);
-        });
+        self.update_and_save_server_config(
+            ServerTask::UpdateServerSoftWrap,
+            cx,
+            move |server| {
+                server.soft_wrap = Some(soft_wrap);
+            },
+            move |this| {
+                this.soft_wrap = previous_soft_wrap;
+                Some(ServerEvent::SoftWrapToggled(previous_soft_wrap))
+            },
+        );
     }
     /// Get the current query mode (All/Prefix/Exact)
     pub fn query_mode(&self) -> QueryMode {
         self.query_mode
     }
 
+    /// Get the key type the scan is filtered by
+    pub fn scan_type(&self) -> Option<KeyType> {
+        self.scan_type
+    }
+
+    /// Whether the keyword is sent as a raw glob pattern
+    pub fn raw_glob(&self) -> bool {
+        self.raw_glob
+    }
+
+    /// Switch between literal and raw glob keywords and restart the scan
+    pub fn set_raw_glob(&mut self, raw_glob: bool, cx: &mut Context<Self>) {
+        self.raw_glob = raw_glob;
+        // Exact mode reads the key directly, the keyword is never a pattern
+        if self.query_mode == QueryMode::Exact {
+            cx.notify();
+            return;
+        }
+        let keyword = self.keyword.clone();
+        self.handle_filter(keyword, cx);
+    }
+
+    /// Filter the scan by key type and restart it with the current keyword
+    pub fn set_scan_type(&mut self, key_type: Option<KeyType>, cx: &mut Context<Self>) {
+        self.scan_type = key_type;
+        // Exact mode does not scan, the filter applies to the next scan
+        if self.query_mode == QueryMode::Exact {
+            cx.notify();
+            return;
+        }
+        let keyword = self.keyword.clone();
+        self.handle_filter(keyword, cx);
+    }
+
     /// Check if the current scan has completed
     pub fn scan_completed(&self) -> bool {
         self.scan_completed
@@ -514,10 +831,34 @@ impl ZedisServerState {
         self.keys.len()
     }
 
+    /// Get the MATCH pattern sent with the current scan
+    pub fn scan_pattern(&self) -> &SharedString {
+        &self.scan_pattern
+    }
+
+    /// Get the COUNT hint sent with each SCAN of the current scan
+    pub fn scan_batch_count(&self) -> u64 {
+        self.scan_batch_count
+    }
+
+    /// Check if the scan is loading all remaining pages
+    pub fn scanning_all(&self) -> bool {
+        self.scan_all
+    }
+
+    /// Get the number of SCAN round trips and scan iterations of the current scan
+    pub fn scan_requests(&self) -> (usize, usize) {
+        (self.scan_requests, self.scan_times)
+    }
+
     /// Get the last measured latency to the server
     pub fn redis_info(&self) -> Option<&RedisInfo> {
         self.redis_info.as_ref()
     }
+    /// Get the PING result of the servers
+    pub fn server_latencies(&self) -> &AHashMap<String, ServerPing> {
+        &self.server_latencies
+    }
 
     /// Get cluster node counts (master, replica)
     pub fn nodes(&self) -> (usize, usize) {
@@ -569,10 +910,24 @@ impl ZedisServerState {
         &self.keys
     }
 
+    /// Get the internal encoding of keys, empty if it can not be resolved
+    pub fn key_encodings(&self) -> &AHashMap<SharedString, SharedString> {
+        &self.key_encodings
+    }
+
+    /// Get the expiration time (unix seconds) of keys with a TTL
+    pub fn key_expire_ats(&self) -> &AHashMap<SharedString, i64> {
+        &self.key_expire_ats
+    }
+
     /// Get the value data for the currently selected key
     pub fn value(&self) -> Option<&RedisValue> {
         self.value.as_ref()
     }
+    /// Marks the value as edited but not saved yet, reset when the value is loaded again
+    pub fn set_unsaved_value_edits(&mut self, unsaved: bool) {
+        self.unsaved_value_edits = unsaved;
+    }
 
     /// Get the key type of the currently selected value
     pub fn value_key_type(&self) -> Option<KeyType> {
@@ -586,6 +941,11 @@ impl ZedisServerState {
     pub fn remove_server(&mut self, id: &str, cx: &mut Context<Self>) {
         let mut servers = self.servers.clone().unwrap_or_default();
         servers.retain(|s| s.id != id);
+        if cx.global::<ZedisGlobalStore>().read(cx).last_server_id() == Some(id) {
+            update_app_state_and_save(cx, "clear_last_server_id", move |state, _cx| {
+                state.set_last_server_id(None);
+            });
+        }
 
         self.spawn(
             ServerTask::RemoveServer,
@@ -604,6 +964,119 @@ impl ZedisServerState {
         );
     }
 
+    /// Disconnect from a server and drop its cached connection
+    ///
+    /// The state is reset if the server is the active one.
+    ///
+    /// # Arguments
+    /// * `server_id` - Server id to disconnect from
+    /// * `cx` - Context for state updates
+    pub fn disconnect(&mut self, server_id: &str, cx: &mut Context<Self>) {
+        get_connection_manager().remove_client(server_id);
+        info!(server_id, "Server disconnected");
+        if self.server_id == server_id {
+            self.select(SharedString::default(), cx);
+        }
+        cx.notify();
+    }
+
+    /// Save the chosen sentinel master and reconnect to the server
+    ///
+    /// # Arguments
+    /// * `server_id` - Server id of the sentinel
+    /// * `master_name` - Master name picked from the SENTINEL MASTERS response
+    /// * `cx` - Context for spawning async task
+    pub fn select_sentinel_master(
+        &mut self,
+        server_id: SharedString,
+        master_name: SharedString,
+        cx: &mut Context<Self>,
+    ) {
+        let mut servers = self.servers.clone().unwrap_or_default();
+        let Some(server) = servers.iter_mut().find(|s| s.id == server_id.as_str()) else {
+            return;
+        };
+        server.master_name = Some(master_name.to_string());
+        server.updated_at = Some(Local::now().to_rfc3339());
+
+        self.spawn(
+            ServerTask::SelectSentinelMaster,
+            move || async move {
+                save_servers(servers.clone()).await?;
+                Ok(servers)
+            },
+            move |this, result, cx| {
+                let Ok(servers) = result else {
+                    return;
+                };
+                this.servers = Some(servers);
+                cx.emit(ServerEvent::ServerListUpdated);
+                // The failed client is not cached, connect again with the saved master name
+                get_connection_manager().reset_client(&server_id);
+                if this.server_id == server_id {
+                    this.server_id = SharedString::default();
+                }
+                this.select(server_id, cx);
+            },
+            cx,
+        );
+    }
+
+    /// Connect with the password entered at connect time
+    ///
+    /// The password is only kept in memory until the server is disconnected.
+    ///
+    /// # Arguments
+    /// * `server_id` - Server id to connect to
+    /// * `password` - Password entered by the user
+    /// * `cx` - Context for spawning async tasks and state updates
+    pub fn connect_with_password(&mut self, server_id: SharedString, password: SharedString, cx: &mut Context<Self>) {
+        get_connection_manager().set_password(&server_id, password.to_string());
+        if self.server_id == server_id {
+            self.server_id = SharedString::default();
+        }
+        self.select(server_id, cx);
+    }
+
+    /// Returns true while a connection test is running
+    pub fn testing_connection(&self) -> bool {
+        self.testing_connection
+    }
+    /// Tests the connection of the server config from the server dialog
+    ///
+    /// The result is shown as a notification, clicks while a test is running are ignored.
+    pub fn test_connection(&mut self, server: RedisServer, cx: &mut Context<Self>) {
+        if self.testing_connection {
+            return;
+        }
+        self.testing_connection = true;
+        cx.notify();
+        self.spawn(
+            ServerTask::TestConnection,
+            move || async move { get_connection_manager().test_connection(server).await },
+            move |this, result, cx| {
+                this.testing_connection = false;
+                let notification = match result {
+                    Ok((server_type, version)) => {
+                        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale().to_string();
+                        let message = t!(
+                            "servers.test_connection_success",
+                            server_type = server_type,
+                            version = version,
+                            locale = locale
+                        );
+                        NotificationAction::new_success(message.to_string().into())
+                    }
+                    Err(e) => NotificationAction::new_error(e.to_string().into()),
+                };
+                cx.emit(ServerEvent::Notification(
+                    notification.with_title(i18n_servers(cx, "test_connection")),
+                ));
+                cx.notify();
+            },
+            cx,
+        );
+    }
     /// Add new server or update existing server configuration
     ///
     /// # Arguments
@@ -688,6 +1161,12 @@ impl ZedisServerState {
                 return;
             }
 
+            // Remember the server to reconnect to it on the next launch
+            let last_server_id = self.server_id.to_string();
+            update_app_state_and_save(cx, "save_last_server_id", move |state, _cx| {
+                state.set_last_server_id(Some(last_server_id));
+            });
+
             // Set loading state
             self.server_status = RedisServerStatus::Loading;
             self.scaning = true;
@@ -706,7 +1185,7 @@ impl ZedisServerState {
                     let version = client.version().to_string();
                     let nodes = client.nodes();
                     let nodes_description = client.nodes_description();
-                    Ok((dbsize, nodes, nodes_description, version))
+                    Ok((dbsize, nodes, nodes_description, version, client.password_ignored()))
                 },
                 move |this, result, cx| {
                     // Ignore if user switched to a different server while loading
@@ -714,12 +1193,28 @@ impl ZedisServerState {
                         return;
                     }
 
+                    // Ask which master to use when the sentinel monitors several
+                    if let Err(Error::SentinelMasters { names }) = &result {
+                        let names = names.iter().map(|name| name.clone().into()).collect();
+                        cx.emit(ServerEvent::SentinelMastersFound(this.server_id.clone(), names));
+                    }
+                    if let Err(Error::PasswordRequired) = &result {
+                        cx.emit(ServerEvent::PasswordRequired(this.server_id.clone()));
+                    }
+
                     // Update metadata if successful
-                    if let Ok((dbsize, nodes, nodes_description, version)) = result {
+                    if let Ok((dbsize, nodes, nodes_description, version, password_ignored)) = result {
                         this.dbsize = Some(dbsize);
                         this.nodes = nodes;
                         this.nodes_description = Arc::new(nodes_description);
                         this.version = version.into();
+                        // Suggest removing the password that the server does not require
+                        if password_ignored {
+                            cx.emit(ServerEvent::Notification(
+                                NotificationAction::new_warning(i18n_servers(cx, "password_ignored"))
+                                    .with_title(i18n_servers(cx, "password_ignored_title")),
+                            ));
+                        }
                     };
 
                     let server_id = this.server_id.clone();
@@ -740,3 +1235,37 @@ impl ZedisServerState {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_mode_reverts_if_not_saved() {
+        let mut state = ZedisServerState {
+            server_id: "local".into(),
+            ..Default::default()
+        };
+        let rollback = state.change_query_mode(QueryMode::Prefix);
+        assert_eq!(state.query_mode(), QueryMode::Prefix);
+
+        // Saving the config failed
+        assert!(state.rollback_unsaved_config("local", rollback).is_none());
+        assert_eq!(state.query_mode(), QueryMode::All);
+    }
+
+    #[test]
+    fn test_query_mode_of_other_server_is_not_reverted() {
+        let mut state = ZedisServerState {
+            server_id: "local".into(),
+            ..Default::default()
+        };
+        let rollback = state.change_query_mode(QueryMode::Exact);
+        // The user switched to another server while saving
+        state.server_id = "remote".into();
+        state.query_mode = QueryMode::Prefix;
+
+        state.rollback_unsaved_config("local", rollback);
+        assert_eq!(state.query_mode(), QueryMode::Prefix);
+    }
+}
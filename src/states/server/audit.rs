@@ -0,0 +1,185 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Local audit trail of write operations.
+//!
+//! Every successful write (SET, DEL, EXPIRE, RENAME, ...) is appended to a
+//! bounded log with its target and time. Reads and scans are never logged.
+//! The log is saved as `audit.json` in the config directory, next to the app
+//! state, so it survives restarts.
+
+use super::{ServerTask, ZedisServerState};
+use crate::{
+    error::Error,
+    helpers::{get_or_create_config_dir, unix_ts},
+};
+use gpui::prelude::*;
+use parking_lot::{Mutex, RwLock};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::VecDeque,
+    path::PathBuf,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+use tracing::error;
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+// Maximum entries kept in memory and on disk
+const MAX_AUDIT_ENTRIES: usize = 500;
+const AUDIT_LOG_FILE: &str = "audit.json";
+// Writes in quick succession are saved together once this delay has passed
+const AUDIT_SAVE_DELAY: Duration = Duration::from_millis(500);
+
+// Whether a save of the audit log is scheduled and not started yet
+static AUDIT_SAVE_PENDING: AtomicBool = AtomicBool::new(false);
+// Only one save writes the file at a time
+static AUDIT_SAVE_LOCK: Mutex<()> = Mutex::new(());
+
+/// A write operation performed on a server
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// Name of the server, the id if it has been removed
+    pub server: String,
+    /// Task name of the operation, e.g. "delete_key"
+    pub action: String,
+    /// Key (or parameter) the operation was applied to
+    pub target: String,
+    /// Unix timestamp of the operation
+    pub created_at: i64,
+}
+
+fn get_audit_log_path() -> Result<PathBuf> {
+    Ok(get_or_create_config_dir()?.join(AUDIT_LOG_FILE))
+}
+
+/// Loads the saved audit log, empty if it does not exist or can't be parsed.
+pub(crate) fn load_audit_log() -> VecDeque<AuditEntry> {
+    let result: Result<VecDeque<AuditEntry>> = (|| {
+        let path = get_audit_log_path()?;
+        if !path.exists() {
+            return Ok(VecDeque::new());
+        }
+        let value = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&value)?)
+    })();
+    result.unwrap_or_else(|e| {
+        error!(error = %e, "load audit log fail");
+        VecDeque::new()
+    })
+}
+
+/// Saves the current entries of the audit log, the writes are serialized.
+fn save_audit_log(audit_log: &RwLock<VecDeque<AuditEntry>>) -> Result<()> {
+    let _guard = AUDIT_SAVE_LOCK.lock();
+    let value = serde_json::to_string(&*audit_log.read())?;
+    std::fs::write(get_audit_log_path()?, value)?;
+    Ok(())
+}
+
+/// Schedules a save of the audit log in the background.
+///
+/// Entries added before the save starts are written together.
+fn schedule_save_audit_log(audit_log: Arc<RwLock<VecDeque<AuditEntry>>>, cx: &mut Context<ZedisServerState>) {
+    if AUDIT_SAVE_PENDING.swap(true, Ordering::AcqRel) {
+        return;
+    }
+    cx.background_spawn(async move {
+        smol::Timer::after(AUDIT_SAVE_DELAY).await;
+        AUDIT_SAVE_PENDING.store(false, Ordering::Release);
+        if let Err(e) = save_audit_log(&audit_log) {
+            error!(error = %e, "save audit log fail");
+        }
+    })
+    .detach();
+}
+
+impl ServerTask {
+    /// Whether the task writes to Redis and is recorded in the audit log
+    pub fn is_write(&self) -> bool {
+        matches!(
+            self,
+            ServerTask::DeleteKey
+                | ServerTask::RenameKey
+                | ServerTask::CopyKey
+                | ServerTask::DeleteKeys
+                | ServerTask::AddKey
+                | ServerTask::ImportKey
+                | ServerTask::ImportValue
+                | ServerTask::UpdateKeyTtl
+                | ServerTask::RemoveListValue
+                | ServerTask::UndoRemoveListValue
+                | ServerTask::UpdateListValue
+                | ServerTask::PushListValue
+                | ServerTask::InsertListValue
+                | ServerTask::TrimListValue
+                | ServerTask::AddSetValue
+                | ServerTask::RemoveSetValue
+                | ServerTask::AddZsetValue
+                | ServerTask::RemoveZsetValue
+                | ServerTask::AddHashValue
+                | ServerTask::RemoveHashValue
+                | ServerTask::UpdateHashValues
+                | ServerTask::AddStreamValue
+                | ServerTask::RemoveStreamValue
+                | ServerTask::SaveValue
+                | ServerTask::SetConfig
+        )
+    }
+}
+
+impl ZedisServerState {
+    /// Appends a successful write to the audit log and saves it in the background.
+    ///
+    /// Only tasks where `is_write` is true are recorded, the oldest entry is
+    /// dropped once MAX_AUDIT_ENTRIES is reached. Bulk operations add a single
+    /// summary entry instead of one per key.
+    pub(crate) fn add_audit_entry(&mut self, task: &ServerTask, target: impl Into<String>, cx: &mut Context<Self>) {
+        if !task.is_write() {
+            return;
+        }
+        let server = self
+            .server(&self.server_id)
+            .map(|server| server.name.clone())
+            .unwrap_or_else(|| self.server_id.to_string());
+        {
+            let mut guard = self.audit_log.write();
+            while guard.len() >= MAX_AUDIT_ENTRIES {
+                guard.pop_front();
+            }
+            guard.push_back(AuditEntry {
+                server,
+                action: task.as_str().to_string(),
+                target: target.into(),
+                created_at: unix_ts(),
+            });
+        }
+        schedule_save_audit_log(self.audit_log.clone(), cx);
+        cx.notify();
+    }
+    /// Returns the audit log, newest entry first
+    pub fn audit_log(&self) -> Vec<AuditEntry> {
+        self.audit_log.read().iter().rev().cloned().collect()
+    }
+    /// Removes all entries of the audit log, in memory and on disk
+    pub fn clear_audit_log(&mut self, cx: &mut Context<Self>) {
+        self.audit_log.write().clear();
+        schedule_save_audit_log(self.audit_log.clone(), cx);
+        cx.notify();
+    }
+}
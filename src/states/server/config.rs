@@ -0,0 +1,167 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Redis server configuration module.
+//!
+//! This module provides functionality for tuning the server including:
+//! - Listing parameters matching a glob (CONFIG GET)
+//! - Updating a parameter on all master nodes (CONFIG SET)
+//! - Detecting servers where the CONFIG command is disabled or renamed
+
+use super::{ServerEvent, ServerTask, ZedisServerState};
+use crate::{
+    connection::get_connection_manager,
+    error::Error,
+    states::{NotificationAction, i18n_config},
+};
+use gpui::{SharedString, prelude::*};
+use redis::cmd;
+use std::collections::HashMap;
+
+// Pattern used when the config panel is opened
+const DEFAULT_CONFIG_PATTERN: &str = "*";
+
+/// State of the config panel
+#[derive(Debug, Clone, Default)]
+pub struct ConfigState {
+    /// Glob pattern of the last CONFIG GET
+    pattern: SharedString,
+    /// Parameters sorted by name
+    items: Vec<(SharedString, SharedString)>,
+    /// Whether CONFIG GET is running
+    loading: bool,
+    /// Whether the CONFIG command is disabled on the server
+    unsupported: bool,
+}
+
+impl ConfigState {
+    /// Get the glob pattern of the last CONFIG GET
+    pub fn pattern(&self) -> &SharedString {
+        &self.pattern
+    }
+    /// Get the parameters sorted by name
+    pub fn items(&self) -> &[(SharedString, SharedString)] {
+        &self.items
+    }
+    /// Whether CONFIG GET is running
+    pub fn loading(&self) -> bool {
+        self.loading
+    }
+    /// Whether the CONFIG command is disabled on the server
+    pub fn unsupported(&self) -> bool {
+        self.unsupported
+    }
+}
+
+/// Returns true if the error means the CONFIG command can not be used
+/// (renamed/disabled command or missing ACL permission).
+fn is_config_unsupported(err: &Error) -> bool {
+    let Error::Redis { source } = err else {
+        return false;
+    };
+    let message = source.to_string().to_lowercase();
+    message.contains("unknown command") || message.contains("noperm")
+}
+
+impl ZedisServerState {
+    /// Get the config panel state
+    pub fn config(&self) -> &ConfigState {
+        &self.config
+    }
+    /// Lists the parameters matching the glob pattern with CONFIG GET.
+    ///
+    /// # Arguments
+    /// * `pattern` - Glob pattern such as `maxmemory*`, `*` if empty
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn load_config(&mut self, pattern: SharedString, cx: &mut Context<Self>) {
+        let pattern: SharedString = if pattern.is_empty() {
+            DEFAULT_CONFIG_PATTERN.into()
+        } else {
+            pattern
+        };
+        let server_id = self.server_id.clone();
+        self.config.pattern = pattern.clone();
+        self.config.loading = true;
+        cx.notify();
+        self.spawn(
+            ServerTask::LoadConfig,
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id).await?;
+                let values: HashMap<String, String> = cmd("CONFIG")
+                    .arg("GET")
+                    .arg(pattern.as_str())
+                    .query_async(&mut conn)
+                    .await?;
+                let mut items: Vec<(SharedString, SharedString)> = values
+                    .into_iter()
+                    .map(|(name, value)| (name.into(), value.into()))
+                    .collect();
+                items.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+                Ok(items)
+            },
+            move |this, result, cx| {
+                this.config.loading = false;
+                match result {
+                    Ok(items) => {
+                        this.config.items = items;
+                    }
+                    Err(e) => {
+                        if is_config_unsupported(&e) {
+                            this.config.unsupported = true;
+                            this.config.items.clear();
+                        }
+                    }
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+    /// Updates a parameter with CONFIG SET on all master nodes.
+    ///
+    /// # Arguments
+    /// * `name` - Parameter name
+    /// * `value` - New value of the parameter
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn set_config(&mut self, name: SharedString, value: SharedString, cx: &mut Context<Self>) {
+        let server_id = self.server_id.clone();
+        let name_clone = name.clone();
+        let value_clone = value.clone();
+        self.spawn(
+            ServerTask::SetConfig,
+            move || async move {
+                let client = get_connection_manager().get_client(&server_id).await?;
+                let _: Vec<()> = client
+                    .query_async_masters(vec![
+                        cmd("CONFIG").arg("SET").arg(name.as_str()).arg(value.as_str()).clone(),
+                    ])
+                    .await?;
+                Ok(())
+            },
+            move |this, result, cx| {
+                if result.is_ok() {
+                    this.add_audit_entry(&ServerTask::SetConfig, format!("{name_clone} = {value_clone}"), cx);
+                    if let Some(item) = this.config.items.iter_mut().find(|(name, _)| name == &name_clone) {
+                        item.1 = value_clone;
+                    }
+                    cx.emit(ServerEvent::Notification(
+                        NotificationAction::new_success(i18n_config(cx, "update_success")).with_title(name_clone),
+                    ));
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+}
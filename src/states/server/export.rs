@@ -0,0 +1,662 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Single key export and import module.
+//!
+//! A key is written as a small JSON envelope `{ "key", "type", "ttl", "value" }`:
+//! - string: the value
+//! - list/set: an array of elements
+//! - zset: an array of `{ "member", "score" }`
+//! - hash: an object of field/value pairs
+//!
+//! If the string or one of the items is not UTF-8, all of them are base64
+//! encoded and the envelope has `"encoding": "base64"`.
+//! Importing the envelope recreates the key with the same type and expiry.
+//!
+//! The raw value can also be exported on its own: strings as is, lists and sets
+//! newline-delimited and hashes as a JSON object. If a field or value of a hash
+//! is not UTF-8, the object is `{ "encoding": "base64", "fields": {...} }` instead.
+//! A file is imported the same way, as a string or as the lines of a list or set.
+
+use super::{
+    ServerEvent, ServerTask, ZedisServerState,
+    value::{KeyType, RedisValue, RedisValueData},
+};
+use crate::{
+    connection::{RedisAsyncConn, get_connection_manager},
+    error::Error,
+    states::{NotificationAction, ZedisGlobalStore, i18n_editor, i18n_key_tree},
+};
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use gpui::{SharedString, prelude::*};
+use humansize::{DECIMAL, format_size};
+use redis::{Script, cmd, pipe};
+use rust_i18n::t;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+use std::{path::PathBuf, sync::LazyLock};
+use uuid::Uuid;
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+// Encoding of a string value or items that are not valid UTF-8
+const BASE64_ENCODING: &str = "base64";
+
+// Creates the key only if it does not exist, checked in the same step so a key
+// created meanwhile is never overwritten.
+// KEYS[1]: the key, ARGV[1]: the write command, ARGV[2]: seconds to live (0 if persistent),
+// the other arguments are passed to the command in batches of pairs.
+static CREATE_KEY_SCRIPT: LazyLock<Script> = LazyLock::new(|| {
+    Script::new(
+        r"
+if redis.call('EXISTS', KEYS[1]) == 1 then
+    return 0
+end
+local batch = 1000
+for i = 3, #ARGV, batch do
+    redis.call(ARGV[1], KEYS[1], unpack(ARGV, i, math.min(i + batch - 1, #ARGV)))
+end
+if tonumber(ARGV[2]) > 0 then
+    redis.call('EXPIRE', KEYS[1], ARGV[2])
+end
+return 1
+",
+    )
+});
+
+/// JSON envelope of an exported key
+#[derive(Debug, Serialize, Deserialize)]
+struct KeyEnvelope {
+    key: String,
+    #[serde(rename = "type")]
+    key_type: String,
+    /// Seconds to live, -1 if the key does not expire
+    ttl: i64,
+    value: Value,
+    /// Encoding of a binary string value
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    encoding: Option<String>,
+}
+
+fn invalid(message: &str) -> Error {
+    Error::Invalid {
+        message: message.to_string(),
+    }
+}
+
+/// Converts the items to strings, all of them are base64 encoded if one is not UTF-8.
+///
+/// Returns the strings and their encoding, None for UTF-8.
+fn encode_items(items: Vec<Vec<u8>>) -> (Vec<String>, Option<String>) {
+    if items.iter().all(|item| std::str::from_utf8(item).is_ok()) {
+        let items = items
+            .into_iter()
+            .map(|item| String::from_utf8(item).unwrap_or_default())
+            .collect();
+        return (items, None);
+    }
+    let items = items.iter().map(|item| BASE64.encode(item)).collect();
+    (items, Some(BASE64_ENCODING.to_string()))
+}
+
+/// Converts a string written by `encode_items` back to bytes.
+fn decode_item(item: String, encoding: Option<&str>) -> Result<Vec<u8>> {
+    if encoding != Some(BASE64_ENCODING) {
+        return Ok(item.into_bytes());
+    }
+    BASE64
+        .decode(item)
+        .map_err(|e| Error::Invalid { message: e.to_string() })
+}
+
+/// Converts the fields of a hash to the JSON object written by `export_value`.
+///
+/// Binary fields and values are base64 encoded like in `encode_items`,
+/// the object is then wrapped with its encoding.
+fn hash_to_json(fields: Vec<(Vec<u8>, Vec<u8>)>) -> Value {
+    let items = fields.into_iter().flat_map(|(field, value)| [field, value]).collect();
+    let (items, encoding) = encode_items(items);
+    let mut map = Map::with_capacity(items.len() / 2);
+    let mut items = items.into_iter();
+    while let (Some(field), Some(value)) = (items.next(), items.next()) {
+        map.insert(field, Value::String(value));
+    }
+    match encoding {
+        Some(encoding) => json!({ "encoding": encoding, "fields": map }),
+        None => Value::Object(map),
+    }
+}
+
+/// Writes a new key with the command and its arguments, see `CREATE_KEY_SCRIPT`.
+///
+/// Returns false if the key already exists, nothing is written then.
+async fn create_key(conn: &mut RedisAsyncConn, key: &str, command: &str, args: Vec<Vec<u8>>, ttl: i64) -> Result<bool> {
+    let mut invocation = CREATE_KEY_SCRIPT.key(key);
+    invocation.arg(command).arg(ttl.max(0));
+    for arg in args {
+        invocation.arg(arg);
+    }
+    let created: bool = invocation.invoke_async(conn).await?;
+    Ok(created)
+}
+
+/// Converts a JSON array to strings, non-string elements are kept as compact JSON.
+fn json_to_strings(value: Value) -> Result<Vec<String>> {
+    let Value::Array(items) = value else {
+        return Err(invalid("Value should be an array"));
+    };
+    Ok(items
+        .into_iter()
+        .map(|item| match item {
+            Value::String(s) => s,
+            other => other.to_string(),
+        })
+        .collect())
+}
+
+/// How a file is written to the key by `import_value`
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ImportValueMode {
+    /// The whole file as a string (SET)
+    #[default]
+    String,
+    /// Each line as an element of a list (RPUSH)
+    List,
+    /// Each line as a member of a set (SADD)
+    Set,
+}
+
+impl ImportValueMode {
+    fn key_type(&self) -> KeyType {
+        match self {
+            ImportValueMode::String => KeyType::String,
+            ImportValueMode::List => KeyType::List,
+            ImportValueMode::Set => KeyType::Set,
+        }
+    }
+}
+
+/// A file to import as the value of a key
+#[derive(Debug, Clone)]
+pub struct ImportValueRequest {
+    pub key: SharedString,
+    pub path: PathBuf,
+    pub mode: ImportValueMode,
+}
+
+/// Raw file content of a loaded value
+enum ValueContent {
+    Ready(Vec<u8>),
+    /// The items are read with LRANGE, the loaded ones may be a page and are converted to text
+    FetchList,
+    /// The members are read with SMEMBERS
+    FetchSet,
+    /// The fields are read with HGETALL
+    FetchHash,
+    /// The string was too large to be loaded, it is read with GET
+    FetchString,
+}
+
+/// Converts the loaded value to the content written by `export_value`.
+///
+/// Only the bytes of a string are written as loaded. The items of lists, sets
+/// and hashes are read again, the loaded ones may be a page or filtered, and
+/// have been converted to text which would change binary items.
+fn value_to_content(value: &RedisValue) -> Result<ValueContent> {
+    if value.is_deferred() {
+        return Ok(ValueContent::FetchString);
+    }
+    let content = match value.data.as_ref() {
+        Some(RedisValueData::Bytes(value)) => ValueContent::Ready(value.bytes.to_vec()),
+        Some(RedisValueData::List(_)) => ValueContent::FetchList,
+        Some(RedisValueData::Set(_)) => ValueContent::FetchSet,
+        Some(RedisValueData::Hash(_)) => ValueContent::FetchHash,
+        _ => {
+            return Err(Error::Invalid {
+                message: format!("Export of {} values is not supported", value.key_type().name()),
+            });
+        }
+    };
+    Ok(content)
+}
+
+impl ZedisServerState {
+    /// Exports the key with its type and TTL to a JSON file.
+    ///
+    /// The whole value is read from Redis, not only the loaded page.
+    ///
+    /// # Arguments
+    /// * `key` - The key to export
+    /// * `path` - Path of the JSON file
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn export_key(&mut self, key: SharedString, path: PathBuf, cx: &mut Context<Self>) {
+        let server_id = self.server_id.clone();
+        let path_clone = path.clone();
+        self.spawn(
+            ServerTask::ExportKey,
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id).await?;
+                let (t, ttl): (String, i64) = pipe()
+                    .cmd("TYPE")
+                    .arg(key.as_str())
+                    .cmd("TTL")
+                    .arg(key.as_str())
+                    .query_async(&mut conn)
+                    .await?;
+                if ttl == -2 {
+                    return Err(invalid("Key does not exist"));
+                }
+                let key_type = KeyType::from(t.as_str());
+                // Items are read as bytes, binary ones are base64 encoded
+                let (value, encoding) = match key_type {
+                    KeyType::String => {
+                        let bytes: Vec<u8> = cmd("GET").arg(key.as_str()).query_async(&mut conn).await?;
+                        let (mut items, encoding) = encode_items(vec![bytes]);
+                        (Value::String(items.pop().unwrap_or_default()), encoding)
+                    }
+                    KeyType::List => {
+                        let items: Vec<Vec<u8>> = cmd("LRANGE")
+                            .arg(key.as_str())
+                            .arg(0)
+                            .arg(-1)
+                            .query_async(&mut conn)
+                            .await?;
+                        let (items, encoding) = encode_items(items);
+                        (json!(items), encoding)
+                    }
+                    KeyType::Set => {
+                        let items: Vec<Vec<u8>> = cmd("SMEMBERS").arg(key.as_str()).query_async(&mut conn).await?;
+                        let (items, encoding) = encode_items(items);
+                        (json!(items), encoding)
+                    }
+                    KeyType::Zset => {
+                        let items: Vec<(Vec<u8>, f64)> = cmd("ZRANGE")
+                            .arg(key.as_str())
+                            .arg(0)
+                            .arg(-1)
+                            .arg("WITHSCORES")
+                            .query_async(&mut conn)
+                            .await?;
+                        let (members, scores): (Vec<_>, Vec<_>) = items.into_iter().unzip();
+                        let (members, encoding) = encode_items(members);
+                        let items = members
+                            .into_iter()
+                            .zip(scores)
+                            .map(|(member, score)| json!({ "member": member, "score": score }))
+                            .collect();
+                        (Value::Array(items), encoding)
+                    }
+                    KeyType::Hash => {
+                        let items: Vec<(Vec<u8>, Vec<u8>)> =
+                            cmd("HGETALL").arg(key.as_str()).query_async(&mut conn).await?;
+                        let items = items.into_iter().flat_map(|(field, value)| [field, value]).collect();
+                        let (items, encoding) = encode_items(items);
+                        let map: Map<String, Value> = items
+                            .chunks_exact(2)
+                            .map(|pair| (pair[0].clone(), Value::String(pair[1].clone())))
+                            .collect();
+                        (Value::Object(map), encoding)
+                    }
+                    _ => {
+                        return Err(Error::Invalid {
+                            message: format!("Export of {} keys is not supported", key_type.name()),
+                        });
+                    }
+                };
+                let envelope = KeyEnvelope {
+                    key: key.to_string(),
+                    key_type: key_type.name().to_string(),
+                    ttl,
+                    value,
+                    encoding,
+                };
+                smol::fs::write(&path, serde_json::to_vec_pretty(&envelope)?).await?;
+                Ok(())
+            },
+            move |_this, result, cx| {
+                if result.is_ok() {
+                    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale().to_string();
+                    let message = t!(
+                        "editor.export_key_success_tips",
+                        path = path_clone.display(),
+                        locale = locale
+                    );
+                    cx.emit(ServerEvent::Notification(
+                        NotificationAction::new_success(message.to_string().into())
+                            .with_title(i18n_editor(cx, "export_key_success")),
+                    ));
+                }
+            },
+            cx,
+        );
+    }
+    /// Imports a key from a JSON file written by `export_key`.
+    ///
+    /// The key is created in one script together with its expiry,
+    /// an existing key is never overwritten.
+    ///
+    /// # Arguments
+    /// * `path` - Path of the JSON file
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn import_key(&mut self, path: PathBuf, cx: &mut Context<Self>) {
+        let server_id = self.server_id.clone();
+        self.spawn(
+            ServerTask::ImportKey,
+            move || async move {
+                let data = smol::fs::read(&path).await?;
+                let envelope: KeyEnvelope = serde_json::from_slice(&data)?;
+                let key = envelope.key;
+                if key.is_empty() {
+                    return Err(invalid("Key is required"));
+                }
+                let key_type = KeyType::from(envelope.key_type.as_str());
+                let encoding = envelope.encoding.as_deref();
+
+                let (command, args) = match key_type {
+                    KeyType::String => {
+                        let Value::String(text) = envelope.value else {
+                            return Err(invalid("Value of a string key should be a string"));
+                        };
+                        ("SET", vec![decode_item(text, encoding)?])
+                    }
+                    KeyType::List | KeyType::Set => {
+                        let items = json_to_strings(envelope.value)?;
+                        if items.is_empty() {
+                            return Err(invalid("Value should not be empty"));
+                        }
+                        let items = items
+                            .into_iter()
+                            .map(|item| decode_item(item, encoding))
+                            .collect::<Result<Vec<_>>>()?;
+                        let cmd_name = if key_type == KeyType::List { "RPUSH" } else { "SADD" };
+                        (cmd_name, items)
+                    }
+                    KeyType::Zset => {
+                        let Value::Array(items) = envelope.value else {
+                            return Err(invalid("Value of a zset key should be an array"));
+                        };
+                        if items.is_empty() {
+                            return Err(invalid("Value should not be empty"));
+                        }
+                        let mut args = Vec::with_capacity(items.len() * 2);
+                        for item in items {
+                            let member = item.get("member").and_then(|v| v.as_str());
+                            let score = item.get("score").and_then(|v| v.as_f64());
+                            let (Some(member), Some(score)) = (member, score) else {
+                                return Err(invalid("Zset item should have a member and a score"));
+                            };
+                            args.push(score.to_string().into_bytes());
+                            args.push(decode_item(member.to_string(), encoding)?);
+                        }
+                        ("ZADD", args)
+                    }
+                    KeyType::Hash => {
+                        let Value::Object(map) = envelope.value else {
+                            return Err(invalid("Value of a hash key should be an object"));
+                        };
+                        if map.is_empty() {
+                            return Err(invalid("Value should not be empty"));
+                        }
+                        let mut args = Vec::with_capacity(map.len() * 2);
+                        for (field, value) in map {
+                            let value = match value {
+                                Value::String(s) => s,
+                                other => other.to_string(),
+                            };
+                            args.push(decode_item(field, encoding)?);
+                            args.push(decode_item(value, encoding)?);
+                        }
+                        // HSET only takes several fields since Redis 4.0
+                        let client = get_connection_manager().get_client(&server_id).await?;
+                        let command = if client.supports_multi_field_hset() {
+                            "HSET"
+                        } else {
+                            "HMSET"
+                        };
+                        (command, args)
+                    }
+                    _ => {
+                        return Err(Error::Invalid {
+                            message: format!("Import of {} keys is not supported", envelope.key_type),
+                        });
+                    }
+                };
+
+                let mut conn = get_connection_manager().get_connection(&server_id).await?;
+                // The existence check and the writes run as one script
+                if !create_key(&mut conn, &key, command, args, envelope.ttl).await? {
+                    return Err(invalid("Key already exists"));
+                }
+                Ok((SharedString::from(key), key_type))
+            },
+            move |this, result, cx| {
+                if let Ok((key, key_type)) = result {
+                    this.add_audit_entry(&ServerTask::ImportKey, key.as_str(), cx);
+                    this.keys.insert(key.clone(), key_type);
+                    this.key_tree_id = Uuid::now_v7().to_string().into();
+                    cx.emit(ServerEvent::Notification(NotificationAction::new_success(
+                        i18n_key_tree(cx, "import_key_success"),
+                    )));
+                    this.select_written_key(key, cx);
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+    /// Exports the raw value of the selected key to a file.
+    ///
+    /// A loaded string is written without a round-trip, a string too large to be
+    /// loaded is read with `GET`. Lists, sets and hashes are read again with
+    /// `LRANGE 0 -1`, `SMEMBERS` and `HGETALL`. List and set items are written
+    /// as raw bytes, binary hash fields are base64 encoded, see `hash_to_json`.
+    ///
+    /// # Arguments
+    /// * `key` - The key to export
+    /// * `path` - Path of the file
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn export_value(&mut self, key: SharedString, path: PathBuf, cx: &mut Context<Self>) {
+        let Some(value) = self.value.clone() else {
+            return;
+        };
+        let server_id = self.server_id.clone();
+        let path_clone = path.clone();
+        self.spawn(
+            ServerTask::ExportValue,
+            move || async move {
+                let content = match value_to_content(&value)? {
+                    ValueContent::Ready(content) => content,
+                    ValueContent::FetchList => {
+                        let mut conn = get_connection_manager().get_connection(&server_id).await?;
+                        let items: Vec<Vec<u8>> = cmd("LRANGE")
+                            .arg(key.as_str())
+                            .arg(0)
+                            .arg(-1)
+                            .query_async(&mut conn)
+                            .await?;
+                        items.join(&b'\n')
+                    }
+                    ValueContent::FetchSet => {
+                        let mut conn = get_connection_manager().get_connection(&server_id).await?;
+                        let members: Vec<Vec<u8>> = cmd("SMEMBERS").arg(key.as_str()).query_async(&mut conn).await?;
+                        members.join(&b'\n')
+                    }
+                    ValueContent::FetchHash => {
+                        let mut conn = get_connection_manager().get_connection(&server_id).await?;
+                        let fields: Vec<(Vec<u8>, Vec<u8>)> =
+                            cmd("HGETALL").arg(key.as_str()).query_async(&mut conn).await?;
+                        serde_json::to_vec_pretty(&hash_to_json(fields))?
+                    }
+                    ValueContent::FetchString => {
+                        let mut conn = get_connection_manager().get_connection(&server_id).await?;
+                        cmd("GET").arg(key.as_str()).query_async(&mut conn).await?
+                    }
+                };
+                smol::fs::write(&path, content).await?;
+                Ok(())
+            },
+            move |_this, result, cx| {
+                if result.is_ok() {
+                    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale().to_string();
+                    let message = t!(
+                        "editor.export_value_success_tips",
+                        path = path_clone.display(),
+                        locale = locale
+                    );
+                    cx.emit(ServerEvent::Notification(
+                        NotificationAction::new_success(message.to_string().into())
+                            .with_title(i18n_editor(cx, "export_value_success")),
+                    ));
+                }
+            },
+            cx,
+        );
+    }
+    /// Imports a file as the value of a key.
+    ///
+    /// Files larger than the configured size are rejected. If the key exists
+    /// and `overwrite` is false nothing is written, `ImportValueKeyExists` is
+    /// emitted so the overwrite can be confirmed.
+    ///
+    /// # Arguments
+    /// * `request` - The key, the file and how it is written
+    /// * `overwrite` - Whether to replace an existing key
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn import_value(&mut self, request: ImportValueRequest, overwrite: bool, cx: &mut Context<Self>) {
+        let server_id = self.server_id.clone();
+        let max_size = cx.global::<ZedisGlobalStore>().read(cx).max_import_size_mb() * 1024 * 1024;
+        let request_clone = request.clone();
+        self.spawn(
+            ServerTask::ImportValue,
+            move || async move {
+                let ImportValueRequest { key, path, mode } = request;
+                if key.is_empty() {
+                    return Err(invalid("Key is required"));
+                }
+                let size = smol::fs::metadata(&path).await?.len();
+                if size > max_size {
+                    return Err(Error::Invalid {
+                        message: format!(
+                            "File is {}, larger than the import limit of {}",
+                            format_size(size, DECIMAL),
+                            format_size(max_size, DECIMAL)
+                        ),
+                    });
+                }
+                let data = smol::fs::read(&path).await?;
+
+                let (command, args) = match mode {
+                    ImportValueMode::String => ("SET", vec![data]),
+                    ImportValueMode::List | ImportValueMode::Set => {
+                        // Empty lines (e.g. the trailing newline) are skipped
+                        let items: Vec<Vec<u8>> = data
+                            .split(|b| *b == b'\n')
+                            .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+                            .filter(|line| !line.is_empty())
+                            .map(|line| line.to_vec())
+                            .collect();
+                        if items.is_empty() {
+                            return Err(invalid("Value should not be empty"));
+                        }
+                        let cmd_name = if mode == ImportValueMode::List { "RPUSH" } else { "SADD" };
+                        (cmd_name, items)
+                    }
+                };
+
+                let mut conn = get_connection_manager().get_connection(&server_id).await?;
+                if !overwrite {
+                    // The existence check and the writes run as one script
+                    if !create_key(&mut conn, &key, command, args, 0).await? {
+                        return Ok(None);
+                    }
+                    return Ok(Some((key, mode.key_type())));
+                }
+                // The old value and its expiry are replaced as a whole
+                let _: () = pipe()
+                    .atomic()
+                    .cmd("DEL")
+                    .arg(key.as_str())
+                    .cmd(command)
+                    .arg(key.as_str())
+                    .arg(args)
+                    .query_async(&mut conn)
+                    .await?;
+                Ok(Some((key, mode.key_type())))
+            },
+            move |this, result, cx| {
+                match result {
+                    Ok(Some((key, key_type))) => {
+                        this.add_audit_entry(&ServerTask::ImportValue, key.as_str(), cx);
+                        this.keys.insert(key.clone(), key_type);
+                        this.key_tree_id = Uuid::now_v7().to_string().into();
+                        cx.emit(ServerEvent::Notification(NotificationAction::new_success(
+                            i18n_key_tree(cx, "import_value_success"),
+                        )));
+                        this.select_written_key(key, cx);
+                    }
+                    Ok(None) => {
+                        cx.emit(ServerEvent::ImportValueKeyExists(request_clone));
+                    }
+                    Err(_) => {}
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_items() {
+        let (items, encoding) = encode_items(vec![b"tree".to_vec(), b"xie".to_vec()]);
+        assert_eq!(items, vec!["tree", "xie"]);
+        assert_eq!(encoding, None);
+
+        // One binary item encodes all of them
+        let binary = vec![b"tree".to_vec(), vec![0xff, 0x00, 0xfe]];
+        let (items, encoding) = encode_items(binary.clone());
+        assert_eq!(encoding.as_deref(), Some(BASE64_ENCODING));
+        let decoded = items
+            .into_iter()
+            .map(|item| decode_item(item, encoding.as_deref()))
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(decoded, binary);
+    }
+
+    #[test]
+    fn test_hash_to_json() {
+        let fields = vec![(b"name".to_vec(), b"tree".to_vec())];
+        assert_eq!(hash_to_json(fields), json!({ "name": "tree" }));
+
+        let fields = vec![
+            (b"name".to_vec(), b"tree".to_vec()),
+            (b"data".to_vec(), vec![0xff, 0x00]),
+        ];
+        assert_eq!(
+            hash_to_json(fields),
+            json!({
+                "encoding": BASE64_ENCODING,
+                "fields": {
+                    BASE64.encode("name"): BASE64.encode("tree"),
+                    BASE64.encode("data"): BASE64.encode([0xff, 0x00]),
+                },
+            })
+        );
+    }
+}
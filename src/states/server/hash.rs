@@ -17,21 +17,25 @@
 //! This module provides functionality for managing Redis HASH operations including:
 //! - Loading HASH field-value pairs with pagination support via HSCAN
 //! - Adding/updating fields in a HASH (HSET)
+//! - Updating multiple fields at once (HSET, or HMSET before Redis 4.0)
 //! - Removing fields from a HASH (HDEL)
 //! - Filtering HASH fields with pattern matching
 //! - Efficient incremental loading for large HASHes
 
 use super::{
     KeyType, RedisValueData, ServerTask, ZedisServerState,
+    key::keyword_pattern,
     value::{RedisHashValue, RedisValue, RedisValueStatus},
 };
 use crate::{
     connection::{RedisAsyncConn, get_connection_manager},
     error::Error,
-    states::{NotificationAction, ServerEvent, i18n_hash_editor},
+    states::{NotificationAction, ServerEvent, ZedisGlobalStore, i18n_hash_editor},
 };
+use ahash::AHashSet;
 use gpui::{SharedString, prelude::*};
 use redis::cmd;
+use rust_i18n::t;
 use std::sync::Arc;
 
 type Result<T, E = Error> = std::result::Result<T, E>;
@@ -57,11 +61,8 @@ async fn get_redis_hash_value(
     cursor: u64,
     count: usize,
 ) -> Result<(u64, Vec<(SharedString, SharedString)>)> {
-    // Build pattern: wrap keyword with wildcards or match all fields
-    let pattern = keyword
-        .as_ref()
-        .map(|kw| format!("*{}*", kw))
-        .unwrap_or_else(|| "*".to_string());
+    // Build pattern: wrap the escaped keyword with wildcards or match all fields
+    let pattern = keyword_pattern(keyword.as_deref().unwrap_or_default(), false);
 
     // Execute HSCAN with MATCH and COUNT options
     let (next_cursor, raw_values): HashScanValue = cmd("HSCAN")
@@ -166,8 +167,9 @@ impl ZedisServerState {
         let new_field_clone = new_field.clone();
         let new_value_clone = new_value.clone();
 
+        let audit_target = key_clone.to_string();
         self.spawn(
-            ServerTask::AddSetValue,
+            ServerTask::AddHashValue,
             // Async operation: execute HSET on Redis
             move || async move {
                 let mut conn = get_connection_manager().get_connection(&server_id).await?;
@@ -183,6 +185,9 @@ impl ZedisServerState {
             },
             // UI callback: handle result and update local state
             move |this, result, cx| {
+                if result.is_ok() {
+                    this.add_audit_entry(&ServerTask::AddHashValue, audit_target, cx);
+                }
                 let title = i18n_hash_editor(cx, "add_value_success");
                 let msg = i18n_hash_editor(cx, "add_value_success_tips");
                 let update_exist_field_value_msg = i18n_hash_editor(cx, "update_exist_field_value_success_tips");
@@ -227,6 +232,90 @@ impl ZedisServerState {
             cx,
         );
     }
+    /// Updates multiple fields of the Redis HASH with a single command.
+    ///
+    /// The local values are updated right away and restored if the command fails.
+    /// Uses HSET with multiple field/value pairs, or HMSET before Redis 4.0.
+    ///
+    /// # Arguments
+    /// * `new_values` - The field/value pairs to set
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn update_hash_values(&mut self, new_values: Vec<(SharedString, SharedString)>, cx: &mut Context<Self>) {
+        if new_values.is_empty() {
+            return;
+        }
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+        value.status = RedisValueStatus::Updating;
+
+        // Apply the new values locally, keep the original ones for the rollback
+        let mut original_values = Vec::with_capacity(new_values.len());
+        if let Some(RedisValueData::Hash(hash_data)) = value.data.as_mut() {
+            let hash = Arc::make_mut(hash_data);
+            for (field, new_value) in &new_values {
+                if let Some(item) = hash.values.iter_mut().find(|item| item.0 == *field) {
+                    original_values.push((field.clone(), item.1.clone()));
+                    item.1 = new_value.clone();
+                }
+            }
+        }
+        cx.emit(ServerEvent::ValueUpdated(key.clone()));
+        cx.notify();
+
+        let server_id = self.server_id.clone();
+        let key_clone = key.clone();
+        let count = new_values.len();
+        let audit_target = format!("{key} ({count})");
+        self.spawn(
+            ServerTask::UpdateHashValues,
+            move || async move {
+                let client = get_connection_manager().get_client(&server_id).await?;
+                let mut conn = client.connection();
+                let cmd_name = if client.supports_multi_field_hset() {
+                    "HSET"
+                } else {
+                    "HMSET"
+                };
+                let mut command = cmd(cmd_name);
+                command.arg(key.as_str());
+                for (field, value) in &new_values {
+                    command.arg(field.as_str()).arg(value.as_str());
+                }
+                let _: () = command.query_async(&mut conn).await?;
+                Ok(())
+            },
+            move |this, result, cx| {
+                if result.is_ok() {
+                    this.add_audit_entry(&ServerTask::UpdateHashValues, audit_target, cx);
+                }
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                    if result.is_err()
+                        && this.key.as_ref() == Some(&key_clone)
+                        && let Some(RedisValueData::Hash(hash_data)) = value.data.as_mut()
+                    {
+                        let hash = Arc::make_mut(hash_data);
+                        for (field, original_value) in original_values {
+                            if let Some(item) = hash.values.iter_mut().find(|item| item.0 == field) {
+                                item.1 = original_value;
+                            }
+                        }
+                    }
+                }
+                if result.is_ok() {
+                    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale().to_string();
+                    let message = t!("hash_editor.update_values_success", count = count, locale = locale);
+                    cx.emit(ServerEvent::Notification(NotificationAction::new_success(
+                        message.to_string().into(),
+                    )));
+                }
+                cx.emit(ServerEvent::ValueUpdated(key_clone));
+                cx.notify();
+            },
+            cx,
+        );
+    }
     /// Applies a filter to HASH fields by resetting the scan state with a keyword.
     ///
     /// Creates a new HASH value state with the filter keyword and triggers a load.
@@ -275,6 +364,7 @@ impl ZedisServerState {
         let remove_field_clone = remove_field.clone();
         let key_clone = key.clone();
 
+        let audit_target = key_clone.to_string();
         self.spawn(
             ServerTask::RemoveHashValue,
             // Async operation: execute HDEL on Redis
@@ -291,6 +381,9 @@ impl ZedisServerState {
             },
             // UI callback: update local state to reflect removal
             move |this, result, cx| {
+                if result.is_ok() {
+                    this.add_audit_entry(&ServerTask::RemoveHashValue, audit_target, cx);
+                }
                 if let Ok(count) = result {
                     // Only update if field was actually removed
                     if count != 0
@@ -369,9 +462,13 @@ impl ZedisServerState {
                         hash.done = true;
                     }
 
-                    // Append new field-value pairs to existing list
+                    // Append new field-value pairs to existing list,
+                    // HSCAN may return a field more than once while the hash is rehashed
                     if !new_values.is_empty() {
-                        hash.values.extend(new_values);
+                        let loaded: AHashSet<SharedString> =
+                            hash.values.iter().map(|(field, _)| field.clone()).collect();
+                        hash.values
+                            .extend(new_values.into_iter().filter(|(field, _)| !loaded.contains(field)));
                     }
                     if !hash.done && hash.values.len() < 50 {
                         should_load_more = true;
@@ -17,29 +17,93 @@ use super::{
     hash::first_load_hash_value,
     list::first_load_list_value,
     set::first_load_set_value,
-    string::get_redis_value,
-    value::{KeyType, RedisValue, RedisValueStatus, SortOrder},
+    stream::first_load_stream_value,
+    string::get_redis_value_within,
+    value::{ExpireCondition, KeyType, RedisValue, RedisValueData, RedisValueStatus, SortOrder, ViewMode},
     zset::first_load_zset_value,
 };
 use crate::{
     connection::{QueryMode, get_connection_manager},
     error::Error,
     helpers::unix_ts,
+    states::{NotificationAction, ZedisGlobalStore, i18n_editor, i18n_key_tree},
 };
+use ahash::AHashSet;
+use chrono::DateTime;
 use futures::{StreamExt, stream};
 use gpui::{SharedString, prelude::*};
 use redis::{cmd, pipe};
-use std::time::Duration;
+use rust_i18n::t;
+use std::{sync::Arc, time::Duration};
 use tracing::debug;
 use uuid::Uuid;
 
 const DEFAULT_SCAN_RESULT_MAX: usize = 1_000;
+const DEFAULT_SCAN_COUNT: u64 = 2_000; // COUNT hint of a scan without keyword
+const DEFAULT_KEYWORD_SCAN_COUNT: u64 = 10_000; // COUNT hint of a keyword or prefix scan
+const FILL_KEY_TYPES_BATCH_SIZE: usize = 100; // Keys per TYPE/TTL pipeline
+const PREVIEW_SCAN_COUNT: u64 = 100; // COUNT hint for the single preview SCAN
+const DELETE_KEYS_BATCH_SIZE: usize = 500; // Keys per UNLINK/DEL round
+const PREVIEW_SAMPLE_SIZE: usize = 5; // Number of sample keys shown in the preview
+const COUNT_KEYS_SCAN_COUNT: u64 = 10_000; // COUNT hint for each SCAN of a count-only scan
+const SCAN_ALL_YIELD_INTERVAL: Duration = Duration::from_millis(10); // Pause between pages of a full scan
+
+/// Running total of a count-only scan
+#[derive(Debug, Clone, Default)]
+pub struct KeyCount {
+    /// MATCH pattern of the scan
+    pub pattern: SharedString,
+    /// Number of matching keys counted so far
+    pub count: u64,
+    /// Whether all cursors returned to 0
+    pub done: bool,
+    /// Whether the scan is still running
+    pub running: bool,
+}
+
+/// Escapes the glob metacharacters, so SCAN MATCH treats the text literally
+fn escape_glob(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '*' | '?' | '[' | ']' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// MATCH pattern of a keyword scan, keys containing the keyword anywhere
+///
+/// The keyword is matched literally unless `raw_glob` is set.
+pub(super) fn keyword_pattern(keyword: &str, raw_glob: bool) -> String {
+    if keyword.is_empty() {
+        "*".to_string()
+    } else if raw_glob {
+        format!("*{keyword}*")
+    } else {
+        format!("*{}*", escape_glob(keyword))
+    }
+}
+
+/// MATCH pattern of a prefix scan
+///
+/// The prefix is matched literally unless `raw_glob` is set.
+fn prefix_pattern(prefix: &str, raw_glob: bool) -> String {
+    if raw_glob {
+        format!("{prefix}*")
+    } else {
+        format!("{}*", escape_glob(prefix))
+    }
+}
 
 impl ZedisServerState {
-    /// Fills the type of keys that are currently loaded but have an unknown type.
+    /// Fills the type and TTL of keys that are currently loaded but have an unknown type or TTL.
     ///
     /// This is typically used when expanding a directory in the key tree view.
     /// It filters keys based on the prefix and ensures we only query keys at the current level.
+    /// TYPE and TTL are sent in one pipeline per batch of keys, in cluster mode keys may
+    /// live in different slots, so each key gets its own pipeline.
     fn fill_key_types(&mut self, prefix: Option<SharedString>, cx: &mut Context<Self>) {
         // Filter keys that need type resolution
         let binding = prefix.unwrap_or_default();
@@ -49,7 +113,8 @@ impl ZedisServerState {
             .keys
             .iter()
             .filter_map(|(key, value)| {
-                if *value != KeyType::Unknown {
+                // Keys of a SCAN TYPE still need their TTL
+                if *value != KeyType::Unknown && self.key_ttl_loaded.contains(key) {
                     return None;
                 }
                 if prefix.is_empty() {
@@ -74,38 +139,147 @@ impl ZedisServerState {
             return;
         }
         let server_id = self.server_id.clone();
+        let failed_prefix = binding.clone();
         keys.sort_unstable();
-        // Spawn a background task to fetch types concurrently
+        // Spawn a background task to fetch types and TTLs concurrently
         self.spawn(
             ServerTask::FillKeyTypes,
+            move || async move {
+                let client = get_connection_manager().get_client(&server_id).await?;
+                let conn = client.read_connection();
+                let batch_size = if client.is_cluster() {
+                    1
+                } else {
+                    FILL_KEY_TYPES_BATCH_SIZE
+                };
+                // Use a stream to execute pipelines concurrently with backpressure
+                let batches: Vec<std::result::Result<Vec<(SharedString, String, i64)>, (usize, String)>> =
+                    stream::iter(keys.chunks(batch_size))
+                        .map(|batch| {
+                            let mut conn_clone = conn.clone();
+                            let batch = batch.to_vec();
+                            async move {
+                                let mut pipeline = pipe();
+                                for key in batch.iter() {
+                                    pipeline.cmd("TYPE").arg(key.as_str()).cmd("TTL").arg(key.as_str());
+                                }
+                                // Keys of a failed batch stay Unknown, so they are resolved again on retry
+                                let values: Vec<(String, i64)> = pipeline
+                                    .query_async(&mut conn_clone)
+                                    .await
+                                    .map_err(|e| (batch.len(), e.to_string()))?;
+                                Ok(batch
+                                    .into_iter()
+                                    .zip(values)
+                                    .map(|(key, (t, ttl))| (key, t, ttl))
+                                    .collect())
+                            }
+                        })
+                        .buffer_unordered(10) // Limit concurrency to 10 pipelines
+                        .collect::<Vec<_>>()
+                        .await;
+                let mut types = Vec::with_capacity(keys.len());
+                let mut failed = 0;
+                let mut first_error = None;
+                for batch in batches {
+                    match batch {
+                        Ok(values) => types.extend(values),
+                        Err((count, error)) => {
+                            failed += count;
+                            first_error.get_or_insert(error);
+                        }
+                    }
+                }
+                Ok((types, failed, first_error))
+            },
+            move |this, result, cx| {
+                if let Ok((types, failed, first_error)) = result {
+                    // Report all failed batches as one error and allow a retry of the folder
+                    if let Some(error) = first_error {
+                        this.key_type_failed_prefixes.insert(failed_prefix.clone());
+                        this.add_error_message(
+                            ServerTask::FillKeyTypes.as_str().to_string(),
+                            format!("TYPE failed for {failed} keys: {error}"),
+                            cx,
+                        );
+                    } else {
+                        this.key_type_failed_prefixes.remove(&failed_prefix);
+                    }
+                    let now = unix_ts();
+                    // Update local state with fetched types and TTLs
+                    for (key, value, ttl) in types {
+                        if ttl >= 0 {
+                            this.key_expire_ats.insert(key.clone(), now + ttl);
+                        } else {
+                            this.key_expire_ats.remove(&key);
+                        }
+                        this.key_ttl_loaded.insert(key.clone());
+                        if let Some(k) = this.keys.get_mut(&key) {
+                            *k = KeyType::from(value.as_str());
+                        }
+                    }
+                    // Trigger UI update by changing the tree ID
+                    this.key_tree_id = Uuid::now_v7().to_string().into();
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+    /// Resolves the types of the keys under a folder again after TYPE failed.
+    pub fn retry_key_types(&mut self, prefix: SharedString, cx: &mut Context<Self>) {
+        self.key_type_failed_prefixes.remove(&prefix);
+        cx.notify();
+        self.fill_key_types(Some(prefix), cx);
+    }
+    /// Returns the prefixes whose key types could not be resolved.
+    ///
+    /// The root level is stored as an empty prefix.
+    pub fn key_type_failed_prefixes(&self) -> &AHashSet<SharedString> {
+        &self.key_type_failed_prefixes
+    }
+    /// Fills the internal encoding (OBJECT ENCODING) of the given keys.
+    ///
+    /// Called lazily by the key tree for visible keys only. Keys are marked
+    /// before the request, so they are not fetched again while pending.
+    pub fn fill_key_encodings(&mut self, keys: Vec<SharedString>, cx: &mut Context<Self>) {
+        let keys: Vec<SharedString> = keys
+            .into_iter()
+            .filter(|key| !self.key_encodings.contains_key(key))
+            .collect();
+        if keys.is_empty() {
+            return;
+        }
+        for key in keys.iter() {
+            self.key_encodings.insert(key.clone(), SharedString::default());
+        }
+        let server_id = self.server_id.clone();
+        self.spawn(
+            ServerTask::FillKeyEncodings,
             move || async move {
                 let conn = get_connection_manager().get_connection(&server_id).await?;
-                // Use a stream to execute commands concurrently with backpressure
-                let types: Vec<(SharedString, String)> = stream::iter(keys.iter().cloned())
+                let encodings: Vec<(SharedString, String)> = stream::iter(keys.into_iter())
                     .map(|key| {
                         let mut conn_clone = conn.clone();
-                        let key = key.clone();
                         async move {
-                            let t: String = cmd("TYPE")
+                            let encoding: Option<String> = cmd("OBJECT")
+                                .arg("ENCODING")
                                 .arg(key.as_str())
                                 .query_async(&mut conn_clone)
                                 .await
                                 .unwrap_or_default();
-                            (key, t)
+                            (key, encoding.unwrap_or_default())
                         }
                     })
                     .buffer_unordered(100) // Limit concurrency to 100
                     .collect::<Vec<_>>()
                     .await;
-                Ok(types)
+                Ok(encodings)
             },
             move |this, result, cx| {
-                if let Ok(types) = result {
-                    // Update local state with fetched types
-                    for (key, value) in types {
-                        if let Some(k) = this.keys.get_mut(&key) {
-                            *k = KeyType::from(value.as_str());
-                        }
+                if let Ok(encodings) = result {
+                    for (key, encoding) in encodings {
+                        this.key_encodings.insert(key, encoding.into());
                     }
                     // Trigger UI update by changing the tree ID
                     this.key_tree_id = Uuid::now_v7().to_string().into();
@@ -125,31 +299,41 @@ impl ZedisServerState {
             return;
         }
         let cursors = self.cursors.clone();
+        let (scan_count, scan_page_limit) = self.scan_settings();
         // Calculate max limit based on scan times to prevent infinite scrolling from loading too much
-        let max = (self.scan_times + 1) * DEFAULT_SCAN_RESULT_MAX;
+        let max = (self.scan_times + 1) * scan_page_limit;
 
         let processing_server = server_id.clone();
         let processing_keyword = keyword.clone();
+        let pattern = keyword_pattern(&keyword, self.raw_glob);
+        // Adjust count based on keyword specificity, unless configured for the server
+        let count = scan_count.unwrap_or(if keyword.is_empty() {
+            DEFAULT_SCAN_COUNT
+        } else {
+            DEFAULT_KEYWORD_SCAN_COUNT
+        });
+        // Keep what is sent for the status bar
+        self.scan_pattern = pattern.clone().into();
+        self.scan_batch_count = count;
+        self.scan_requests += 1;
+        let scan_type = self.scan_type;
         self.spawn(
             ServerTask::ScanKeys,
             move || async move {
                 let client = get_connection_manager().get_client(&server_id).await?;
-                let pattern = if keyword.is_empty() {
-                    "*".to_string()
+                // Let the server filter by type, older versions resolve types afterwards
+                let scan_type = scan_type.filter(|_| client.supports_scan_type());
+                let key_type = scan_type.map(|key_type| key_type.name());
+                let (cursors, keys) = if let Some(cursors) = cursors {
+                    client.scan(cursors, &pattern, count, key_type).await?
                 } else {
-                    format!("*{}*", keyword)
+                    client.first_scan(&pattern, count, key_type).await?
                 };
-                // Adjust count based on keyword specificity
-                let count = if keyword.is_empty() { 2_000 } else { 10_000 };
-                if let Some(cursors) = cursors {
-                    client.scan(cursors, &pattern, count).await
-                } else {
-                    client.first_scan(&pattern, count).await
-                }
+                Ok((cursors, keys, scan_type))
             },
             move |this, result, cx| {
                 match result {
-                    Ok((cursors, keys)) => {
+                    Ok((cursors, keys, scan_type)) => {
                         debug!("cursors: {cursors:?}, keys count: {}", keys.len());
                         // Check if scan is complete (all cursors returned to 0)
                         if cursors.iter().sum::<u64>() == 0 {
@@ -159,7 +343,11 @@ impl ZedisServerState {
                         } else {
                             this.cursors = Some(cursors);
                         }
-                        this.extend_keys(keys);
+                        if let Some(key_type) = scan_type {
+                            this.extend_typed_keys(keys, key_type);
+                        } else {
+                            this.extend_keys(keys);
+                        }
                     }
                     Err(_) => {
                         this.cursors = None;
@@ -169,11 +357,23 @@ impl ZedisServerState {
                     cx.emit(ServerEvent::KeyScanPaged(processing_keyword.clone()));
                 }
                 // Automatically load more if we haven't reached the limit and scan isn't done
-                if this.cursors.is_some() && this.keys.len() < max {
-                    // run again
-                    this.scan_keys(processing_server, processing_keyword, cx);
+                if this.cursors.is_some() && (this.scan_all || this.keys.len() < max) {
+                    if this.scan_all {
+                        // Yield between pages, so the tree is rendered while scanning all keys
+                        cx.spawn(async move |handle, cx| {
+                            cx.background_executor().timer(SCAN_ALL_YIELD_INTERVAL).await;
+                            let _ = handle.update(cx, |this, cx| {
+                                this.scan_keys(processing_server, processing_keyword, cx);
+                            });
+                        })
+                        .detach();
+                    } else {
+                        // run again
+                        this.scan_keys(processing_server, processing_keyword, cx);
+                    }
                     return cx.notify();
                 }
+                this.scan_all = false;
                 this.scaning = false;
                 cx.notify();
                 if this.keys.len() == 1
@@ -187,14 +387,183 @@ impl ZedisServerState {
             cx,
         );
     }
+    /// Returns the SCAN COUNT and page limit configured for the current server.
+    ///
+    /// The COUNT is None when not configured, as its default depends on the keyword.
+    fn scan_settings(&self) -> (Option<u64>, usize) {
+        let server = self.server(&self.server_id);
+        let scan_count = server.and_then(|server| server.scan_count).filter(|count| *count != 0);
+        let scan_page_limit = server
+            .and_then(|server| server.scan_page_limit)
+            .filter(|limit| *limit != 0)
+            .unwrap_or(DEFAULT_SCAN_RESULT_MAX);
+        (scan_count, scan_page_limit)
+    }
+    /// Returns the MATCH pattern a search of the keyword sends in the current query mode.
+    ///
+    /// None in Exact mode, the key is read directly without a scan.
+    pub fn match_pattern(&self, keyword: &str) -> Option<String> {
+        match self.query_mode {
+            QueryMode::All => Some(keyword_pattern(keyword, self.raw_glob)),
+            QueryMode::Prefix => Some(prefix_pattern(keyword, self.raw_glob)),
+            QueryMode::Exact => None,
+        }
+    }
+    /// Previews the scan pattern without touching the main scan state.
+    ///
+    /// Runs a single SCAN with a small COUNT and reports how many keys matched
+    /// in that page together with a few samples.
+    pub fn preview_scan(&mut self, keyword: SharedString, cx: &mut Context<Self>) {
+        let pattern = self
+            .match_pattern(&keyword)
+            .unwrap_or_else(|| keyword_pattern(&keyword, self.raw_glob));
+        let server_id = self.server_id.clone();
+        let pattern_clone = pattern.clone();
+        let scan_type = self.scan_type;
+        self.spawn(
+            ServerTask::PreviewScan,
+            move || async move {
+                let client = get_connection_manager().get_client(&server_id).await?;
+                let key_type = scan_type
+                    .filter(|_| client.supports_scan_type())
+                    .map(|key_type| key_type.name());
+                let (_, keys) = client.first_scan(&pattern, PREVIEW_SCAN_COUNT, key_type).await?;
+                Ok(keys)
+            },
+            move |_this, result, cx| {
+                let Ok(keys) = result else {
+                    return;
+                };
+                let locale = cx.global::<ZedisGlobalStore>().read(cx).locale().to_string();
+                let samples = keys
+                    .iter()
+                    .take(PREVIEW_SAMPLE_SIZE)
+                    .map(|key| key.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let message = if keys.is_empty() {
+                    t!("key_tree.scan_preview_empty", pattern = pattern_clone, locale = locale)
+                } else {
+                    t!(
+                        "key_tree.scan_preview_message",
+                        pattern = pattern_clone,
+                        count = keys.len(),
+                        samples = samples,
+                        locale = locale
+                    )
+                };
+                let title = i18n_key_tree(cx, "scan_preview_title");
+                cx.emit(ServerEvent::Notification(
+                    NotificationAction::new_info(message.to_string().into()).with_title(title),
+                ));
+            },
+            cx,
+        );
+    }
+    /// Counts the keys matching the keyword without loading them.
+    ///
+    /// Scans until all cursors return to 0, only the running total is kept,
+    /// so it is much lighter than loading every key into the tree.
+    pub fn count_keys(&mut self, keyword: SharedString, cx: &mut Context<Self>) {
+        let pattern = self
+            .match_pattern(&keyword)
+            .unwrap_or_else(|| keyword_pattern(&keyword, self.raw_glob));
+        self.key_count_generation += 1;
+        self.key_count = Some(KeyCount {
+            pattern: pattern.into(),
+            running: true,
+            ..Default::default()
+        });
+        cx.notify();
+        self.count_keys_next(self.key_count_generation, None, cx);
+    }
+    /// Stops the running count, the total counted so far is kept.
+    pub fn cancel_count_keys(&mut self, cx: &mut Context<Self>) {
+        self.key_count_generation += 1;
+        if let Some(key_count) = self.key_count.as_mut() {
+            key_count.running = false;
+        }
+        cx.notify();
+    }
+    /// Hides the result of the count.
+    pub fn clear_key_count(&mut self, cx: &mut Context<Self>) {
+        self.key_count_generation += 1;
+        self.key_count = None;
+        cx.notify();
+    }
+    /// Returns the result of the count-only scan
+    pub fn key_count(&self) -> Option<&KeyCount> {
+        self.key_count.as_ref()
+    }
+    /// Runs one SCAN round of the count, continues until done or canceled.
+    fn count_keys_next(&mut self, generation: u64, cursors: Option<Vec<u64>>, cx: &mut Context<Self>) {
+        let Some(key_count) = self.key_count.as_ref() else {
+            return;
+        };
+        let server_id = self.server_id.clone();
+        let pattern = key_count.pattern.clone();
+        let scan_type = self.scan_type;
+        self.spawn(
+            ServerTask::CountKeys,
+            move || async move {
+                let client = get_connection_manager().get_client(&server_id).await?;
+                let key_type = scan_type
+                    .filter(|_| client.supports_scan_type())
+                    .map(|key_type| key_type.name());
+                // Keys are dropped right away, only the number is kept
+                let (cursors, keys) = if let Some(cursors) = cursors {
+                    client.scan(cursors, &pattern, COUNT_KEYS_SCAN_COUNT, key_type).await?
+                } else {
+                    client.first_scan(&pattern, COUNT_KEYS_SCAN_COUNT, key_type).await?
+                };
+                Ok((cursors, keys.len() as u64))
+            },
+            move |this, result, cx| {
+                // Canceled or replaced by a newer count
+                if this.key_count_generation != generation {
+                    return;
+                }
+                let Some(key_count) = this.key_count.as_mut() else {
+                    return;
+                };
+                let Ok((cursors, count)) = result else {
+                    key_count.running = false;
+                    cx.notify();
+                    return;
+                };
+                key_count.count += count;
+                if cursors.iter().sum::<u64>() == 0 {
+                    key_count.done = true;
+                    key_count.running = false;
+                    cx.notify();
+                    return;
+                }
+                cx.notify();
+                this.count_keys_next(generation, Some(cursors), cx);
+            },
+            cx,
+        );
+    }
     pub fn handle_filter(&mut self, keyword: SharedString, cx: &mut Context<Self>) {
         self.reset_scan();
         match self.query_mode {
-            QueryMode::Prefix => self.scan_prefix(keyword, cx),
+            QueryMode::Prefix => self.scan_prefix_pattern(keyword, self.raw_glob, cx),
             QueryMode::Exact => self.select_key(keyword, cx),
             _ => self.scan(keyword, cx),
         }
     }
+    /// Clears the keyword and resets the scan state.
+    ///
+    /// In All mode the full keyspace scan is restarted right away,
+    /// the other modes wait for the next search.
+    pub fn reset_filter(&mut self, cx: &mut Context<Self>) {
+        if self.query_mode == QueryMode::All {
+            self.scan(SharedString::default(), cx);
+            return;
+        }
+        self.reset_scan();
+        cx.notify();
+    }
     /// Collapse all keys
     pub fn collapse_all_keys(&mut self, cx: &mut Context<Self>) {
         cx.emit(ServerEvent::KeyCollapseAll);
@@ -217,10 +586,43 @@ impl ZedisServerState {
         self.scan_keys(self.server_id.clone(), self.keyword.clone(), cx);
         cx.notify();
     }
+    /// Loads all remaining pages of the current scan until it completes.
+    ///
+    /// The page limit is ignored until the scan completes or `stop_scan_all` is called.
+    pub fn scan_all(&mut self, cx: &mut Context<Self>) {
+        if self.scan_completed || self.scan_all || self.query_mode != QueryMode::All {
+            return;
+        }
+        self.scan_all = true;
+        // A running page continues the full scan when it returns
+        if self.scaning {
+            return cx.notify();
+        }
+        self.scaning = true;
+        cx.notify();
+        self.scan_keys(self.server_id.clone(), self.keyword.clone(), cx);
+    }
+    /// Stops the full scan after the running page, the loaded keys are kept.
+    pub fn stop_scan_all(&mut self, cx: &mut Context<Self>) {
+        if !self.scan_all {
+            return;
+        }
+        self.scan_all = false;
+        // Continue the page limit from the loaded keys, so the scan stops after this page
+        let (_, scan_page_limit) = self.scan_settings();
+        self.scan_times = (self.keys.len() / scan_page_limit).saturating_sub(1);
+        cx.notify();
+    }
     /// Scans keys matching a specific prefix.
     ///
     /// Optimized for populating directory-like structures in the key view.
     pub fn scan_prefix(&mut self, prefix: SharedString, cx: &mut Context<Self>) {
+        self.scan_prefix_pattern(prefix, false, cx);
+    }
+    /// Scans keys starting with the prefix, which is a glob pattern if `raw_glob` is set.
+    ///
+    /// Folders of the tree are literal key prefixes, only the searched keyword may be raw.
+    fn scan_prefix_pattern(&mut self, prefix: SharedString, raw_glob: bool, cx: &mut Context<Self>) {
         // Avoid reloading if already loaded
         let mut key_type_full_loaded = false;
         let mut key_full_loaded = false;
@@ -249,13 +651,15 @@ impl ZedisServerState {
         cx.emit(ServerEvent::KeyScanStarted(prefix.clone()));
 
         let server_id = self.server_id.clone();
-        let pattern = format!("{}*", prefix);
+        let pattern = prefix_pattern(&prefix, raw_glob);
+        let scan_type = self.scan_type;
+        let count = self.scan_settings().0.unwrap_or(DEFAULT_KEYWORD_SCAN_COUNT);
         self.spawn(
             ServerTask::ScanPrefix,
             move || async move {
                 let client = get_connection_manager().get_client(&server_id).await?;
-                let count = 10_000;
-                // let mut cursors: Option<Vec<u64>>,
+                let scan_type = scan_type.filter(|_| client.supports_scan_type());
+                let key_type = scan_type.map(|key_type| key_type.name());
                 let mut cursors: Option<Vec<u64>> = None;
                 let mut result_keys = vec![];
                 let mut done = false;
@@ -263,9 +667,9 @@ impl ZedisServerState {
                 // to gather a sufficient amount without blocking for too long.
                 for _ in 0..20 {
                     let (new_cursor, keys) = if let Some(cursors) = cursors.clone() {
-                        client.scan(cursors, &pattern, count).await?
+                        client.scan(cursors, &pattern, count, key_type).await?
                     } else {
-                        client.first_scan(&pattern, count).await?
+                        client.first_scan(&pattern, count, key_type).await?
                     };
                     result_keys.extend(keys);
                     // Break if scan cycle finishes
@@ -276,20 +680,25 @@ impl ZedisServerState {
                     cursors = Some(new_cursor);
                 }
 
-                Ok((result_keys, done))
+                Ok((result_keys, done, scan_type))
             },
             move |this, result, cx| {
-                if let Ok((keys, done)) = result {
+                if let Ok((keys, done, scan_type)) = result {
                     debug!(
                         prefix = prefix.as_str(),
                         count = keys.len(),
                         done,
                         "scan prefix success"
                     );
-                    if done {
+                    // A raw pattern is not a key prefix, it can't be reused for folders
+                    if done && !raw_glob {
                         this.loaded_prefixes.insert(prefix.clone());
                     }
-                    this.extend_keys(keys);
+                    if let Some(key_type) = scan_type {
+                        this.extend_typed_keys(keys, key_type);
+                    } else {
+                        this.extend_keys(keys);
+                    }
                 }
                 cx.notify();
                 // Resolve types for the keys under this prefix
@@ -298,7 +707,7 @@ impl ZedisServerState {
                 {
                     this.select_key(key.clone(), cx);
                 } else {
-                    this.fill_key_types(Some(prefix.clone()), cx);
+                    this.fill_key_types((!raw_glob).then(|| prefix.clone()), cx);
                 }
                 cx.emit(ServerEvent::KeyScanPaged(prefix.clone()));
             },
@@ -306,9 +715,75 @@ impl ZedisServerState {
         );
     }
 
+    /// Reveals the selected key in the key tree.
+    ///
+    /// The key is added to the loaded keys if it was opened without a scan
+    /// (e.g. Exact mode), the tree expands its folders and scrolls to it.
+    pub fn reveal_key(&mut self, cx: &mut Context<Self>) {
+        let Some(key) = self.key.clone().filter(|key| !key.is_empty()) else {
+            return;
+        };
+        if !self.keys.contains_key(&key) {
+            let key_type = self.value_key_type().unwrap_or_default();
+            self.keys.insert(key.clone(), key_type);
+            self.key_tree_id = Uuid::now_v7().to_string().into();
+        }
+        cx.emit(ServerEvent::KeyRevealed(key));
+        cx.notify();
+    }
     /// Selects a key and fetches its details (Type, TTL, Value).
     pub fn select_key(&mut self, key: SharedString, cx: &mut Context<Self>) {
+        self.load_key(key, false, false, false, cx);
+    }
+    /// Selects a key the app has just written, e.g. an added or imported key.
+    ///
+    /// The value is read from the master, a replica may not have the write yet.
+    pub fn select_written_key(&mut self, key: SharedString, cx: &mut Context<Self>) {
+        self.load_key(key, false, false, true, cx);
+    }
+    /// Fetches the details of the selected key again, e.g. after another client changed it.
+    ///
+    /// The highlighting language chosen for the value is kept. The size guard
+    /// applies again, unless the user chose to load the huge string of this key
+    /// anyway. The value is read from the master, so it reflects the latest writes of the app.
+    pub fn reload_key(&mut self, cx: &mut Context<Self>) {
+        let Some(key) = self.key.clone() else {
+            return;
+        };
+        let load_full = self.force_loaded_key.as_ref() == Some(&key);
+        self.load_key(key, true, load_full, true, cx);
+    }
+    /// Loads the selected string value whose loading was deferred because of its size.
+    pub fn load_deferred_value(&mut self, cx: &mut Context<Self>) {
+        let Some(key) = self.key.clone() else {
+            return;
+        };
+        self.force_loaded_key = Some(key.clone());
+        self.load_key(key, true, true, true, cx);
+    }
+    /// Starts a new selection, the results of earlier selections are ignored from now on.
+    fn next_select_generation(&mut self) -> u64 {
+        self.select_generation += 1;
+        self.select_generation
+    }
+    /// Returns true if the result belongs to the latest selection and the key is still selected.
+    fn is_latest_selection(&self, generation: u64, key: &SharedString) -> bool {
+        self.select_generation == generation && self.key.as_ref() == Some(key)
+    }
+    fn load_key(
+        &mut self,
+        key: SharedString,
+        reload: bool,
+        load_full: bool,
+        from_master: bool,
+        cx: &mut Context<Self>,
+    ) {
         self.key = Some(key.clone());
+        if !reload {
+            self.force_loaded_key = None;
+        }
+        // The editors drop their unsaved edits once the value is loaded
+        self.unsaved_value_edits = false;
         if key.is_empty() {
             return;
         }
@@ -325,20 +800,53 @@ impl ZedisServerState {
         cx.emit(ServerEvent::KeySelected(key.clone()));
         cx.notify();
 
+        // Results of earlier selections may arrive later, e.g. when quickly
+        // clicking through keys or selecting the same key again
+        let generation = self.next_select_generation();
         let server_id = self.server_id.clone();
         let current_key = key.clone();
+        // Huge strings are only loaded on request
+        let max_string_size = if load_full {
+            None
+        } else {
+            let max_size_mb = cx.global::<ZedisGlobalStore>().read(cx).max_string_load_size_mb();
+            Some(max_size_mb as usize * 1024 * 1024)
+        };
+        // STRLEN is sent with TYPE and TTL for keys known as strings
+        let known_string = max_string_size.is_some() && self.keys.get(&key) == Some(&KeyType::String);
 
         self.spawn(
             ServerTask::Selectkey,
             move || async move {
-                let mut conn = get_connection_manager().get_connection(&server_id).await?;
-                let (t, ttl): (String, i64) = pipe()
-                    .cmd("TYPE")
-                    .arg(key.as_str())
-                    .cmd("TTL")
-                    .arg(key.as_str())
-                    .query_async(&mut conn)
-                    .await?;
+                let client = get_connection_manager().get_client(&server_id).await?;
+                let mut conn = if from_master {
+                    client.connection()
+                } else {
+                    client.read_connection()
+                };
+                let mut pipeline = pipe();
+                pipeline.cmd("TYPE").arg(key.as_str()).cmd("TTL").arg(key.as_str());
+                // The pipeline only touches one key, so cluster mode routes it to the owning node
+                let (t, ttl, size) = if known_string {
+                    pipeline.cmd("STRLEN").arg(key.as_str());
+                    match pipeline.query_async::<(String, i64, usize)>(&mut conn).await {
+                        Ok((t, ttl, size)) => (t, ttl, Some(size)),
+                        // The key was replaced by another type, which rejects STRLEN
+                        Err(_) => {
+                            let (t, ttl): (String, i64) = pipe()
+                                .cmd("TYPE")
+                                .arg(key.as_str())
+                                .cmd("TTL")
+                                .arg(key.as_str())
+                                .query_async(&mut conn)
+                                .await?;
+                            (t, ttl, None)
+                        }
+                    }
+                } else {
+                    let (t, ttl): (String, i64) = pipeline.query_async(&mut conn).await?;
+                    (t, ttl, None)
+                };
                 // the key does not exist
                 if ttl == -2 {
                     return Ok(RedisValue {
@@ -355,26 +863,71 @@ impl ZedisServerState {
 
                 let key_type = KeyType::from(t.as_str());
                 let mut redis_value = match key_type {
-                    KeyType::String => get_redis_value(&mut conn, &key).await,
+                    KeyType::String => get_redis_value_within(&mut conn, &key, max_string_size, size).await,
                     KeyType::List => first_load_list_value(&mut conn, &key).await,
                     KeyType::Set => first_load_set_value(&mut conn, &key).await,
                     KeyType::Zset => first_load_zset_value(&mut conn, &key, SortOrder::Asc).await,
                     KeyType::Hash => first_load_hash_value(&mut conn, &key).await,
-                    _ => Err(Error::Invalid {
-                        message: "unsupported key type".to_string(),
+                    KeyType::Stream => first_load_stream_value(&mut conn, &key).await,
+                    // The editor shows a notice for types it can not render
+                    _ => Ok(RedisValue {
+                        key_type,
+                        ..Default::default()
                     }),
                 }?;
                 redis_value.expire_at = expire_at;
+                // Queried on its own like OBJECT ENCODING, servers rejecting MEMORY
+                // (e.g. ACL rules) can not fail the whole value loading
+                if client.supports_memory_usage() {
+                    redis_value.memory_usage = cmd("MEMORY")
+                        .arg("USAGE")
+                        .arg(key.as_str())
+                        .query_async(&mut conn)
+                        .await
+                        .unwrap_or_default();
+                }
+                // Queried on its own, so servers rejecting OBJECT (e.g. ACL rules)
+                // can not fail the whole value loading
+                let encoding: Option<String> = cmd("OBJECT")
+                    .arg("ENCODING")
+                    .arg(key.as_str())
+                    .query_async(&mut conn)
+                    .await
+                    .unwrap_or_default();
+                redis_value.encoding = encoding.map(SharedString::from);
+                redis_value.cluster_slot = client
+                    .key_slot_node(key.as_bytes())
+                    .map(|(slot, node)| (slot, node.map(SharedString::from)));
 
                 Ok(redis_value)
             },
             move |this, result, cx| {
-                // if the key is not the same as the selected key, return
-                if this.key != Some(current_key.clone()) {
+                // only apply the result of the latest selection
+                if !this.is_latest_selection(generation, &current_key) {
                     return;
                 }
                 match result {
-                    Ok(value) => {
+                    Ok(mut value) => {
+                        // Apply the view mode last used for this key type
+                        let view_mode = cx
+                            .global::<ZedisGlobalStore>()
+                            .read(cx)
+                            .view_mode(value.key_type())
+                            .map(ViewMode::from_str)
+                            .filter(|view_mode| !view_mode.is_explicit_only());
+                        if let Some(view_mode) = view_mode
+                            && let Some(RedisValueData::Bytes(bytes_value)) = value.data.as_mut()
+                        {
+                            Arc::make_mut(bytes_value).view_mode = view_mode;
+                        }
+                        let highlight_language = this
+                            .value
+                            .as_ref()
+                            .and_then(|value| value.bytes_value())
+                            .and_then(|value| value.highlight_language.clone());
+                        if reload && let Some(RedisValueData::Bytes(bytes_value)) = value.data.as_mut() {
+                            Arc::make_mut(bytes_value).highlight_language = highlight_language;
+                        }
                         if !value.is_expired()
                             && let Some(key) = this.key.as_ref()
                         {
@@ -422,7 +975,11 @@ impl ZedisServerState {
             },
             move |this, result, cx| {
                 if let Ok(()) = result {
+                    this.add_audit_entry(&ServerTask::DeleteKey, remove_key.as_str(), cx);
                     this.keys.remove(&remove_key);
+                    this.key_expire_ats.remove(&remove_key);
+                    this.key_ttl_loaded.remove(&remove_key);
+                    this.key_encodings.remove(&remove_key);
                     // Force refresh of the key tree view
                     this.key_tree_id = Uuid::now_v7().to_string().into();
                     // Deselect if the deleted key was selected
@@ -436,8 +993,277 @@ impl ZedisServerState {
             cx,
         );
     }
+    /// Renames a key with RENAME, or RENAMENX to keep an existing target.
+    ///
+    /// In cluster mode both keys must hash to the same slot, otherwise the
+    /// CROSSSLOT error is shown like any other command error.
+    pub fn rename_key(&mut self, key: SharedString, new_key: SharedString, nx: bool, cx: &mut Context<Self>) {
+        if new_key.is_empty() || key == new_key {
+            return;
+        }
+        let server_id = self.server_id.clone();
+        let Some(value) = self.value.as_mut() else {
+            return;
+        };
+        value.status = RedisValueStatus::Updating;
+        cx.notify();
+        let old_key = key.clone();
+        let target_key = new_key.clone();
+        self.spawn(
+            ServerTask::RenameKey,
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id).await?;
+                if nx {
+                    // 1 if the key was renamed, 0 if the new key already exists
+                    let renamed: i64 = cmd("RENAMENX")
+                        .arg(key.as_str())
+                        .arg(new_key.as_str())
+                        .query_async(&mut conn)
+                        .await?;
+                    if renamed == 0 {
+                        return Err(Error::Invalid {
+                            message: format!("Key {new_key} already exists"),
+                        });
+                    }
+                } else {
+                    let _: () = cmd("RENAME")
+                        .arg(key.as_str())
+                        .arg(new_key.as_str())
+                        .query_async(&mut conn)
+                        .await?;
+                }
+                Ok(())
+            },
+            move |this, result, cx| {
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                }
+                if let Ok(()) = result {
+                    this.add_audit_entry(&ServerTask::RenameKey, format!("{old_key} -> {target_key}"), cx);
+                    let key_type = this.keys.remove(&old_key).or_else(|| this.value_key_type());
+                    this.keys.insert(target_key.clone(), key_type.unwrap_or_default());
+                    // RENAME may have overwritten a key with a different TTL or encoding
+                    this.key_expire_ats.remove(&target_key);
+                    this.key_ttl_loaded.remove(&target_key);
+                    this.key_encodings.remove(&target_key);
+                    if let Some(expire_at) = this.key_expire_ats.remove(&old_key) {
+                        this.key_expire_ats.insert(target_key.clone(), expire_at);
+                    }
+                    if this.key_ttl_loaded.remove(&old_key) {
+                        this.key_ttl_loaded.insert(target_key.clone());
+                    }
+                    if let Some(encoding) = this.key_encodings.remove(&old_key) {
+                        this.key_encodings.insert(target_key.clone(), encoding);
+                    }
+                    // Force refresh of the key tree view
+                    this.key_tree_id = Uuid::now_v7().to_string().into();
+                    if this.key == Some(old_key) {
+                        this.key = Some(target_key.clone());
+                        cx.emit(ServerEvent::KeySelected(target_key));
+                    }
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+    /// Copies a key to a new name with COPY, falling back to DUMP/RESTORE
+    /// for servers older than Redis 6.2.
+    pub fn copy_key(&mut self, key: SharedString, new_key: SharedString, replace: bool, cx: &mut Context<Self>) {
+        if new_key.is_empty() || key == new_key {
+            return;
+        }
+        let server_id = self.server_id.clone();
+        let key_type = self.keys.get(&key).copied().or_else(|| self.value_key_type());
+        let target_key = new_key.clone();
+        let audit_target = format!("{key} -> {new_key}");
+        self.spawn(
+            ServerTask::CopyKey,
+            move || async move {
+                let client = get_connection_manager().get_client(&server_id).await?;
+                let mut conn = client.connection();
+                let exists_error = || Error::Invalid {
+                    message: format!("Key {new_key} already exists"),
+                };
+                if client.supports_copy() {
+                    let mut copy = cmd("COPY");
+                    copy.arg(key.as_str()).arg(new_key.as_str());
+                    if replace {
+                        copy.arg("REPLACE");
+                    }
+                    // 1 if the key was copied, 0 if the destination already exists
+                    let copied: i64 = copy.query_async(&mut conn).await?;
+                    if copied == 0 {
+                        return Err(exists_error());
+                    }
+                    return Ok(());
+                }
+                let (data, ttl): (Option<Vec<u8>>, i64) = pipe()
+                    .cmd("DUMP")
+                    .arg(key.as_str())
+                    .cmd("PTTL")
+                    .arg(key.as_str())
+                    .query_async(&mut conn)
+                    .await?;
+                let Some(data) = data else {
+                    return Err(Error::Invalid {
+                        message: format!("Key {key} does not exist"),
+                    });
+                };
+                if !replace {
+                    let exists: bool = cmd("EXISTS").arg(new_key.as_str()).query_async(&mut conn).await?;
+                    if exists {
+                        return Err(exists_error());
+                    }
+                }
+                let mut restore = cmd("RESTORE");
+                restore.arg(new_key.as_str()).arg(ttl.max(0)).arg(data);
+                if replace {
+                    restore.arg("REPLACE");
+                }
+                let _: () = restore.query_async(&mut conn).await?;
+                Ok(())
+            },
+            move |this, result, cx| {
+                if let Ok(()) = result {
+                    this.add_audit_entry(&ServerTask::CopyKey, audit_target, cx);
+                    this.keys.insert(target_key.clone(), key_type.unwrap_or_default());
+                    // The copy may have replaced a key with a different TTL or encoding
+                    this.key_expire_ats.remove(&target_key);
+                    this.key_ttl_loaded.remove(&target_key);
+                    this.key_encodings.remove(&target_key);
+                    // Force refresh of the key tree view
+                    this.key_tree_id = Uuid::now_v7().to_string().into();
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+    /// Deletes the given loaded keys, e.g. the keys shown by the current filter.
+    ///
+    /// Only keys already scanned are deleted, keys the scan has not reached yet
+    /// are kept. Keys are removed with UNLINK (DEL before Redis 4.0) in batches,
+    /// and dropped from the key map as each batch returns.
+    pub fn delete_keys_matching(&mut self, mut keys: Vec<SharedString>, cx: &mut Context<Self>) {
+        if self.delete_keys_progress.is_some() {
+            return;
+        }
+        keys.retain(|key| self.keys.contains_key(key));
+        if keys.is_empty() {
+            return;
+        }
+        keys.sort_unstable();
+        let total = keys.len();
+        self.delete_keys_generation += 1;
+        self.delete_keys_progress = Some((0, total));
+        cx.emit(ServerEvent::KeysDeleteProgress(0, total));
+        cx.notify();
+        self.delete_keys_next(self.delete_keys_generation, keys, cx);
+    }
+    /// Returns the deleted and total number of keys of the running bulk delete.
+    pub fn delete_keys_progress(&self) -> Option<(usize, usize)> {
+        self.delete_keys_progress
+    }
+    /// Deletes one batch of keys, continues until all keys are deleted or an error occurs.
+    fn delete_keys_next(&mut self, generation: u64, mut keys: Vec<SharedString>, cx: &mut Context<Self>) {
+        let rest = keys.split_off(keys.len().min(DELETE_KEYS_BATCH_SIZE));
+        let server_id = self.server_id.clone();
+        self.spawn(
+            ServerTask::DeleteKeys,
+            move || async move {
+                let client = get_connection_manager().get_client(&server_id).await?;
+                let conn = client.connection();
+                let command = if client.supports_unlink() { "UNLINK" } else { "DEL" };
+                // Keys of a cluster may live on different nodes
+                let batch_size = if client.is_cluster() { 1 } else { keys.len().max(1) };
+                let results: Vec<Result<(), Error>> = stream::iter(keys.chunks(batch_size))
+                    .map(|batch| {
+                        let mut conn_clone = conn.clone();
+                        async move {
+                            let mut pipeline = pipe();
+                            for key in batch.iter() {
+                                pipeline.cmd(command).arg(key.as_str()).ignore();
+                            }
+                            let _: () = pipeline.query_async(&mut conn_clone).await?;
+                            Ok(())
+                        }
+                    })
+                    .buffer_unordered(10)
+                    .collect()
+                    .await;
+                results.into_iter().collect::<Result<Vec<()>, Error>>()?;
+                Ok(keys)
+            },
+            move |this, result, cx| {
+                // Stopped by switching the server
+                if this.delete_keys_generation != generation {
+                    return;
+                }
+                let Some((deleted, total)) = this.delete_keys_progress else {
+                    return;
+                };
+                let Ok(keys) = result else {
+                    this.delete_keys_progress = None;
+                    if deleted > 0 {
+                        this.add_delete_keys_audit_entry(deleted, cx);
+                    }
+                    this.key_tree_id = Uuid::now_v7().to_string().into();
+                    cx.emit(ServerEvent::KeysDeleteProgress(deleted, total));
+                    cx.notify();
+                    return;
+                };
+                for key in keys.iter() {
+                    this.keys.remove(key);
+                    this.key_expire_ats.remove(key);
+                    this.key_ttl_loaded.remove(key);
+                    this.key_encodings.remove(key);
+                }
+                if this.key.as_ref().is_some_and(|key| keys.contains(key)) {
+                    this.key = None;
+                    this.value = None;
+                }
+                let deleted = deleted + keys.len();
+                this.key_tree_id = Uuid::now_v7().to_string().into();
+                if rest.is_empty() {
+                    this.delete_keys_progress = None;
+                    this.add_delete_keys_audit_entry(deleted, cx);
+                    cx.emit(ServerEvent::KeysDeleteProgress(deleted, total));
+                    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale().to_string();
+                    let message = t!("key_tree.delete_matching_done", count = deleted, locale = locale);
+                    cx.emit(ServerEvent::Notification(
+                        NotificationAction::new_success(message.to_string().into())
+                            .with_title(i18n_key_tree(cx, "delete_matching_title")),
+                    ));
+                    cx.notify();
+                    return;
+                }
+                this.delete_keys_progress = Some((deleted, total));
+                cx.emit(ServerEvent::KeysDeleteProgress(deleted, total));
+                cx.notify();
+                this.delete_keys_next(generation, rest, cx);
+            },
+            cx,
+        );
+    }
+    /// Records a bulk delete as a single audit entry with the number of deleted keys.
+    fn add_delete_keys_audit_entry(&mut self, deleted: usize, cx: &mut Context<Self>) {
+        let target = format!("{deleted} keys ({})", self.scan_pattern);
+        self.add_audit_entry(&ServerTask::DeleteKeys, target, cx);
+    }
     /// Updates the TTL (expiration) for a key.
-    pub fn update_key_ttl(&mut self, key: SharedString, ttl: SharedString, cx: &mut Context<Self>) {
+    ///
+    /// The TTL is a number of seconds or a humantime duration (EXPIRE), or an
+    /// RFC3339 time like `2026-01-02T03:04:05+08:00` (EXPIREAT).
+    /// The new expiration is shown optimistically and rolled back if the
+    /// command fails or the EXPIRE condition (NX/XX/GT/LT) is not met.
+    pub fn update_key_ttl(
+        &mut self,
+        key: SharedString,
+        ttl: SharedString,
+        condition: ExpireCondition,
+        cx: &mut Context<Self>,
+    ) {
         if ttl.is_empty() {
             return;
         }
@@ -447,11 +1273,21 @@ impl ZedisServerState {
         };
         value.status = RedisValueStatus::Updating;
         let original_ttl = value.expire_at;
+        let audit_target = format!("{key} ({ttl})");
 
         let mut new_ttl = Duration::ZERO;
+        // Unix timestamp of an absolute expiration time
+        let mut expire_at = None;
         let mut parse_fail_error = "".to_string();
         if let Ok(secs) = ttl.parse::<u64>() {
             new_ttl = Duration::from_secs(secs);
+        } else if let Ok(datetime) = DateTime::parse_from_rfc3339(ttl.trim()) {
+            // EXPIREAT with a past time deletes the key
+            if datetime.timestamp() <= unix_ts() {
+                parse_fail_error = format!("{ttl} is in the past");
+            } else {
+                expire_at = Some(datetime.timestamp());
+            }
         } else {
             match humantime::parse_duration(&ttl) {
                 Ok(ttl) => new_ttl = ttl,
@@ -461,7 +1297,9 @@ impl ZedisServerState {
             }
         }
 
-        if !new_ttl.is_zero() {
+        if let Some(expire_at) = expire_at {
+            value.expire_at = Some(expire_at);
+        } else if !new_ttl.is_zero() {
             value.expire_at = Some(unix_ts() + new_ttl.as_secs() as i64);
         }
         cx.notify();
@@ -474,20 +1312,79 @@ impl ZedisServerState {
                     });
                 }
                 let mut conn = get_connection_manager().get_connection(&server_id).await?;
-                let _: () = cmd("EXPIRE")
-                    .arg(key.as_str())
-                    .arg(new_ttl.as_secs())
-                    .query_async(&mut conn)
-                    .await?;
-                Ok(ttl)
+                let mut expire = cmd(if expire_at.is_some() { "EXPIREAT" } else { "EXPIRE" });
+                expire.arg(key.as_str());
+                match expire_at {
+                    Some(expire_at) => expire.arg(expire_at),
+                    None => expire.arg(new_ttl.as_secs()),
+                };
+                if let Some(option) = condition.as_option() {
+                    expire.arg(option);
+                }
+                // 1 if the timeout was set, 0 if the key does not exist or the condition is not met
+                let updated: i64 = expire.query_async(&mut conn).await?;
+                Ok(updated == 1)
             },
             move |this, result, cx| {
+                let updated = matches!(result, Ok(true));
+                if updated {
+                    this.add_audit_entry(&ServerTask::UpdateKeyTtl, audit_target, cx);
+                }
                 if let Some(value) = this.value.as_mut() {
-                    if result.is_err() {
+                    if !updated {
                         value.expire_at = original_ttl;
                     }
                     value.status = RedisValueStatus::Idle;
                 }
+                if let Ok(false) = result {
+                    cx.emit(ServerEvent::Notification(
+                        NotificationAction::new_warning(i18n_editor(cx, "ttl_not_updated"))
+                            .with_title(i18n_editor(cx, "update_ttl_title")),
+                    ));
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+
+    /// Removes the expiration of a key with PERSIST, making it permanent.
+    pub fn persist_key(&mut self, key: SharedString, cx: &mut Context<Self>) {
+        let server_id = self.server_id.clone();
+        let Some(value) = self.value.as_mut() else {
+            return;
+        };
+        value.status = RedisValueStatus::Updating;
+        cx.notify();
+        let persist_key = key.clone();
+        self.spawn(
+            ServerTask::UpdateKeyTtl,
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id).await?;
+                // 1 if the timeout was removed, 0 if the key does not exist or has no timeout
+                let removed: i64 = cmd("PERSIST").arg(key.as_str()).query_async(&mut conn).await?;
+                Ok(removed == 1)
+            },
+            move |this, result, cx| {
+                if let Some(value) = this.value.as_mut() {
+                    if let Ok(true) = result {
+                        value.expire_at = Some(-1);
+                    }
+                    value.status = RedisValueStatus::Idle;
+                }
+                match result {
+                    Ok(true) => {
+                        this.add_audit_entry(&ServerTask::UpdateKeyTtl, format!("{persist_key} (persist)"), cx);
+                        this.key_expire_ats.remove(&persist_key);
+                    }
+                    Ok(false) => {
+                        cx.emit(ServerEvent::Notification(
+                            NotificationAction::new_warning(i18n_editor(cx, "ttl_not_updated"))
+                                .with_title(i18n_editor(cx, "update_ttl_title")),
+                        ));
+                    }
+                    Err(_) => {}
+                }
                 cx.notify();
             },
             cx,
@@ -551,6 +1448,15 @@ impl ZedisServerState {
                             .query_async(&mut conn)
                             .await?;
                     }
+                    KeyType::Stream => {
+                        let _: () = cmd("XADD")
+                            .arg(key.as_str())
+                            .arg("*")
+                            .arg("field1")
+                            .arg("value1")
+                            .query_async(&mut conn)
+                            .await?;
+                    }
                     _ => {
                         return Err(Error::Invalid {
                             message: "Invalid key type".to_string(),
@@ -569,9 +1475,10 @@ impl ZedisServerState {
             },
             move |this, result, cx| {
                 if result.is_ok() {
+                    this.add_audit_entry(&ServerTask::AddKey, key_clone.as_str(), cx);
                     this.keys.insert(key_clone.clone(), key_type);
                     this.key_tree_id = Uuid::now_v7().to_string().into();
-                    this.select_key(key_clone, cx);
+                    this.select_written_key(key_clone, cx);
                 }
                 cx.notify();
             },
@@ -579,3 +1486,84 @@ impl ZedisServerState {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Matches the text against a MATCH pattern like Redis `stringmatchlen`,
+    /// supporting `*`, `?`, `[...]` and `\` escapes.
+    fn glob_match(pattern: &[u8], text: &[u8]) -> bool {
+        match pattern.split_first() {
+            None => text.is_empty(),
+            Some((b'*', rest)) => (0..=text.len()).any(|index| glob_match(rest, &text[index..])),
+            Some((b'?', rest)) => !text.is_empty() && glob_match(rest, &text[1..]),
+            Some((b'[', rest)) => {
+                let Some(end) = rest.iter().position(|c| *c == b']') else {
+                    return false;
+                };
+                !text.is_empty() && rest[..end].contains(&text[0]) && glob_match(&rest[end + 1..], &text[1..])
+            }
+            Some((b'\\', rest)) if !rest.is_empty() => {
+                text.first() == Some(&rest[0]) && glob_match(&rest[1..], &text[1..])
+            }
+            Some((c, rest)) => text.first() == Some(c) && glob_match(rest, &text[1..]),
+        }
+    }
+
+    #[test]
+    fn test_out_of_order_selection_results() {
+        let mut state = ZedisServerState::default();
+        let key_a = SharedString::from("a");
+        let key_b = SharedString::from("b");
+
+        state.key = Some(key_a.clone());
+        let generation_a = state.next_select_generation();
+        state.key = Some(key_b.clone());
+        let generation_b = state.next_select_generation();
+
+        // The result of the later click arrives first
+        assert!(state.is_latest_selection(generation_b, &key_b));
+        assert!(!state.is_latest_selection(generation_a, &key_a));
+
+        // Selecting the same key again ignores the result of the first load
+        state.key = Some(key_a.clone());
+        let first = state.next_select_generation();
+        let second = state.next_select_generation();
+        assert!(state.is_latest_selection(second, &key_a));
+        assert!(!state.is_latest_selection(first, &key_a));
+    }
+
+    #[test]
+    fn test_escape_glob() {
+        assert_eq!(escape_glob("user:1"), "user:1");
+        assert_eq!(escape_glob("a*b?c"), "a\\*b\\?c");
+        assert_eq!(escape_glob("a[b]"), "a\\[b\\]");
+        assert_eq!(escape_glob("a\\b"), "a\\\\b");
+    }
+
+    #[test]
+    fn test_keyword_pattern() {
+        assert_eq!(keyword_pattern("", false), "*");
+        assert_eq!(keyword_pattern("user", false), "*user*");
+        assert_eq!(keyword_pattern("a[b]", false), "*a\\[b\\]*");
+        assert_eq!(keyword_pattern("a[b]", true), "*a[b]*");
+    }
+
+    #[test]
+    fn test_prefix_pattern() {
+        assert_eq!(prefix_pattern("user:", false), "user:*");
+        assert_eq!(prefix_pattern("user?", false), "user\\?*");
+        assert_eq!(prefix_pattern("user?", true), "user?*");
+    }
+
+    #[test]
+    fn test_keyword_pattern_finds_key_with_brackets() {
+        let pattern = keyword_pattern("a[b]", false);
+        assert!(glob_match(pattern.as_bytes(), b"a[b]"));
+        assert!(glob_match(pattern.as_bytes(), b"x:a[b]:y"));
+        // Unescaped, `[b]` is a character class and only matches "ab"
+        assert!(!glob_match(b"*a[b]*", b"a[b]"));
+        assert!(!glob_match(pattern.as_bytes(), b"ab"));
+    }
+}
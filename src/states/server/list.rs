@@ -19,15 +19,46 @@ use super::{
 use crate::{
     connection::{RedisAsyncConn, get_connection_manager},
     error::Error,
-    states::ServerEvent,
+    states::{NotificationAction, ServerEvent, ZedisGlobalStore, i18n_list_editor},
 };
 use gpui::{SharedString, prelude::*};
-use redis::{cmd, pipe};
-use std::sync::Arc;
+use redis::{Script, cmd, pipe};
+use rust_i18n::t;
+use std::sync::{Arc, LazyLock};
 use uuid::Uuid;
 
 type Result<T, E = Error> = std::result::Result<T, E>;
 
+// Max number of removed list items kept for undo
+const LIST_UNDO_LIMIT: usize = 20;
+
+// Checks the item at the index and inserts next to it in one step, so no other
+// client can change the list in between. Returns {1, size} or {0, current item}.
+//
+// KEYS[1]: list key, ARGV: index, pivot, unique marker, BEFORE/AFTER, new value, pivot index
+static INSERT_LIST_VALUE_SCRIPT: LazyLock<Script> = LazyLock::new(|| {
+    Script::new(
+        r"
+local current = redis.call('LINDEX', KEYS[1], ARGV[1])
+if current ~= ARGV[2] then
+    return {0, current}
+end
+redis.call('LSET', KEYS[1], ARGV[1], ARGV[3])
+local size = redis.call('LINSERT', KEYS[1], ARGV[4], ARGV[3], ARGV[5])
+redis.call('LSET', KEYS[1], ARGV[6], ARGV[2])
+return {1, size}
+",
+    )
+});
+
+/// A removed list item, kept in memory so the removal can be undone
+#[derive(Debug, Clone)]
+pub(crate) struct RemovedListValue {
+    key: SharedString,
+    index: usize,
+    value: SharedString,
+}
+
 /// Fetch a range of elements from a Redis List.
 ///
 /// Returns a vector of strings. Binary data is lossily converted to UTF-8.
@@ -74,7 +105,12 @@ impl ZedisServerState {
         value.data = Some(RedisValueData::List(Arc::new(new_list_value)));
         cx.emit(ServerEvent::ValueUpdated(self.key.clone().unwrap_or_default()));
     }
-    pub fn remove_list_value(&mut self, index: usize, cx: &mut Context<Self>) {
+    /// Remove an item from a Redis List.
+    ///
+    /// Like `update_list_value`, the item at `index` is checked against
+    /// `original_value` first, so a concurrent modification does not remove
+    /// the wrong item. The removed item can be restored by `undo_remove_list_value`.
+    pub fn remove_list_value(&mut self, index: usize, original_value: SharedString, cx: &mut Context<Self>) {
         let Some((key, value)) = self.try_get_mut_key_value() else {
             return;
         };
@@ -82,11 +118,27 @@ impl ZedisServerState {
         cx.notify();
         let server_id = self.server_id.clone();
         let key_clone = key.clone();
+        let original_value_clone = original_value.clone();
+        let audit_target = key_clone.to_string();
         self.spawn(
             ServerTask::RemoveListValue,
             move || async move {
                 let unique_marker = Uuid::new_v4().to_string();
                 let mut conn = get_connection_manager().get_connection(&server_id).await?;
+                let current_value: Option<String> = cmd("LINDEX")
+                    .arg(key.as_str())
+                    .arg(index)
+                    .query_async(&mut conn)
+                    .await?;
+                if current_value.as_deref() != Some(original_value_clone.as_str()) {
+                    return Err(Error::Invalid {
+                        message: format!(
+                            "Value changed (expected: '{}', actual: '{}'), remove aborted.",
+                            original_value_clone,
+                            current_value.unwrap_or_default()
+                        ),
+                    });
+                }
                 let _: () = pipe()
                     .atomic()
                     .cmd("LSET")
@@ -103,45 +155,154 @@ impl ZedisServerState {
                 Ok(())
             },
             move |this, result, cx| {
+                if result.is_ok() {
+                    this.add_audit_entry(&ServerTask::RemoveListValue, audit_target, cx);
+                }
                 if let Some(value) = this.value.as_mut() {
                     if result.is_ok()
                         && let Some(RedisValueData::List(list_data)) = value.data.as_mut()
                     {
                         let list = Arc::make_mut(list_data);
                         list.size -= 1;
-                        list.values.remove(index);
-                        cx.emit(ServerEvent::ValueUpdated(key_clone));
+                        if index < list.values.len() {
+                            list.values.remove(index);
+                        }
+                        cx.emit(ServerEvent::ValueUpdated(key_clone.clone()));
                     }
                     value.status = RedisValueStatus::Idle;
                 }
+                if result.is_ok() {
+                    if this.removed_list_values.len() >= LIST_UNDO_LIMIT {
+                        this.removed_list_values.remove(0);
+                    }
+                    this.removed_list_values.push(RemovedListValue {
+                        key: key_clone.clone(),
+                        index,
+                        value: original_value,
+                    });
+                    cx.emit(ServerEvent::ListValueRemoved(key_clone));
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+    /// Restore the latest removed item of the selected list at its original index.
+    ///
+    /// The item at that index is swapped with a unique marker, so LINSERT
+    /// inserts before the right item even if the list has duplicates.
+    pub fn undo_remove_list_value(&mut self, cx: &mut Context<Self>) {
+        let Some(key) = self.key.clone() else {
+            return;
+        };
+        let Some(position) = self.removed_list_values.iter().rposition(|item| item.key == key) else {
+            return;
+        };
+        let removed = self.removed_list_values.remove(position);
+        if let Some(value) = self.value.as_mut() {
+            value.status = RedisValueStatus::Updating;
+        }
+        cx.notify();
+        let server_id = self.server_id.clone();
+        let index = removed.index;
+        let item = removed.value.clone();
+        let audit_target = key.to_string();
+        self.spawn(
+            ServerTask::UndoRemoveListValue,
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id).await?;
+                let size: usize = cmd("LLEN").arg(key.as_str()).query_async(&mut conn).await?;
+                // The list is shorter now, append the item
+                if index >= size {
+                    let _: () = cmd("RPUSH")
+                        .arg(key.as_str())
+                        .arg(item.as_str())
+                        .query_async(&mut conn)
+                        .await?;
+                    return Ok(size);
+                }
+                let current_value: String = cmd("LINDEX")
+                    .arg(key.as_str())
+                    .arg(index)
+                    .query_async(&mut conn)
+                    .await?;
+                let unique_marker = Uuid::new_v4().to_string();
+                let _: () = pipe()
+                    .atomic()
+                    .cmd("LSET")
+                    .arg(key.as_str())
+                    .arg(index)
+                    .arg(&unique_marker)
+                    .cmd("LINSERT")
+                    .arg(key.as_str())
+                    .arg("BEFORE")
+                    .arg(&unique_marker)
+                    .arg(item.as_str())
+                    .cmd("LSET")
+                    .arg(key.as_str())
+                    .arg(index + 1)
+                    .arg(current_value)
+                    .query_async(&mut conn)
+                    .await?;
+                Ok(index)
+            },
+            move |this, result, cx| {
+                if result.is_ok() {
+                    this.add_audit_entry(&ServerTask::UndoRemoveListValue, audit_target, cx);
+                }
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                    if let Ok(index) = result
+                        && this.key.as_ref() == Some(&removed.key)
+                        && let Some(RedisValueData::List(list_data)) = value.data.as_mut()
+                    {
+                        let list = Arc::make_mut(list_data);
+                        // Only insert into the loaded part of the list
+                        let loaded = list.values.len();
+                        if index < loaded || (index == loaded && loaded == list.size) {
+                            list.values.insert(index, removed.value.clone());
+                        }
+                        list.size += 1;
+                        cx.emit(ServerEvent::ValueUpdated(removed.key.clone()));
+                    }
+                }
                 cx.notify();
             },
             cx,
         );
     }
-    pub fn push_list_value(&mut self, new_value: SharedString, mode: SharedString, cx: &mut Context<Self>) {
+    /// Pushes multiple elements to a Redis List with a single LPUSH/RPUSH.
+    ///
+    /// LPUSH inserts the elements one after another at the head,
+    /// so they end up in reverse order, the same as redis-cli.
+    pub fn push_list_values(&mut self, new_values: Vec<SharedString>, mode: SharedString, cx: &mut Context<Self>) {
+        if new_values.is_empty() {
+            return;
+        }
         let Some((key, value)) = self.try_get_mut_key_value() else {
             return;
         };
         let is_lpush = mode == "1";
+        let count = new_values.len();
         let mut pushed_value = false;
         value.status = RedisValueStatus::Updating;
         if let Some(RedisValueData::List(list_data)) = value.data.as_mut() {
             // Use Arc::make_mut to get mutable access (Cow behavior)
             let list = Arc::make_mut(list_data);
             if is_lpush {
-                list.values.insert(0, new_value.clone());
+                list.values.splice(0..0, new_values.iter().rev().cloned());
                 pushed_value = true;
             } else if list.values.len() == list.size {
-                list.values.push(new_value.clone());
+                list.values.extend(new_values.iter().cloned());
                 pushed_value = true;
             }
-            list.size += 1;
+            list.size += count;
         }
 
         cx.notify();
         let server_id = self.server_id.clone();
         let key_clone = key.clone();
+        let audit_target = key_clone.to_string();
         self.spawn(
             ServerTask::PushListValue,
             move || async move {
@@ -150,28 +311,30 @@ impl ZedisServerState {
 
                 let _: () = cmd(cmd_name)
                     .arg(key.as_str())
-                    .arg(new_value.as_str())
+                    .arg(new_values.iter().map(|v| v.as_str()).collect::<Vec<_>>())
                     .query_async(&mut conn)
                     .await?;
                 Ok(())
             },
             move |this, result, cx| {
+                if result.is_ok() {
+                    this.add_audit_entry(&ServerTask::PushListValue, audit_target, cx);
+                }
                 if let Some(value) = this.value.as_mut() {
                     value.status = RedisValueStatus::Idle;
                     if result.is_err()
-                        && pushed_value
                         && let Some(RedisValueData::List(list_data)) = this.value.as_mut().and_then(|v| v.data.as_mut())
                     {
                         // Use Arc::make_mut to get mutable access (Cow behavior)
                         let list = Arc::make_mut(list_data);
                         if pushed_value {
                             if is_lpush {
-                                list.values.remove(0);
+                                list.values.drain(0..count);
                             } else {
-                                list.values.pop();
+                                list.values.truncate(list.values.len().saturating_sub(count));
                             }
                         }
-                        list.size -= 1;
+                        list.size -= count;
                     }
                 }
                 cx.emit(ServerEvent::ValueUpdated(key_clone));
@@ -180,6 +343,137 @@ impl ZedisServerState {
             cx,
         );
     }
+    /// Insert a value before or after the item at `index` with LINSERT.
+    ///
+    /// LINSERT uses the first item equal to the pivot, so the item at `index`
+    /// is checked against `pivot` and swapped with a unique marker first,
+    /// the value is then inserted next to the right item even if the list has duplicates.
+    /// The check and the insert run in one script.
+    pub fn insert_list_value(
+        &mut self,
+        index: usize,
+        pivot: SharedString,
+        new_value: SharedString,
+        before: bool,
+        cx: &mut Context<Self>,
+    ) {
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+        value.status = RedisValueStatus::Updating;
+        cx.notify();
+        let server_id = self.server_id.clone();
+        let key_clone = key.clone();
+        let new_value_clone = new_value.clone();
+        let audit_target = key_clone.to_string();
+        self.spawn(
+            ServerTask::InsertListValue,
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id).await?;
+                let unique_marker = Uuid::new_v4().to_string();
+                let position = if before { "BEFORE" } else { "AFTER" };
+                // The pivot moves down one item when the value is inserted before it
+                let pivot_index = if before { index + 1 } else { index };
+                let (inserted, result): (bool, Option<String>) = INSERT_LIST_VALUE_SCRIPT
+                    .key(key.as_str())
+                    .arg(index)
+                    .arg(pivot.as_str())
+                    .arg(&unique_marker)
+                    .arg(position)
+                    .arg(new_value_clone.as_str())
+                    .arg(pivot_index)
+                    .invoke_async(&mut conn)
+                    .await?;
+                let result = result.unwrap_or_default();
+                if !inserted {
+                    return Err(Error::Invalid {
+                        message: format!(
+                            "Value changed (expected: '{}', actual: '{}'), insert aborted.",
+                            pivot, result
+                        ),
+                    });
+                }
+                let size: usize = result.parse().map_err(|e| Error::Invalid {
+                    message: format!("Invalid list size {e:?}"),
+                })?;
+                Ok(size)
+            },
+            move |this, result, cx| {
+                if result.is_ok() {
+                    this.add_audit_entry(&ServerTask::InsertListValue, audit_target, cx);
+                }
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                    if let Ok(size) = result
+                        && this.key.as_ref() == Some(&key_clone)
+                        && let Some(RedisValueData::List(list_data)) = value.data.as_mut()
+                    {
+                        let list = Arc::make_mut(list_data);
+                        let insert_index = if before { index } else { index + 1 };
+                        // Only insert into the loaded part of the list
+                        let loaded = list.values.len();
+                        if insert_index < loaded || (insert_index == loaded && loaded == list.size) {
+                            list.values.insert(insert_index, new_value);
+                        }
+                        list.size = size;
+                        cx.emit(ServerEvent::ValueUpdated(key_clone));
+                    }
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+    /// Trim the selected list to the items from `start` to `stop` with LTRIM.
+    ///
+    /// Negative indexes count from the end of the list, like LTRIM.
+    /// The list is loaded again from the first page once trimmed.
+    pub fn trim_list_value(&mut self, start: i64, stop: i64, cx: &mut Context<Self>) {
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+        value.status = RedisValueStatus::Updating;
+        cx.notify();
+        let server_id = self.server_id.clone();
+        let key_clone = key.clone();
+        let audit_target = format!("{key} {start} {stop}");
+        self.spawn(
+            ServerTask::TrimListValue,
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id).await?;
+                let _: () = cmd("LTRIM")
+                    .arg(key.as_str())
+                    .arg(start)
+                    .arg(stop)
+                    .query_async(&mut conn)
+                    .await?;
+                first_load_list_value(&mut conn, &key).await
+            },
+            move |this, result, cx| {
+                if result.is_ok() {
+                    this.add_audit_entry(&ServerTask::TrimListValue, audit_target, cx);
+                }
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                    if let Ok(new_value) = result
+                        && this.key.as_ref() == Some(&key_clone)
+                    {
+                        let size = new_value.list_value().map(|list| list.size).unwrap_or_default();
+                        value.data = new_value.data;
+                        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale().to_string();
+                        let message = t!("list_editor.trim_success", size = size, locale = locale);
+                        cx.emit(ServerEvent::Notification(
+                            NotificationAction::new_success(message.to_string().into())
+                                .with_title(i18n_list_editor(cx, "trim_title")),
+                        ));
+                        cx.emit(ServerEvent::ValueUpdated(key_clone));
+                    }
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
     /// Update a specific item in a Redis List.
     ///
     /// Performs an optimistic lock check: verifies if the current value at `index`
@@ -213,6 +507,7 @@ impl ZedisServerState {
         let original_value_clone = original_value.clone();
         let new_value_clone = new_value.clone();
 
+        let audit_target = key_clone.to_string();
         self.spawn(
             ServerTask::UpdateListValue,
             move || async move {
@@ -246,6 +541,9 @@ impl ZedisServerState {
                 Ok(())
             },
             move |this, result, cx| {
+                if result.is_ok() {
+                    this.add_audit_entry(&ServerTask::UpdateListValue, audit_target, cx);
+                }
                 if let Some(value) = this.value.as_mut() {
                     value.status = RedisValueStatus::Idle;
                     if result.is_err()
@@ -288,7 +586,7 @@ impl ZedisServerState {
         self.spawn(
             ServerTask::LoadMoreValue,
             move || async move {
-                let mut conn = get_connection_manager().get_connection(&server_id).await?;
+                let mut conn = get_connection_manager().get_read_connection(&server_id).await?;
                 // Fetch only the new items
                 let new_values = get_redis_list_value(&mut conn, &key, start, stop).await?;
                 Ok(new_values)
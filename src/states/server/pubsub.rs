@@ -0,0 +1,259 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Redis Pub/Sub operations module.
+//!
+//! This module provides functionality for watching Redis channels including:
+//! - Subscribing to channels (SUBSCRIBE) or patterns (PSUBSCRIBE) on a dedicated connection
+//! - Sharing that connection across all subscriptions of the server
+//! - Unsubscribing from individual channels (UNSUBSCRIBE)
+//! - Keeping a bounded log of received messages
+//! - Closing the connection when the view is left
+
+use super::{ServerEvent, ServerTask, ZedisServerState};
+use crate::connection::get_connection_manager;
+use chrono::Local;
+use futures::StreamExt;
+use gpui::{SharedString, Task, prelude::*};
+use redis::{Msg, aio::PubSubSink};
+use std::{collections::VecDeque, fmt, sync::Arc};
+use tracing::info;
+
+// Maximum messages to keep in memory, older messages are dropped first
+const MAX_PUBSUB_MESSAGES: usize = 1000;
+
+/// Returns true if the channel is a glob pattern, subscribed with PSUBSCRIBE
+fn is_pattern(channel: &str) -> bool {
+    channel.contains(['*', '?', '['])
+}
+
+/// A message received from a subscribed channel
+#[derive(Debug, Clone)]
+pub struct PubSubMessage {
+    /// Channel the message was published to
+    pub channel: SharedString,
+    /// Message payload (lossy UTF-8)
+    pub payload: SharedString,
+    /// Local time when the message was received
+    pub received_at: SharedString,
+}
+
+impl From<Msg> for PubSubMessage {
+    fn from(msg: Msg) -> Self {
+        Self {
+            channel: msg.get_channel_name().to_string().into(),
+            payload: String::from_utf8_lossy(msg.get_payload_bytes()).to_string().into(),
+            received_at: Local::now().format("%H:%M:%S%.3f").to_string().into(),
+        }
+    }
+}
+
+/// State of the dedicated pub/sub connection
+#[derive(Clone, Default)]
+pub struct PubSubState {
+    /// Sink used to manage subscriptions, shared by all channels
+    sink: Option<PubSubSink>,
+    /// Task that forwards incoming messages, dropped to stop listening
+    listener: Option<Arc<Task<()>>>,
+    /// Whether the dedicated connection is being established
+    connecting: bool,
+    /// Subscribed channels
+    channels: Vec<SharedString>,
+    /// Received messages (limited to MAX_PUBSUB_MESSAGES)
+    messages: VecDeque<PubSubMessage>,
+    /// Number of messages received since the log was cleared
+    received_count: u64,
+}
+
+impl fmt::Debug for PubSubState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PubSubState")
+            .field("connected", &self.sink.is_some())
+            .field("connecting", &self.connecting)
+            .field("channels", &self.channels)
+            .field("messages", &self.messages.len())
+            .finish()
+    }
+}
+
+impl PubSubState {
+    /// Get the subscribed channels
+    pub fn channels(&self) -> &[SharedString] {
+        &self.channels
+    }
+    /// Get the received messages, oldest first
+    pub fn messages(&self) -> &VecDeque<PubSubMessage> {
+        &self.messages
+    }
+    /// Whether the dedicated connection is being established
+    pub fn connecting(&self) -> bool {
+        self.connecting
+    }
+    /// Number of messages received, including the ones dropped from the log
+    pub fn received_count(&self) -> u64 {
+        self.received_count
+    }
+    fn push_message(&mut self, message: PubSubMessage) {
+        self.received_count += 1;
+        if self.messages.len() >= MAX_PUBSUB_MESSAGES {
+            self.messages.pop_front();
+        }
+        self.messages.push_back(message);
+    }
+}
+
+impl ZedisServerState {
+    /// Get the pub/sub state
+    pub fn pubsub(&self) -> &PubSubState {
+        &self.pubsub
+    }
+    /// Subscribes to a channel with SUBSCRIBE, or to a glob pattern with PSUBSCRIBE.
+    ///
+    /// The first subscription opens a dedicated connection and starts listening,
+    /// later subscriptions reuse the same connection.
+    ///
+    /// # Arguments
+    /// * `channel` - The channel name or pattern to subscribe
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn subscribe_channel(&mut self, channel: SharedString, cx: &mut Context<Self>) {
+        if channel.is_empty() || self.pubsub.connecting || self.pubsub.channels.contains(&channel) {
+            return;
+        }
+        let server_id = self.server_id.clone();
+        let channel_clone = channel.clone();
+
+        // Reuse the established connection
+        if let Some(mut sink) = self.pubsub.sink.clone() {
+            self.spawn(
+                ServerTask::Subscribe,
+                move || async move {
+                    if is_pattern(&channel) {
+                        sink.psubscribe(channel.as_str()).await?;
+                    } else {
+                        sink.subscribe(channel.as_str()).await?;
+                    }
+                    Ok(())
+                },
+                move |this, result, cx| {
+                    if result.is_ok() && !this.pubsub.channels.contains(&channel_clone) {
+                        this.pubsub.channels.push(channel_clone);
+                        cx.emit(ServerEvent::PubSubSubscriptionsUpdated);
+                    }
+                    cx.notify();
+                },
+                cx,
+            );
+            return;
+        }
+
+        self.pubsub.connecting = true;
+        cx.notify();
+        self.spawn(
+            ServerTask::Subscribe,
+            move || async move {
+                let pubsub = get_connection_manager().get_pubsub(&server_id).await?;
+                let (mut sink, stream) = pubsub.split();
+                if is_pattern(&channel) {
+                    sink.psubscribe(channel.as_str()).await?;
+                } else {
+                    sink.subscribe(channel.as_str()).await?;
+                }
+                Ok((sink, stream))
+            },
+            move |this, result, cx| {
+                this.pubsub.connecting = false;
+                if let Ok((sink, mut stream)) = result {
+                    let listener = cx.spawn(async move |handle, cx| {
+                        while let Some(msg) = stream.next().await {
+                            let message = PubSubMessage::from(msg);
+                            let result = handle.update(cx, |this, cx| {
+                                let channel = message.channel.clone();
+                                this.pubsub.push_message(message);
+                                cx.emit(ServerEvent::PubSubMessage(channel));
+                                cx.notify();
+                            });
+                            if result.is_err() {
+                                break;
+                            }
+                        }
+                        info!("Pub/Sub listener stopped");
+                    });
+                    this.pubsub.sink = Some(sink);
+                    this.pubsub.listener = Some(Arc::new(listener));
+                    this.pubsub.channels.push(channel_clone);
+                    cx.emit(ServerEvent::PubSubSubscriptionsUpdated);
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+    /// Unsubscribes from a channel with UNSUBSCRIBE, or from a pattern with PUNSUBSCRIBE.
+    ///
+    /// The dedicated connection is closed once the last channel is removed.
+    ///
+    /// # Arguments
+    /// * `channel` - The channel name to unsubscribe
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn unsubscribe_channel(&mut self, channel: SharedString, cx: &mut Context<Self>) {
+        let Some(mut sink) = self.pubsub.sink.clone() else {
+            return;
+        };
+        let channel_clone = channel.clone();
+        self.spawn(
+            ServerTask::Unsubscribe,
+            move || async move {
+                if is_pattern(&channel) {
+                    sink.punsubscribe(channel.as_str()).await?;
+                } else {
+                    sink.unsubscribe(channel.as_str()).await?;
+                }
+                Ok(())
+            },
+            move |this, result, cx| {
+                if result.is_ok() {
+                    this.pubsub.channels.retain(|item| item != &channel_clone);
+                    if this.pubsub.channels.is_empty() {
+                        this.pubsub.sink = None;
+                        this.pubsub.listener = None;
+                    }
+                    cx.emit(ServerEvent::PubSubSubscriptionsUpdated);
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+    /// Clears the received pub/sub messages
+    pub fn clear_pubsub_messages(&mut self, cx: &mut Context<Self>) {
+        self.pubsub.messages.clear();
+        self.pubsub.received_count = 0;
+        cx.notify();
+    }
+    /// Closes the dedicated connection and drops all subscriptions.
+    ///
+    /// Dropping the listener task stops reading messages, the connection
+    /// is closed together with its sink.
+    pub fn close_pubsub(&mut self, cx: &mut Context<Self>) {
+        if self.pubsub.sink.is_none() && self.pubsub.channels.is_empty() {
+            return;
+        }
+        self.pubsub.sink = None;
+        self.pubsub.listener = None;
+        self.pubsub.channels.clear();
+        info!("Pub/Sub connection closed");
+        cx.emit(ServerEvent::PubSubSubscriptionsUpdated);
+        cx.notify();
+    }
+}
@@ -14,6 +14,7 @@
 
 use super::{
     KeyType, RedisValueData, ServerTask, ZedisServerState,
+    key::keyword_pattern,
     value::{NotificationAction, RedisSetValue, RedisValue, RedisValueStatus},
 };
 use crate::{
@@ -21,6 +22,7 @@ use crate::{
     error::Error,
     states::{ServerEvent, i18n_set_editor},
 };
+use ahash::AHashSet;
 use gpui::{SharedString, prelude::*};
 use redis::cmd;
 use std::sync::Arc;
@@ -45,11 +47,8 @@ async fn get_redis_set_value(
     cursor: u64,
     count: usize,
 ) -> Result<(u64, Vec<String>)> {
-    // Build pattern: wrap keyword with wildcards or match all
-    let pattern = keyword
-        .as_ref()
-        .map(|kw| format!("*{}*", kw))
-        .unwrap_or_else(|| "*".to_string());
+    // Build pattern: wrap the escaped keyword with wildcards or match all
+    let pattern = keyword_pattern(keyword.as_deref().unwrap_or_default(), false);
 
     // Execute SSCAN with MATCH and COUNT options
     let (next_cursor, raw_values): (u64, Vec<Vec<u8>>) = cmd("SSCAN")
@@ -133,6 +132,7 @@ impl ZedisServerState {
         let key_clone = key.clone();
         let new_value_clone = new_value.clone();
 
+        let audit_target = key_clone.to_string();
         self.spawn(
             ServerTask::AddSetValue,
             // Async operation: execute SADD on Redis
@@ -149,6 +149,9 @@ impl ZedisServerState {
             },
             // UI callback: handle result and update state
             move |this, result, cx| {
+                if result.is_ok() {
+                    this.add_audit_entry(&ServerTask::AddSetValue, audit_target, cx);
+                }
                 let Some(value) = this.value.as_mut() else {
                     return;
                 };
@@ -268,9 +271,16 @@ impl ZedisServerState {
                         set.done = true;
                     }
 
-                    // Append new members to existing list
+                    // Append new members to existing list,
+                    // SSCAN may return a member more than once across cursor iterations
                     if !new_values.is_empty() {
-                        set.values.extend(new_values.into_iter().map(|v| v.into()));
+                        let mut loaded: AHashSet<SharedString> = set.values.iter().cloned().collect();
+                        for value in new_values {
+                            let value: SharedString = value.into();
+                            if loaded.insert(value.clone()) {
+                                set.values.push(value);
+                            }
+                        }
                     }
 
                     // Auto-load more batches when filtering until we have enough results
@@ -317,6 +327,7 @@ impl ZedisServerState {
         let remove_value_clone = remove_value.clone();
         let key_clone = key.clone();
 
+        let audit_target = key_clone.to_string();
         self.spawn(
             ServerTask::RemoveSetValue,
             // Async operation: execute SREM on Redis
@@ -333,6 +344,9 @@ impl ZedisServerState {
             },
             // UI callback: update local state to reflect removal
             move |this, result, cx| {
+                if result.is_ok() {
+                    this.add_audit_entry(&ServerTask::RemoveSetValue, audit_target, cx);
+                }
                 if let Ok(count) = result
                     && let Some(RedisValueData::Set(set_data)) = this.value.as_mut().and_then(|v| v.data.as_mut())
                 {
@@ -13,13 +13,31 @@
 // limitations under the License.
 
 use crate::connection::get_connection_manager;
-use crate::states::{ServerEvent, ServerTask, ZedisServerState};
+use crate::error::Error;
+use crate::states::{NotificationAction, ServerEvent, ServerTask, ZedisGlobalStore, ZedisServerState, i18n_servers};
+use futures::{StreamExt, stream};
 use gpui::prelude::*;
 use redis::cmd;
+use rust_i18n::t;
 use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::Duration;
 use std::time::Instant;
-use tracing::error;
+use tracing::{debug, error};
+
+// Max servers pinged at the same time by the server list
+const PING_SERVERS_CONCURRENCY: usize = 5;
+
+/// Result of pinging a server of the server list
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ServerPing {
+    /// PING round trip time
+    Latency(Duration),
+    /// The password is prompted on connect and has not been entered yet
+    PasswordRequired,
+    /// The server can not be reached
+    Unreachable,
+}
 
 #[derive(Debug, Default, Clone)]
 pub struct RedisKeySpaceStats {
@@ -28,6 +46,15 @@ pub struct RedisKeySpaceStats {
     pub avg_ttl: u64,
 }
 
+/// Memory usage of a single master node
+#[derive(Debug, Default, Clone)]
+pub struct RedisNodeMemory {
+    pub addr: String,
+    pub used_memory: u64,
+    pub used_memory_human: String,
+    pub maxmemory: u64,
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct RedisInfo {
     pub latency: Duration,
@@ -64,6 +91,9 @@ pub struct RedisInfo {
 
     // --- Keyspace (db0, db1...) ---
     pub keyspace: HashMap<String, RedisKeySpaceStats>,
+
+    // --- Nodes (only filled when aggregated from the masters) ---
+    pub nodes: Vec<RedisNodeMemory>,
 }
 
 /// Aggregates metrics from multiple Redis Cluster nodes into a single global view.
@@ -247,7 +277,45 @@ fn parse_keyspace_value(v: &str) -> Result<RedisKeySpaceStats, ()> {
     Ok(stats)
 }
 
+/// Runs INFO ALL on every master and aggregates the results, keeping the memory of each node.
+async fn fetch_redis_info(server_id: &str) -> Result<RedisInfo, Error> {
+    let manager = get_connection_manager();
+    let list: Vec<String> = manager
+        .query_async_masters(server_id, vec![cmd("INFO").arg("ALL").clone()])
+        .await?;
+    let infos: Vec<RedisInfo> = list.iter().map(|info| RedisInfo::parse(info)).collect();
+    let addrs = manager.master_host_ports(server_id);
+    let nodes = infos
+        .iter()
+        .enumerate()
+        .map(|(index, info)| RedisNodeMemory {
+            addr: addrs.get(index).cloned().unwrap_or_default(),
+            used_memory: info.used_memory,
+            used_memory_human: info.used_memory_human.clone(),
+            maxmemory: info.maxmemory,
+        })
+        .collect();
+    let mut info = aggregate_redis_info(infos);
+    info.nodes = nodes;
+    Ok(info)
+}
+
 impl ZedisServerState {
+    /// Re-pings the server and reloads the selected key.
+    ///
+    /// Called when the window regains focus (if enabled in settings),
+    /// so the displayed value reflects changes made outside of Zedis.
+    /// The key is not reloaded while its value has unsaved edits, they would be lost.
+    pub fn refresh_on_focus(&mut self, cx: &mut Context<Self>) {
+        if self.server_id.is_empty() {
+            return;
+        }
+        self.refresh_redis_info(cx);
+        if self.unsaved_value_edits || self.key.as_ref().is_none_or(|key| key.is_empty()) {
+            return;
+        }
+        self.reload_key(cx);
+    }
     pub fn refresh_redis_info(&mut self, cx: &mut Context<Self>) {
         if self.server_id.is_empty() {
             return;
@@ -259,29 +327,137 @@ impl ZedisServerState {
         self.spawn(
             ServerTask::RefreshRedisInfo,
             move || async move {
-                let client = get_connection_manager().get_client(&server_id).await?;
+                // The heartbeat only needs the connection and master addresses,
+                // avoid cloning the whole client every time
+                let manager = get_connection_manager();
+                let mut conn = manager.get_connection(&server_id).await?;
                 let start = Instant::now();
-                client.ping().await?;
-                let latency = start.elapsed();
+                let ping: redis::RedisResult<()> = cmd("PING").query_async(&mut conn).await;
+                let mut latency = start.elapsed();
+                // The sentinel may have promoted another master since the last heartbeat.
+                // Checked at once if the master does not answer, otherwise throttled,
+                // as a demoted master still answers PING. A failed check is not fatal.
+                let failover = manager
+                    .check_sentinel_failover(&server_id, ping.is_err())
+                    .await
+                    .unwrap_or_else(|e| {
+                        error!(error = %e, "Check sentinel failover fail");
+                        None
+                    })
+                    .map(|client| client.nodes_description());
+                match ping {
+                    // Ping the new master instead
+                    Err(_) if failover.is_some() => {
+                        let mut conn = manager.get_connection(&server_id).await?;
+                        let start = Instant::now();
+                        let _: () = cmd("PING").query_async(&mut conn).await?;
+                        latency = start.elapsed();
+                    }
+                    Err(e) => return Err(e.into()),
+                    Ok(()) => {}
+                }
 
-                let list: Vec<String> = client.query_async_masters(vec![cmd("INFO").arg("ALL").clone()]).await?;
-                let infos: Vec<RedisInfo> = list.iter().map(|info| RedisInfo::parse(info)).collect();
-                let mut info = aggregate_redis_info(infos);
+                let mut info = fetch_redis_info(&server_id).await?;
                 info.latency = latency;
-                Ok(info)
+                Ok((info, failover))
             },
             move |this, result, cx| match result {
-                Ok(info) => {
+                // Ignore the result of a server that is no longer selected
+                Ok(_) if this.server_id != server_id_clone => {}
+                Ok((info, failover)) => {
+                    if let Some(nodes_description) = failover {
+                        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale().to_string();
+                        let message = t!(
+                            "servers.failover_detected",
+                            master = nodes_description.master_nodes,
+                            locale = locale
+                        );
+                        this.nodes_description = Arc::new(nodes_description);
+                        cx.emit(ServerEvent::Notification(
+                            NotificationAction::new_warning(message.to_string().into())
+                                .with_title(i18n_servers(cx, "failover_title")),
+                        ));
+                    }
                     this.redis_info = Some(info);
                     cx.emit(ServerEvent::ServerRedisInfoUpdated(server_id_clone.clone()));
                 }
                 Err(e) => {
-                    // Connection is invalid, remove cached client
-                    get_connection_manager().remove_client(&server_id_clone);
+                    // Connection is invalid, remove cached client.
+                    // A sentinel resolves its master again on the next connect
+                    get_connection_manager().reset_client(&server_id_clone);
                     error!(error = %e, "Ping failed, client connection removed");
                 }
             },
             cx,
         );
     }
+    /// Loads INFO from all masters without pinging, used by the dashboard.
+    ///
+    /// The latency of the last heartbeat is kept.
+    pub fn load_server_info(&mut self, cx: &mut Context<Self>) {
+        if self.server_id.is_empty() {
+            return;
+        }
+        let server_id = self.server_id.clone();
+        let server_id_clone = server_id.clone();
+
+        self.spawn(
+            ServerTask::LoadServerInfo,
+            move || async move { fetch_redis_info(&server_id).await },
+            move |this, result, cx| match result {
+                Ok(_) if this.server_id != server_id_clone => {}
+                Ok(mut info) => {
+                    info.latency = this.redis_info.as_ref().map(|info| info.latency).unwrap_or_default();
+                    this.redis_info = Some(info);
+                    cx.emit(ServerEvent::ServerRedisInfoUpdated(server_id_clone.clone()));
+                }
+                // Errors are shown in the status bar by spawn
+                Err(_) => {}
+            },
+            cx,
+        );
+    }
+
+    /// Pings every configured server concurrently, used by the server list.
+    pub fn ping_servers(&mut self, cx: &mut Context<Self>) {
+        let server_ids: Vec<String> = self
+            .servers
+            .as_ref()
+            .map(|servers| servers.iter().map(|server| server.id.clone()).collect())
+            .unwrap_or_default();
+        if server_ids.is_empty() {
+            return;
+        }
+        self.spawn(
+            ServerTask::PingServers,
+            move || async move {
+                let latencies: Vec<(String, ServerPing)> = stream::iter(server_ids)
+                    .map(|server_id| async move {
+                        let ping = match get_connection_manager().ping(&server_id).await {
+                            Ok(latency) => latency.map(ServerPing::Latency),
+                            // Not pinged until the user enters the password on connect
+                            Err(Error::PasswordRequired) => Some(ServerPing::PasswordRequired),
+                            Err(e) => {
+                                debug!(server_id, error = %e, "Ping server failed");
+                                Some(ServerPing::Unreachable)
+                            }
+                        };
+                        // Skipped servers show no latency
+                        ping.map(|ping| (server_id, ping))
+                    })
+                    .buffer_unordered(PING_SERVERS_CONCURRENCY)
+                    .filter_map(|item| async move { item })
+                    .collect()
+                    .await;
+                Ok(latencies)
+            },
+            move |this, result, cx| {
+                if let Ok(latencies) = result {
+                    this.server_latencies = latencies.into_iter().collect();
+                    cx.notify();
+                }
+            },
+            cx,
+        );
+    }
 }
@@ -0,0 +1,355 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Redis STREAM data type operations module.
+//!
+//! This module provides functionality for managing Redis STREAM operations including:
+//! - Loading entries page by page via XRANGE, starting after the last loaded ID
+//! - Loading consumer groups via XINFO GROUPS
+//! - Adding entries (XADD) and removing entries (XDEL)
+//! - Filtering the loaded entries by keyword
+
+use super::{
+    KeyType, RedisValueData, ServerTask, ZedisServerState,
+    value::{RedisStreamGroup, RedisStreamValue, RedisValue, RedisValueStatus},
+};
+use crate::{
+    connection::{RedisAsyncConn, get_connection_manager},
+    error::Error,
+    states::{NotificationAction, ServerEvent, ZedisGlobalStore, i18n_stream_editor},
+};
+use gpui::{SharedString, prelude::*};
+use redis::cmd;
+use rust_i18n::t;
+use std::{collections::HashMap, sync::Arc};
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+// Number of entries loaded per page
+const STREAM_PAGE_SIZE: usize = 100;
+
+type StreamEntry = (SharedString, Vec<(SharedString, SharedString)>);
+
+/// Returns the smallest ID greater than `id`.
+///
+/// XRANGE only supports exclusive ranges since Redis 6.2,
+/// so the next page starts at the following sequence number instead.
+fn next_stream_id(id: &str) -> String {
+    let Some((ms, seq)) = id.split_once('-') else {
+        return id.to_string();
+    };
+    let (Ok(ms), Ok(seq)) = (ms.parse::<u64>(), seq.parse::<u64>()) else {
+        return id.to_string();
+    };
+    match seq.checked_add(1) {
+        Some(seq) => format!("{ms}-{seq}"),
+        None => format!("{}-0", ms.saturating_add(1)),
+    }
+}
+
+/// Retrieves stream entries using XRANGE.
+///
+/// # Arguments
+/// * `conn` - Redis async connection
+/// * `key` - The STREAM key
+/// * `start` - First ID of the range (inclusive), "-" for the beginning
+/// * `count` - Maximum number of entries to return
+async fn get_redis_stream_value(
+    conn: &mut RedisAsyncConn,
+    key: &str,
+    start: &str,
+    count: usize,
+) -> Result<Vec<StreamEntry>> {
+    let entries: Vec<(String, Vec<Vec<u8>>)> = cmd("XRANGE")
+        .arg(key)
+        .arg(start)
+        .arg("+")
+        .arg("COUNT")
+        .arg(count)
+        .query_async(conn)
+        .await?;
+
+    // Fields are returned as a flat list: field1, value1, field2, value2, ...
+    let entries = entries
+        .into_iter()
+        .map(|(id, items)| {
+            let fields = items
+                .chunks_exact(2)
+                .map(|pair| {
+                    (
+                        String::from_utf8_lossy(&pair[0]).to_string().into(),
+                        String::from_utf8_lossy(&pair[1]).to_string().into(),
+                    )
+                })
+                .collect();
+            (id.into(), fields)
+        })
+        .collect();
+    Ok(entries)
+}
+
+/// Retrieves the consumer groups of the stream using XINFO GROUPS.
+async fn get_redis_stream_groups(conn: &mut RedisAsyncConn, key: &str) -> Result<Vec<RedisStreamGroup>> {
+    let groups: Vec<HashMap<String, Option<String>>> = cmd("XINFO").arg("GROUPS").arg(key).query_async(conn).await?;
+    let get = |group: &HashMap<String, Option<String>>, name: &str| -> String {
+        group.get(name).cloned().flatten().unwrap_or_default()
+    };
+    let groups = groups
+        .iter()
+        .map(|group| RedisStreamGroup {
+            name: get(group, "name").into(),
+            consumers: get(group, "consumers").parse().unwrap_or_default(),
+            pending: get(group, "pending").parse().unwrap_or_default(),
+            last_delivered_id: get(group, "last-delivered-id").into(),
+        })
+        .collect();
+    Ok(groups)
+}
+
+/// Performs initial load of a Redis STREAM value.
+///
+/// Fetches the length (XLEN), the first page of entries and the consumer groups.
+///
+/// # Arguments
+/// * `conn` - Redis async connection
+/// * `key` - The STREAM key to load
+///
+/// # Returns
+/// A `RedisValue` containing STREAM metadata and the first page of entries
+pub(crate) async fn first_load_stream_value(conn: &mut RedisAsyncConn, key: &str) -> Result<RedisValue> {
+    let size: usize = cmd("XLEN").arg(key).query_async(conn).await?;
+    let values = get_redis_stream_value(conn, key, "-", STREAM_PAGE_SIZE).await?;
+    // Consumer groups are secondary info, servers rejecting XINFO (e.g. ACL rules)
+    // can not fail the value loading
+    let groups = get_redis_stream_groups(conn, key).await.unwrap_or_default();
+
+    let done = values.len() < STREAM_PAGE_SIZE;
+    let last_id = values.last().map(|(id, _)| id.clone());
+
+    Ok(RedisValue {
+        key_type: KeyType::Stream,
+        data: Some(RedisValueData::Stream(Arc::new(RedisStreamValue {
+            size,
+            values,
+            last_id,
+            done,
+            groups,
+            ..Default::default()
+        }))),
+        ..Default::default()
+    })
+}
+
+impl ZedisServerState {
+    /// Filters the loaded stream entries by keyword.
+    ///
+    /// The filter is applied locally on IDs, fields and values.
+    pub fn filter_stream_value(&mut self, keyword: SharedString, cx: &mut Context<Self>) {
+        let Some((_, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+        let Some(stream) = value.stream_value() else {
+            return;
+        };
+        let new_stream = RedisStreamValue {
+            keyword: Some(keyword),
+            ..stream.as_ref().clone()
+        };
+        value.data = Some(RedisValueData::Stream(Arc::new(new_stream)));
+        cx.emit(ServerEvent::ValueUpdated(self.key.clone().unwrap_or_default()));
+    }
+    /// Loads the next page of stream entries, starting after the last loaded ID.
+    ///
+    /// # Arguments
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn load_more_stream_value(&mut self, cx: &mut Context<Self>) {
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+        let start = match value.stream_value() {
+            Some(stream) if !stream.done => stream
+                .last_id
+                .as_ref()
+                .map(|id| next_stream_id(id))
+                .unwrap_or_else(|| "-".to_string()),
+            _ => return,
+        };
+
+        value.status = RedisValueStatus::Loading;
+        cx.notify();
+
+        let server_id = self.server_id.clone();
+        cx.emit(ServerEvent::ValuePaginationStarted(key.clone()));
+        let key_clone = key.clone();
+
+        self.spawn(
+            ServerTask::LoadMoreValue,
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id).await?;
+                get_redis_stream_value(&mut conn, &key, &start, STREAM_PAGE_SIZE).await
+            },
+            move |this, result, cx| {
+                if let Ok(new_values) = result
+                    && let Some(RedisValueData::Stream(stream_data)) = this.value.as_mut().and_then(|v| v.data.as_mut())
+                {
+                    let stream = Arc::make_mut(stream_data);
+                    stream.done = new_values.len() < STREAM_PAGE_SIZE;
+                    if let Some((id, _)) = new_values.last() {
+                        stream.last_id = Some(id.clone());
+                    }
+                    stream.values.extend(new_values);
+                }
+
+                cx.emit(ServerEvent::ValuePaginationFinished(key_clone));
+
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+    /// Adds a new entry to the Redis STREAM with XADD.
+    ///
+    /// # Arguments
+    /// * `id` - The entry ID, empty or "*" to let Redis generate it
+    /// * `fields` - The field-value pairs of the entry
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn add_stream_value(
+        &mut self,
+        id: SharedString,
+        fields: Vec<(SharedString, SharedString)>,
+        cx: &mut Context<Self>,
+    ) {
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+        if fields.is_empty() {
+            return;
+        }
+
+        value.status = RedisValueStatus::Updating;
+        cx.notify();
+
+        let server_id = self.server_id.clone();
+        let key_clone = key.clone();
+        let fields_clone = fields.clone();
+        let audit_target = key_clone.to_string();
+        let id = if id.trim().is_empty() {
+            "*".to_string()
+        } else {
+            id.trim().to_string()
+        };
+
+        self.spawn(
+            ServerTask::AddStreamValue,
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id).await?;
+                let mut command = cmd("XADD");
+                command.arg(key.as_str()).arg(id);
+                for (field, value) in fields.iter() {
+                    command.arg(field.as_str()).arg(value.as_str());
+                }
+                // XADD returns the ID of the added entry
+                let new_id: String = command.query_async(&mut conn).await?;
+                Ok(SharedString::from(new_id))
+            },
+            move |this, result, cx| {
+                if let Ok(new_id) = &result {
+                    this.add_audit_entry(&ServerTask::AddStreamValue, audit_target, cx);
+                    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale().to_string();
+                    let title = i18n_stream_editor(cx, "add_value_success");
+                    let msg = t!("stream_editor.add_value_success_tips", id = new_id, locale = locale).to_string();
+                    cx.emit(ServerEvent::Notification(
+                        NotificationAction::new_success(msg.into()).with_title(title),
+                    ));
+                }
+                let Some(value) = this.value.as_mut() else {
+                    return;
+                };
+                value.status = RedisValueStatus::Idle;
+
+                if let Ok(new_id) = result
+                    && let Some(RedisValueData::Stream(stream_data)) = value.data.as_mut()
+                {
+                    let stream = Arc::make_mut(stream_data);
+                    stream.size += 1;
+                    // New entries are appended at the end of the stream,
+                    // only show it when all entries have been loaded
+                    if stream.done {
+                        stream.last_id = Some(new_id.clone());
+                        stream.values.push((new_id, fields_clone));
+                    }
+                }
+
+                cx.emit(ServerEvent::ValueAdded(key_clone));
+                cx.notify();
+            },
+            cx,
+        );
+    }
+    /// Removes an entry from the Redis STREAM with XDEL.
+    ///
+    /// # Arguments
+    /// * `id` - The ID of the entry to remove
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn remove_stream_value(&mut self, id: SharedString, cx: &mut Context<Self>) {
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+
+        value.status = RedisValueStatus::Loading;
+        cx.notify();
+
+        let server_id = self.server_id.clone();
+        let id_clone = id.clone();
+        let key_clone = key.clone();
+        let audit_target = format!("{key_clone} {id_clone}");
+
+        self.spawn(
+            ServerTask::RemoveStreamValue,
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id).await?;
+                // XDEL returns the number of entries removed
+                let count: usize = cmd("XDEL")
+                    .arg(key.as_str())
+                    .arg(id.as_str())
+                    .query_async(&mut conn)
+                    .await?;
+                Ok(count)
+            },
+            move |this, result, cx| {
+                if result.is_ok() {
+                    this.add_audit_entry(&ServerTask::RemoveStreamValue, audit_target, cx);
+                }
+                if let Ok(count) = result
+                    && let Some(RedisValueData::Stream(stream_data)) = this.value.as_mut().and_then(|v| v.data.as_mut())
+                {
+                    let stream = Arc::make_mut(stream_data);
+                    stream.size = stream.size.saturating_sub(count);
+                    stream.values.retain(|(id, _)| id != &id_clone);
+                }
+
+                cx.emit(ServerEvent::ValueUpdated(key_clone));
+
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+}
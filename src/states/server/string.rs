@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use super::value::{DataFormat, KeyType, RedisBytesValue, RedisValue, RedisValueData, ViewMode, detect_format};
-use crate::helpers::decompress_zstd;
+use crate::helpers::{decompress_zstd, pretty_json};
 use crate::{connection::RedisAsyncConn, error::Error};
 use bytes::Bytes;
 use flate2::read::GzDecoder;
@@ -25,17 +25,46 @@ use std::sync::Arc;
 
 type Result<T, E = Error> = std::result::Result<T, E>;
 
-/// Attempts to format a string as pretty-printed JSON.
-/// Returns None if the string is not valid JSON or doesn't look like JSON.
-fn pretty_json(value: &str) -> Option<SharedString> {
-    let trimmed = value.trim();
-    if !((trimmed.starts_with('{') && trimmed.ends_with('}')) || (trimmed.starts_with('[') && trimmed.ends_with(']'))) {
-        return None;
+/// Decompresses gzip or zstd bytes, None for other formats or corrupted data.
+fn decompress(format: DataFormat, bytes: &[u8]) -> Option<Vec<u8>> {
+    match format {
+        DataFormat::Gzip => {
+            let mut decoder = GzDecoder::new(bytes);
+            let mut decompressed = Vec::new();
+            decoder.read_to_end(&mut decompressed).ok()?;
+            Some(decompressed)
+        }
+        DataFormat::Zstd => decompress_zstd(bytes).ok(),
+        _ => None,
     }
-    let json_value = serde_json::from_str::<Value>(value).ok()?;
-    let pretty_str = serde_json::to_string_pretty(&json_value).ok()?;
+}
 
-    Some(pretty_str.into())
+/// Fetch a string value from Redis unless it is larger than `max_size`.
+///
+/// The size is checked first, a huge value is returned as deferred
+/// (without data) so selecting it does not freeze the editor.
+/// `size` is the already known STRLEN of the value, queried here if None.
+pub(crate) async fn get_redis_value_within(
+    conn: &mut RedisAsyncConn,
+    key: &str,
+    max_size: Option<usize>,
+    size: Option<usize>,
+) -> Result<RedisValue> {
+    if let Some(max_size) = max_size {
+        let size: usize = match size {
+            Some(size) => size,
+            None => cmd("STRLEN").arg(key).query_async(conn).await?,
+        };
+        if size > max_size {
+            return Ok(RedisValue {
+                key_type: KeyType::String,
+                size,
+                deferred: true,
+                ..Default::default()
+            });
+        }
+    }
+    get_redis_value(conn, key).await
 }
 
 /// Fetch a string value from Redis.
@@ -61,45 +90,11 @@ pub(crate) async fn get_redis_value(conn: &mut RedisAsyncConn, key: &str) -> Res
             .ok()
             .and_then(|v| serde_json::to_string_pretty(&v).ok())
             .map(SharedString::from),
-        DataFormat::Gzip => {
-            let mut decoder = GzDecoder::new(bytes.as_ref());
-            let mut decompressed_vec = Vec::new();
-
-            if decoder.read_to_end(&mut decompressed_vec).is_ok() {
-                match String::from_utf8(decompressed_vec) {
-                    Ok(s) => {
-                        if let Some(pretty) = pretty_json(&s) {
-                            format = DataFormat::Json;
-                            Some(pretty)
-                        } else {
-                            format = DataFormat::Text;
-                            Some(s.into())
-                        }
-                    }
-                    Err(_) => None,
-                }
-            } else {
-                None
-            }
-        }
-        DataFormat::Zstd => {
-            if let Ok(decompressed_vec) = decompress_zstd(bytes.as_ref()) {
-                match String::from_utf8(decompressed_vec) {
-                    Ok(s) => {
-                        if let Some(pretty) = pretty_json(&s) {
-                            format = DataFormat::Json;
-                            Some(pretty)
-                        } else {
-                            format = DataFormat::Text;
-                            Some(s.into())
-                        }
-                    }
-                    Err(_) => None,
-                }
-            } else {
-                None
-            }
-        }
+        // Compressed values keep their format, so the editor stays read-only
+        // and the decompressed text is never written back by accident
+        DataFormat::Gzip | DataFormat::Zstd => decompress(format, &bytes)
+            .and_then(|decompressed| String::from_utf8(decompressed).ok())
+            .map(|s| pretty_json(&s).unwrap_or_else(|| s.into())),
         DataFormat::Svg | DataFormat::Jpeg | DataFormat::Png | DataFormat::Webp | DataFormat::Gif => None,
         _ => match std::str::from_utf8(&bytes) {
             Ok(s) => {
@@ -123,6 +118,7 @@ pub(crate) async fn get_redis_value(conn: &mut RedisAsyncConn, key: &str) -> Res
             bytes,
             text,
             view_mode: ViewMode::default(),
+            highlight_language: None,
         }))),
         size,
         ..Default::default()
@@ -14,10 +14,12 @@
 
 use super::{ServerEvent, ServerTask, ZedisServerState};
 use crate::connection::get_connection_manager;
+use crate::helpers::quote_redis_arg;
+use crate::states::update_app_state_and_save;
 use bytes::Bytes;
-use chrono::Local;
+use chrono::{DateTime, Local};
 use gpui::{Action, Hsla, SharedString, prelude::*};
-use redis::cmd;
+use redis::{Pipeline, cmd, pipe};
 use schemars::JsonSchema;
 use serde::Deserialize;
 use std::io::Cursor;
@@ -175,6 +177,40 @@ fn is_svg(bytes: &[u8]) -> bool {
     false
 }
 
+// Smallest possible PNG: signature (8) + IHDR chunk (25) + IEND chunk (12)
+const PNG_MIN_SIZE: usize = 45;
+// Smallest possible GIF: header (6) + logical screen descriptor (7) + trailer (1)
+const GIF_MIN_SIZE: usize = 14;
+// Smallest possible JPEG: SOI (2) + a marker segment (4) + EOI (2)
+const JPEG_MIN_SIZE: usize = 8;
+// RIFF header (12) + chunk header (8)
+const WEBP_MIN_SIZE: usize = 20;
+// Elements per generated command, keeps pasted commands shell-friendly
+const COMMAND_CHUNK_SIZE: usize = 100;
+
+/// Returns true if the bytes look like a complete image of the format.
+///
+/// `infer` only checks the magic bytes, so a truncated or tiny value would
+/// still be detected as an image and render as a broken element.
+/// This checks the minimum size and the trailer of each format.
+pub fn is_decodable_image(format: &DataFormat, bytes: &[u8]) -> bool {
+    match format {
+        DataFormat::Png => bytes.len() >= PNG_MIN_SIZE && bytes.ends_with(b"IEND\xAE\x42\x60\x82"),
+        DataFormat::Gif => bytes.len() >= GIF_MIN_SIZE && bytes.ends_with(&[0x3B]),
+        DataFormat::Jpeg => bytes.len() >= JPEG_MIN_SIZE && bytes.ends_with(&[0xFF, 0xD9]),
+        DataFormat::Webp => {
+            if bytes.len() < WEBP_MIN_SIZE {
+                return false;
+            }
+            // RIFF chunk size excludes the first 8 bytes
+            let riff_size = u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]) as usize;
+            riff_size + 8 <= bytes.len()
+        }
+        DataFormat::Svg => is_svg(bytes),
+        _ => false,
+    }
+}
+
 pub fn detect_format(bytes: &[u8]) -> (DataFormat, Option<SharedString>) {
     if bytes.is_empty() {
         return (DataFormat::Bytes, None);
@@ -209,6 +245,7 @@ pub enum RedisValueData {
     Set(Arc<RedisSetValue>),
     Zset(Arc<RedisZsetValue>),
     Hash(Arc<RedisHashValue>),
+    Stream(Arc<RedisStreamValue>),
 }
 
 /// Redis Set value structure with pagination support
@@ -221,6 +258,35 @@ pub struct RedisSetValue {
     pub done: bool,
 }
 
+/// Condition option of the EXPIRE command (requires Redis 7.0+)
+#[derive(Clone, Copy, PartialEq, Debug, Deserialize, JsonSchema, Action, Default)]
+pub enum ExpireCondition {
+    /// Always set the expiration
+    #[default]
+    Always,
+    /// Set only when the key has no expiration
+    Nx,
+    /// Set only when the key has an existing expiration
+    Xx,
+    /// Set only when the new expiration is greater than the current one
+    Gt,
+    /// Set only when the new expiration is less than the current one
+    Lt,
+}
+
+impl ExpireCondition {
+    /// Returns the EXPIRE option, None for `Always`
+    pub fn as_option(&self) -> Option<&'static str> {
+        match self {
+            ExpireCondition::Always => None,
+            ExpireCondition::Nx => Some("NX"),
+            ExpireCondition::Xx => Some("XX"),
+            ExpireCondition::Gt => Some("GT"),
+            ExpireCondition::Lt => Some("LT"),
+        }
+    }
+}
+
 /// Sort order for sorted sets
 #[derive(Clone, Copy, PartialEq, Default, Debug)]
 pub enum SortOrder {
@@ -250,6 +316,28 @@ pub struct RedisHashValue {
     pub values: Vec<(SharedString, SharedString)>,
 }
 
+/// Consumer group of a stream, from XINFO GROUPS
+#[derive(Debug, Clone, Default)]
+pub struct RedisStreamGroup {
+    pub name: SharedString,
+    pub consumers: usize,
+    pub pending: usize,
+    pub last_delivered_id: SharedString,
+}
+
+/// Redis Stream value structure, entries are loaded page by page by ID
+#[derive(Debug, Clone, Default)]
+pub struct RedisStreamValue {
+    pub keyword: Option<SharedString>,
+    pub size: usize,
+    /// Entry ID and its field-value pairs
+    pub values: Vec<(SharedString, Vec<(SharedString, SharedString)>)>,
+    /// ID of the last loaded entry, the next page starts after it
+    pub last_id: Option<SharedString>,
+    pub done: bool,
+    pub groups: Vec<RedisStreamGroup>,
+}
+
 /// Redis List value structure
 #[derive(Debug, Clone, Default)]
 pub struct RedisListValue {
@@ -263,6 +351,16 @@ pub enum ViewMode {
     Auto,
     Plain,
     Hex,
+    /// Decompressed text of gzip or zstd values
+    Decompressed,
+    /// MessagePack decoded as JSON, never detected automatically
+    MessagePack,
+    /// Base64 of the bytes, read-only
+    Base64,
+    /// Bytes decoded as UTF-16 little endian
+    Utf16Le,
+    /// Bytes decoded as UTF-16 big endian
+    Utf16Be,
 }
 
 impl ViewMode {
@@ -271,17 +369,38 @@ impl ViewMode {
             ViewMode::Auto => "Auto",
             ViewMode::Plain => "Plain",
             ViewMode::Hex => "Hex",
+            ViewMode::Decompressed => "Decompressed",
+            ViewMode::MessagePack => "MessagePack",
+            ViewMode::Base64 => "Base64",
+            ViewMode::Utf16Le => "UTF-16LE",
+            ViewMode::Utf16Be => "UTF-16BE",
         }
     }
     pub fn from_str(s: &str) -> Self {
         match s {
             "Plain" => ViewMode::Plain,
             "Hex" => ViewMode::Hex,
+            "Decompressed" => ViewMode::Decompressed,
+            "MessagePack" => ViewMode::MessagePack,
+            "Base64" => ViewMode::Base64,
+            "UTF-16LE" => ViewMode::Utf16Le,
+            "UTF-16BE" => ViewMode::Utf16Be,
             _ => ViewMode::Auto,
         }
     }
+    /// Modes that only decode some values, they are not remembered for the next key
+    /// as most values of the same type would fail to decode.
+    pub fn is_explicit_only(&self) -> bool {
+        matches!(
+            self,
+            ViewMode::Decompressed | ViewMode::MessagePack | ViewMode::Base64 | ViewMode::Utf16Le | ViewMode::Utf16Be
+        )
+    }
 }
 
+// Languages of the syntax highlighting, Auto detects it from the content
+pub const HIGHLIGHT_LANGUAGES: [&str; 5] = ["Auto", "JSON", "YAML", "HTML", "Text"];
+
 #[derive(Debug, Clone, Default)]
 pub struct RedisBytesValue {
     pub format: DataFormat,
@@ -289,6 +408,8 @@ pub struct RedisBytesValue {
     pub mime: Option<SharedString>,
     pub text: Option<SharedString>,
     pub view_mode: ViewMode,
+    /// Syntax highlighting language chosen by the user, detected from the content if None
+    pub highlight_language: Option<SharedString>,
 }
 
 impl RedisBytesValue {
@@ -298,9 +419,17 @@ impl RedisBytesValue {
             DataFormat::Jpeg | DataFormat::Png | DataFormat::Webp | DataFormat::Gif | DataFormat::Svg
         )
     }
+    /// Returns true if the value is an image that can be decoded (not tiny or truncated)
+    pub fn is_decodable_image(&self) -> bool {
+        self.is_image() && is_decodable_image(&self.format, &self.bytes)
+    }
     pub fn is_utf8_text(&self) -> bool {
         matches!(self.format, DataFormat::Text | DataFormat::Json)
     }
+    /// Returns true if the value is gzip or zstd compressed
+    pub fn is_compressed(&self) -> bool {
+        matches!(self.format, DataFormat::Gzip | DataFormat::Zstd)
+    }
 }
 
 impl RedisValue {
@@ -335,6 +464,14 @@ impl RedisValue {
         }
         None
     }
+
+    /// Returns the stream value if the data is a Stream type
+    pub fn stream_value(&self) -> Option<&Arc<RedisStreamValue>> {
+        if let Some(RedisValueData::Stream(data)) = self.data.as_ref() {
+            return Some(data);
+        }
+        None
+    }
 }
 
 /// Redis key types: string, list, set, zset, hash, stream, and vectorset
@@ -365,6 +502,28 @@ impl KeyType {
         }
     }
 
+    /// Returns the Redis type name, e.g. "stream"
+    pub fn name(&self) -> &'static str {
+        match self {
+            KeyType::String => "string",
+            KeyType::List => "list",
+            KeyType::Hash => "hash",
+            KeyType::Set => "set",
+            KeyType::Zset => "zset",
+            KeyType::Stream => "stream",
+            KeyType::Vectorset => "vectorset",
+            KeyType::Unknown => "unknown",
+        }
+    }
+
+    /// Checks if the editor can render values of this type
+    pub fn is_supported(&self) -> bool {
+        matches!(
+            self,
+            KeyType::String | KeyType::List | KeyType::Hash | KeyType::Set | KeyType::Zset | KeyType::Stream
+        )
+    }
+
     /// Returns the color associated with this key type for UI display
     pub fn color(&self) -> Hsla {
         match self {
@@ -397,6 +556,14 @@ pub struct RedisValue {
     pub(crate) data: Option<RedisValueData>,
     pub(crate) expire_at: Option<i64>,
     pub(crate) size: usize,
+    /// Bytes used by the key and its value (MEMORY USAGE, Redis 4.0+)
+    pub(crate) memory_usage: Option<u64>,
+    /// Internal encoding of the value, e.g. listpack or hashtable (OBJECT ENCODING)
+    pub(crate) encoding: Option<SharedString>,
+    /// Hash slot of the key and the `host:port` of its master, only in cluster mode
+    pub(crate) cluster_slot: Option<(u16, Option<SharedString>)>,
+    /// The string exceeds the load size limit, only its size (STRLEN) is known
+    pub(crate) deferred: bool,
 }
 
 impl RedisValue {
@@ -410,6 +577,11 @@ impl RedisValue {
         matches!(self.status, RedisValueStatus::Loading)
     }
 
+    /// Checks if loading the string value was skipped because of its size
+    pub fn is_deferred(&self) -> bool {
+        self.deferred
+    }
+
     /// Returns the string value if the data is a String type
     pub fn bytes_string_value(&self) -> Option<SharedString> {
         if let Some(value) = self.bytes_value()
@@ -429,10 +601,39 @@ impl RedisValue {
     }
 
     /// Returns the size of the value in bytes
+    ///
+    /// For collections only the loaded elements are counted.
     pub fn size(&self) -> usize {
         self.size
     }
 
+    /// Returns the internal encoding reported by the server, if available
+    pub fn encoding(&self) -> Option<SharedString> {
+        self.encoding.clone()
+    }
+
+    /// Returns the hash slot and the owning master of the key in cluster mode
+    pub fn cluster_slot(&self) -> Option<(u16, Option<SharedString>)> {
+        self.cluster_slot.clone()
+    }
+
+    /// Returns the memory usage reported by the server, if available
+    pub fn memory_usage(&self) -> Option<u64> {
+        self.memory_usage
+    }
+
+    /// Returns the total number of elements for list/set/zset/hash/stream values
+    pub fn element_count(&self) -> Option<usize> {
+        match self.data.as_ref()? {
+            RedisValueData::Bytes(_) => None,
+            RedisValueData::List(value) => Some(value.size),
+            RedisValueData::Set(value) => Some(value.size),
+            RedisValueData::Zset(value) => Some(value.size),
+            RedisValueData::Hash(value) => Some(value.size),
+            RedisValueData::Stream(value) => Some(value.size),
+        }
+    }
+
     /// Returns the time-to-live duration for this key
     ///
     /// Returns None if no expiration is set.
@@ -458,11 +659,87 @@ impl RedisValue {
         Some(chrono::Duration::seconds(remaining))
     }
 
+    /// Returns the absolute local time when the key expires.
+    ///
+    /// Returns None for persistent or missing keys.
+    pub fn expire_datetime(&self) -> Option<DateTime<Local>> {
+        let expire_at = self.expire_at.filter(|expire_at| *expire_at >= 0)?;
+        DateTime::from_timestamp(expire_at, 0).map(|datetime| datetime.with_timezone(&Local))
+    }
+
     /// Returns the key type
     pub fn key_type(&self) -> KeyType {
         self.key_type
     }
 
+    /// Builds redis-cli commands that recreate the loaded value of the key.
+    ///
+    /// Collections are split into one command per `COMMAND_CHUNK_SIZE` elements,
+    /// only the values loaded so far are included.
+    pub fn to_redis_commands(&self, key: &str) -> Option<String> {
+        let key = quote_redis_arg(key.as_bytes());
+        let quote = |value: &SharedString| quote_redis_arg(value.as_bytes());
+        let mut commands = match self.data.as_ref()? {
+            RedisValueData::Bytes(value) => vec![format!("SET {key} {}", quote_redis_arg(&value.bytes))],
+            RedisValueData::List(value) => value
+                .values
+                .chunks(COMMAND_CHUNK_SIZE)
+                .map(|chunk| format!("RPUSH {key} {}", chunk.iter().map(quote).collect::<Vec<_>>().join(" ")))
+                .collect(),
+            RedisValueData::Set(value) => value
+                .values
+                .chunks(COMMAND_CHUNK_SIZE)
+                .map(|chunk| format!("SADD {key} {}", chunk.iter().map(quote).collect::<Vec<_>>().join(" ")))
+                .collect(),
+            RedisValueData::Zset(value) => value
+                .values
+                .chunks(COMMAND_CHUNK_SIZE)
+                .map(|chunk| {
+                    let args = chunk
+                        .iter()
+                        .map(|(member, score)| format!("{score} {}", quote(member)))
+                        .collect::<Vec<_>>();
+                    format!("ZADD {key} {}", args.join(" "))
+                })
+                .collect(),
+            RedisValueData::Hash(value) => value
+                .values
+                .chunks(COMMAND_CHUNK_SIZE)
+                .map(|chunk| {
+                    let args = chunk
+                        .iter()
+                        .map(|(field, value)| format!("{} {}", quote(field), quote(value)))
+                        .collect::<Vec<_>>();
+                    format!("HSET {key} {}", args.join(" "))
+                })
+                .collect(),
+            // Entries keep their IDs, so XADD must run on an empty stream
+            RedisValueData::Stream(value) => value
+                .values
+                .iter()
+                .map(|(id, fields)| {
+                    let args = fields
+                        .iter()
+                        .map(|(field, value)| format!("{} {}", quote(field), quote(value)))
+                        .collect::<Vec<_>>();
+                    format!("XADD {key} {id} {}", args.join(" "))
+                })
+                .collect(),
+        };
+        if commands.is_empty() {
+            return None;
+        }
+        if let Some(ttl) = self.ttl().filter(|ttl| ttl.num_seconds() > 0) {
+            commands.push(format!("EXPIRE {key} {}", ttl.num_seconds()));
+        }
+        Some(commands.join("\n"))
+    }
+
+    /// Checks if the key exists but its type can not be rendered by the editor
+    pub fn is_unsupported(&self) -> bool {
+        !self.is_busy() && !self.is_expired() && !self.key_type.is_supported()
+    }
+
     /// Checks if the key is expired (TTL = -2)
     pub fn is_expired(&self) -> bool {
         self.expire_at.is_some_and(|expire_at| expire_at == -2)
@@ -485,6 +762,23 @@ impl From<&str> for KeyType {
     }
 }
 
+/// Builds the SET saving a string value without removing the expiration of the key.
+///
+/// Redis 6.0+ keeps it with KEEPTTL, older servers set the remaining `pttl`
+/// (milliseconds, read before saving) again in the same transaction.
+fn save_value_pipeline(key: &str, value: &str, supports_keepttl: bool, pttl: i64) -> Pipeline {
+    let mut pipeline = pipe();
+    if supports_keepttl {
+        pipeline.cmd("SET").arg(key).arg(value).arg("KEEPTTL").ignore();
+        return pipeline;
+    }
+    pipeline.atomic().cmd("SET").arg(key).arg(value).ignore();
+    if pttl > 0 {
+        pipeline.cmd("PEXPIRE").arg(key).arg(pttl).ignore();
+    }
+    pipeline
+}
+
 impl ZedisServerState {
     /// Saves a new value for a Redis string key
     ///
@@ -501,6 +795,7 @@ impl ZedisServerState {
             return;
         };
         let format = original_bytes_value.format;
+        let highlight_language = original_bytes_value.highlight_language.clone();
         let original_size = value.size;
 
         value.status = RedisValueStatus::Updating;
@@ -509,10 +804,10 @@ impl ZedisServerState {
             bytes: Bytes::from(new_value.clone().to_string().into_bytes()),
             text: Some(new_value.clone()),
             format,
+            highlight_language,
             ..Default::default()
         })));
         let current_key = key.clone();
-        let ttl = value.ttl().map(|ttl| ttl.num_milliseconds()).unwrap_or_default();
 
         cx.notify();
         self.spawn(
@@ -520,20 +815,22 @@ impl ZedisServerState {
             move || async move {
                 let client = get_connection_manager().get_client(&server_id).await?;
                 let mut conn = client.connection();
-                let mut binding = cmd("SET");
-                let mut cmd = binding.arg(key.as_str()).arg(new_value.as_str());
-                // keep ttl if the version is at least 6.0.0
-                cmd = if client.is_at_least_version("6.0.0") {
-                    cmd.arg("KEEPTTL")
-                } else if ttl > 0 {
-                    cmd.arg("PX").arg(ttl)
+                let supports_keepttl = client.supports_keepttl();
+                // Read the remaining TTL right before saving, the loaded one may be stale
+                let pttl: i64 = if supports_keepttl {
+                    0
                 } else {
-                    cmd
+                    cmd("PTTL").arg(key.as_str()).query_async(&mut conn).await?
                 };
-                let _: () = cmd.query_async(&mut conn).await?;
+                let _: () = save_value_pipeline(&key, &new_value, supports_keepttl, pttl)
+                    .query_async(&mut conn)
+                    .await?;
                 Ok(new_value)
             },
             move |this, result, cx| {
+                if result.is_ok() {
+                    this.add_audit_entry(&ServerTask::SaveValue, current_key.as_str(), cx);
+                }
                 if let Some(value) = this.value.as_mut() {
                     value.status = RedisValueStatus::Idle;
                     // Recover original value if save failed
@@ -553,7 +850,15 @@ impl ZedisServerState {
         let Some(value) = self.value.as_mut() else {
             return;
         };
+        let key_type = value.key_type();
         let view_mode = ViewMode::from_str(view_mode.as_str());
+        // Remember the view mode for the next key of the same type
+        if !view_mode.is_explicit_only() {
+            let saved_view_mode = view_mode.as_str();
+            update_app_state_and_save(cx, "save_view_mode", move |state, _cx| {
+                state.set_view_mode(key_type, saved_view_mode);
+            });
+        }
         let key = self.key.clone().unwrap_or_default();
         // Directly modify the data in place
         if let Some(RedisValueData::Bytes(bytes_value)) = &mut value.data {
@@ -563,4 +868,55 @@ impl ZedisServerState {
             cx.notify();
         }
     }
+    /// Overrides the syntax highlighting language of the string value, "Auto" detects it again.
+    pub fn update_bytes_value_language(&mut self, language: SharedString, cx: &mut Context<Self>) {
+        let Some(value) = self.value.as_mut() else {
+            return;
+        };
+        let key = self.key.clone().unwrap_or_default();
+        if let Some(RedisValueData::Bytes(bytes_value)) = &mut value.data {
+            let bytes_value = Arc::make_mut(bytes_value);
+            bytes_value.highlight_language = (language != HIGHLIGHT_LANGUAGES[0]).then_some(language);
+            cx.emit(ServerEvent::ValueLanguageUpdated(key));
+            cx.notify();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use redis::Arg;
+
+    fn pipeline_args(pipeline: &Pipeline) -> Vec<String> {
+        pipeline
+            .cmd_iter()
+            .map(|cmd| {
+                cmd.args_iter()
+                    .filter_map(|arg| match arg {
+                        Arg::Simple(arg) => Some(String::from_utf8_lossy(arg).to_string()),
+                        Arg::Cursor => None,
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_save_value_keeps_ttl() {
+        let pipeline = save_value_pipeline("user:1", "tree", true, 0);
+        assert_eq!(pipeline_args(&pipeline), vec!["SET user:1 tree KEEPTTL"]);
+
+        // A key with a 100s TTL on a server without KEEPTTL
+        let pipeline = save_value_pipeline("user:1", "tree", false, 100_000);
+        assert_eq!(
+            pipeline_args(&pipeline),
+            vec!["SET user:1 tree", "PEXPIRE user:1 100000"]
+        );
+
+        // Persistent keys stay persistent
+        let pipeline = save_value_pipeline("user:1", "tree", false, -1);
+        assert_eq!(pipeline_args(&pipeline), vec!["SET user:1 tree"]);
+    }
 }
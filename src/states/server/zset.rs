@@ -24,15 +24,17 @@
 
 use super::{
     KeyType, RedisValueData, ServerTask, ZedisServerState,
+    key::keyword_pattern,
     value::{RedisValue, RedisValueStatus, RedisZsetValue, SortOrder},
 };
 use crate::{
     connection::{RedisAsyncConn, get_connection_manager},
     error::Error,
-    states::{NotificationAction, ServerEvent, i18n_zset_editor},
+    states::{NotificationAction, ServerEvent, ZedisGlobalStore, i18n_zset_editor},
 };
 use gpui::{SharedString, prelude::*};
-use redis::cmd;
+use redis::{cmd, pipe};
+use rust_i18n::t;
 use std::sync::Arc;
 
 type Result<T, E = Error> = std::result::Result<T, E>;
@@ -193,9 +195,16 @@ impl ZedisServerState {
     /// # Arguments
     /// * `new_value` - The member name to add/update
     /// * `score` - The score to assign to the member
+    /// * `condition` - Optional ZADD condition flag: NX, XX, GT or LT
     /// * `cx` - GPUI context for spawning async tasks and UI updates
-    pub fn add_zset_value(&mut self, new_value: SharedString, score: f64, cx: &mut Context<Self>) {
-        self.add_or_update_zset_value(new_value, score, cx);
+    pub fn add_zset_value(
+        &mut self,
+        new_value: SharedString,
+        score: f64,
+        condition: Option<&'static str>,
+        cx: &mut Context<Self>,
+    ) {
+        self.add_or_update_zset_value(new_value, score, condition, cx);
     }
     /// Updates a member in the Redis ZSET with the specified score.
     ///
@@ -206,9 +215,15 @@ impl ZedisServerState {
     /// * `score` - The score to assign to the member
     /// * `cx` - GPUI context for spawning async tasks and UI updates
     pub fn update_zset_value(&mut self, new_value: SharedString, score: f64, cx: &mut Context<Self>) {
-        self.add_or_update_zset_value(new_value, score, cx);
+        self.add_or_update_zset_value(new_value, score, None, cx);
     }
-    fn add_or_update_zset_value(&mut self, new_value: SharedString, score: f64, cx: &mut Context<Self>) {
+    fn add_or_update_zset_value(
+        &mut self,
+        new_value: SharedString,
+        score: f64,
+        condition: Option<&'static str>,
+        cx: &mut Context<Self>,
+    ) {
         // Early return if no key/value is selected
         let Some((key, value)) = self.try_get_mut_key_value() else {
             return;
@@ -222,23 +237,41 @@ impl ZedisServerState {
         let key_clone = key.clone();
         let new_value_clone = new_value.clone();
 
+        let audit_target = key_clone.to_string();
         self.spawn(
             ServerTask::AddZsetValue,
             // Async operation: execute ZADD on Redis
             move || async move {
                 let mut conn = get_connection_manager().get_connection(&server_id).await?;
 
-                // ZADD returns number of new elements added (0 if updating existing)
-                let count: usize = cmd("ZADD")
+                // ZADD with CH returns the number of added or updated members,
+                // the flags (NX/XX/GT/LT) may skip the write entirely
+                let mut zadd = cmd("ZADD");
+                zadd.arg(key.as_str());
+                if let Some(condition) = condition {
+                    zadd.arg(condition);
+                }
+                zadd.arg("CH").arg(score).arg(new_value.as_str());
+
+                // Read back the cardinality and the stored score in the same transaction,
+                // so the local state matches what the flags actually allowed
+                let (changed, size, stored_score): (usize, usize, Option<f64>) = pipe()
+                    .atomic()
+                    .add_command(zadd)
+                    .cmd("ZCARD")
+                    .arg(key.as_str())
+                    .cmd("ZSCORE")
                     .arg(key.as_str())
-                    .arg(score)
                     .arg(new_value.as_str())
                     .query_async(&mut conn)
                     .await?;
-                Ok(count)
+                Ok((changed, size, stored_score))
             },
             // UI callback: handle result and update local state
             move |this, result, cx| {
+                if result.is_ok() {
+                    this.add_audit_entry(&ServerTask::AddZsetValue, audit_target, cx);
+                }
                 // Reset status to idle
                 if let Some(value) = this.value.as_mut() {
                     value.status = RedisValueStatus::Idle;
@@ -246,12 +279,23 @@ impl ZedisServerState {
                 let title = i18n_zset_editor(cx, "add_value_success");
                 let msg = i18n_zset_editor(cx, "add_value_success_tips");
                 let update_score_msg = i18n_zset_editor(cx, "update_value_score_success_tips");
+                let no_change_msg = i18n_zset_editor(cx, "no_change_tips");
+                let locale = cx.global::<ZedisGlobalStore>().read(cx).locale().to_string();
 
-                if let Ok(count) = result
+                if let Ok((changed, size, stored_score)) = result
                     && let Some(RedisValueData::Zset(zset_data)) = this.value.as_mut().and_then(|v| v.data.as_mut())
                 {
+                    // Nothing was written, e.g. GT with a lower score or XX for a new member
+                    if changed == 0 {
+                        cx.emit(ServerEvent::Notification(NotificationAction::new_warning(
+                            no_change_msg,
+                        )));
+                        cx.notify();
+                        return;
+                    }
                     let zset = Arc::make_mut(zset_data);
-                    zset.size += count;
+                    zset.size = size;
+                    let score = stored_score.unwrap_or(score);
 
                     let mut inserted = false;
                     let mut exists_value = false;
@@ -285,7 +329,14 @@ impl ZedisServerState {
 
                     cx.emit(ServerEvent::ValueAdded(key_clone));
 
-                    if exists_value {
+                    if let Some(condition) = condition {
+                        // Report the CH changed-count when a condition flag was used
+                        let msg = t!("zset_editor.changed_count_tips", count = changed, locale = locale).to_string();
+                        cx.emit(ServerEvent::Notification(
+                            NotificationAction::new_success(msg.into())
+                                .with_title(format!("ZADD {condition} CH").into()),
+                        ));
+                    } else if exists_value {
                         cx.emit(ServerEvent::Notification(NotificationAction::new_success(
                             update_score_msg,
                         )));
@@ -333,6 +384,37 @@ impl ZedisServerState {
         // Trigger load with the new filter
         self.load_more_zset_value(cx);
     }
+    /// Switches the ZSET between ascending (ZRANGE) and descending (ZREVRANGE) score order.
+    ///
+    /// The loaded members are dropped and the first page is reloaded in the new order,
+    /// the order is kept in the value so that pagination continues the same way.
+    ///
+    /// # Arguments
+    /// * `cx` - GPUI context for UI updates
+    pub fn toggle_zset_sort_order(&mut self, cx: &mut Context<Self>) {
+        let Some((_, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+        let Some(zset) = value.zset_value() else {
+            return;
+        };
+        let sort_order = if zset.sort_order == SortOrder::Asc {
+            SortOrder::Desc
+        } else {
+            SortOrder::Asc
+        };
+
+        // Keep the filter keyword, restart loading from the first rank
+        let new_zset = RedisZsetValue {
+            keyword: zset.keyword.clone(),
+            size: zset.size,
+            sort_order,
+            ..Default::default()
+        };
+        value.data = Some(RedisValueData::Zset(Arc::new(new_zset)));
+
+        self.load_more_zset_value(cx);
+    }
     /// Loads the next batch of ZSET members using appropriate pagination strategy.
     ///
     /// Uses two different strategies based on whether filtering is active:
@@ -384,7 +466,7 @@ impl ZedisServerState {
                     Ok((0, values)) // Cursor is irrelevant for range queries
                 } else {
                     // With filter: use scan-based pagination with pattern matching
-                    let pattern = format!("*{keyword}*");
+                    let pattern = keyword_pattern(&keyword, false);
                     let result = search_redis_zset_value(&mut conn, &key, cursor, &pattern, 1000).await?;
                     Ok(result)
                 }
@@ -457,6 +539,7 @@ impl ZedisServerState {
         let remove_value_clone = remove_value.clone();
         let key_clone = key.clone();
 
+        let audit_target = key_clone.to_string();
         self.spawn(
             ServerTask::RemoveZsetValue,
             // Async operation: execute ZREM on Redis
@@ -473,6 +556,9 @@ impl ZedisServerState {
             },
             // UI callback: update local state to reflect removal
             move |this, result, cx| {
+                if result.is_ok() {
+                    this.add_audit_entry(&ServerTask::RemoveZsetValue, audit_target, cx);
+                }
                 if let Ok(()) = result
                     && let Some(RedisValueData::Zset(zset_data)) = this.value.as_mut().and_then(|v| v.data.as_mut())
                 {
@@ -13,33 +13,47 @@
 // limitations under the License.
 
 mod about;
+mod audit_log;
 mod bytes_editor;
+mod config;
 mod content;
+mod dashboard;
 mod editor;
 mod hash_editor;
 mod key_tree;
 mod kv_table;
 mod list_editor;
+mod pubsub;
+mod quick_open;
 mod servers;
 mod set_editor;
 mod setting_editor;
 mod sidebar;
 mod status_bar;
+mod stream_editor;
 mod title_bar;
+mod value_diff;
 mod zset_editor;
 
 pub use about::open_about_window;
+pub use audit_log::ZedisAuditLog;
 pub use bytes_editor::ZedisBytesEditor;
+pub use config::ZedisConfigViewer;
 pub use content::ZedisContent;
+pub use dashboard::ZedisDashboard;
 pub use editor::ZedisEditor;
 pub use hash_editor::ZedisHashEditor;
 pub use key_tree::ZedisKeyTree;
 pub use kv_table::{KvTableColumn, KvTableColumnType, ZedisKvTable};
 pub use list_editor::ZedisListEditor;
+pub use pubsub::ZedisPubSubViewer;
+pub use quick_open::ZedisQuickOpen;
 pub use servers::ZedisServers;
 pub use set_editor::ZedisSetEditor;
 pub use setting_editor::ZedisSettingEditor;
 pub use sidebar::ZedisSidebar;
 pub use status_bar::ZedisStatusBar;
+pub use stream_editor::ZedisStreamEditor;
 pub use title_bar::ZedisTitleBar;
+pub use value_diff::ZedisValueDiff;
 pub use zset_editor::ZedisZsetEditor;
@@ -0,0 +1,131 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    helpers::get_font_family,
+    states::{ZedisServerState, i18n_status_bar},
+};
+use chrono::{DateTime, Local};
+use gpui::{Entity, Subscription, Window, div, prelude::*, px};
+use gpui_component::{
+    ActiveTheme, Disableable, Sizable,
+    button::{Button, ButtonVariants},
+    h_flex,
+    label::Label,
+    v_flex,
+};
+use tracing::info;
+
+const LIST_MAX_HEIGHT: f32 = 420.0;
+const TIME_WIDTH: f32 = 150.0;
+const SERVER_WIDTH: f32 = 120.0;
+const ACTION_WIDTH: f32 = 170.0;
+
+/// Audit log viewer
+///
+/// Lists the write operations performed from the app, newest first.
+/// The entries are kept by the server state and saved to disk.
+pub struct ZedisAuditLog {
+    /// Reference to server state holding the audit log
+    server_state: Entity<ZedisServerState>,
+
+    /// Event subscriptions for reactive updates
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ZedisAuditLog {
+    pub fn new(server_state: Entity<ZedisServerState>, _window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let subscriptions = vec![cx.observe(&server_state, |_this, _server_state, cx| cx.notify())];
+
+        info!("Creating new audit log view");
+
+        Self {
+            server_state,
+            _subscriptions: subscriptions,
+        }
+    }
+}
+
+impl Render for ZedisAuditLog {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let entries = self.server_state.read(cx).audit_log();
+        let is_empty = entries.is_empty();
+        let rows = entries.into_iter().enumerate().map(|(index, entry)| {
+            let time = DateTime::from_timestamp(entry.created_at, 0)
+                .map(|datetime| datetime.with_timezone(&Local).format("%Y-%m-%d %H:%M:%S").to_string())
+                .unwrap_or_default();
+            h_flex()
+                .id(("audit-log-item", index))
+                .w_full()
+                .px_2()
+                .py_1()
+                .gap_3()
+                .child(
+                    div()
+                        .w(px(TIME_WIDTH))
+                        .flex_none()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(time),
+                )
+                .child(
+                    div()
+                        .w(px(SERVER_WIDTH))
+                        .flex_none()
+                        .text_ellipsis()
+                        .child(entry.server),
+                )
+                .child(
+                    div()
+                        .w(px(ACTION_WIDTH))
+                        .flex_none()
+                        .text_color(cx.theme().primary)
+                        .child(entry.action),
+                )
+                .child(div().flex_1().text_ellipsis().child(entry.target))
+        });
+        v_flex()
+            .w_full()
+            .gap_2()
+            .child(
+                h_flex().justify_end().child(
+                    Button::new("audit-log-clear-btn")
+                        .outline()
+                        .small()
+                        .disabled(is_empty)
+                        .label(i18n_status_bar(cx, "audit_log_clear"))
+                        .on_click(cx.listener(|this, _, _, cx| {
+                            this.server_state.update(cx, |state, cx| {
+                                state.clear_audit_log(cx);
+                            });
+                        })),
+                ),
+            )
+            .when(is_empty, |this| {
+                this.child(
+                    Label::new(i18n_status_bar(cx, "audit_log_empty"))
+                        .text_sm()
+                        .text_color(cx.theme().muted_foreground),
+                )
+            })
+            .child(
+                div()
+                    .id("audit-log-list")
+                    .max_h(px(LIST_MAX_HEIGHT))
+                    .overflow_y_scroll()
+                    .font_family(get_font_family())
+                    .text_sm()
+                    .children(rows),
+            )
+    }
+}
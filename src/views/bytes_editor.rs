@@ -12,37 +12,46 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::helpers::get_font_family;
-use crate::states::{DataFormat, RedisBytesValue, ServerEvent, ViewMode, ZedisGlobalStore, ZedisServerState};
+use crate::helpers::{format_number_with_separators, get_editor_font_family, get_font_family};
+use crate::states::{
+    DataFormat, HIGHLIGHT_LANGUAGES, RedisBytesValue, RowDensity, ServerEvent, ViewMode, ZedisGlobalStore,
+    ZedisServerState, i18n_editor, update_app_state_and_save,
+};
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
 use gpui::{App, Entity, Image, ObjectFit, SharedString, Subscription, Window, img, px};
 use gpui::{div, hsla, prelude::*};
 use gpui_component::highlighter::Language;
 use gpui_component::input::{Input, InputEvent, InputState, TabSize};
 use gpui_component::label::Label;
 use gpui_component::list::{List, ListDelegate, ListItem, ListState};
-use gpui_component::{ActiveTheme, IndexPath, h_flex};
+use gpui_component::{ActiveTheme, IndexPath, h_flex, switch::Switch, v_flex};
 use pretty_hex::HexConfig;
 use pretty_hex::config_hex;
+use rust_i18n::t;
 use std::sync::Arc;
 use tracing::info;
 
 // Constants for editor configuration
 const DEFAULT_TAB_SIZE: usize = 2;
-const DEFAULT_LANGUAGE: &str = "json";
+const DEFAULT_LANGUAGE: &str = "JSON";
 const HEX_WIDTH_NARROW: usize = 16; // Bytes per line for narrow viewports
 const HEX_WIDTH_MEDIUM: usize = 24; // Bytes per line for medium viewports
 const HEX_WIDTH_WIDE: usize = 32; // Bytes per line for wide viewports
 const VIEWPORT_WIDE: f32 = 1400.0; // Pixel width to switch hex display width
 const VIEWPORT_MEDIUM: f32 = 1000.0; // Pixel width to switch hex display width
+const MAX_NUMBER_ANNOTATION_LENGTH: usize = 64; // Longer values are not checked for numbers
+const LONG_LINE_LENGTH: usize = 200; // Single line values longer than this get their own wrap toggle
 
 /// String value editor component for Redis String data type
 ///
 /// Features:
-/// - Code editor with syntax highlighting (JSON by default)
+/// - Code editor with syntax highlighting detected from the content (JSON, YAML, HTML)
 /// - Line numbers and indent guides
 /// - Search functionality
-/// - Soft wrap support
+/// - Soft wrap support, with a separate toggle for single long lines
 /// - Automatic hex display for binary data
+/// - Read-only base64 and UTF-16LE/BE decoded views
+/// - Optional thousands separators annotation for plain numbers
 /// - Tracks modification state
 pub struct ZedisBytesEditor {
     /// Reference to server state for Redis operations
@@ -57,21 +66,33 @@ pub struct ZedisBytesEditor {
     /// Code editor state with input handling
     editor: Entity<InputState>,
 
-    /// Whether to soft wrap the editor
+    /// Whether to soft wrap the editor, from the server settings
     soft_wrap: bool,
 
+    /// Soft wrap currently applied to the editor
+    applied_soft_wrap: bool,
+
+    /// Bytes per line of the current hex view
+    hex_width: usize,
+
     /// Whether the editor is readonly
     readonly: bool,
 
     /// Whether to update the editor
     should_update_editor: bool,
 
-    /// Whether the soft wrap has been changed
-    soft_wrap_changed: bool,
+    /// Syntax highlighting language of the editor, one of `HIGHLIGHT_LANGUAGES`
+    language: SharedString,
+
+    /// Whether the highlighting language has been changed
+    language_changed: bool,
 
     /// The data to display in the editor
     data: ByteEditorData,
 
+    /// Error of decoding the value in the selected view mode, e.g. MessagePack
+    decode_error: Option<SharedString>,
+
     /// Event subscriptions for reactive updates
     _subscriptions: Vec<Subscription>,
 }
@@ -90,36 +111,85 @@ impl ByteEditorData {
         }
     }
 }
+/// Detect the syntax highlighting language from the start of the text
+fn detect_language(text: &str) -> &'static str {
+    let text = text.trim_start();
+    if text.starts_with('<') {
+        "HTML"
+    } else if text.starts_with('{') || text.starts_with('[') {
+        "JSON"
+    } else if text.starts_with("---") {
+        "YAML"
+    } else {
+        "Text"
+    }
+}
+
+/// Decode MessagePack bytes as pretty printed JSON
+fn decode_messagepack(bytes: &[u8]) -> Result<SharedString, String> {
+    let value: serde_json::Value = rmp_serde::from_slice(bytes).map_err(|e| e.to_string())?;
+    let text = serde_json::to_string_pretty(&value).map_err(|e| e.to_string())?;
+    Ok(text.into())
+}
+
+/// Decode UTF-16 bytes, a leading byte order mark is skipped
+fn decode_utf16(bytes: &[u8], little_endian: bool) -> Result<SharedString, String> {
+    if bytes.len() % 2 != 0 {
+        return Err(format!("UTF-16 needs an even number of bytes, got {}", bytes.len()));
+    }
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|unit| {
+            let unit = [unit[0], unit[1]];
+            if little_endian {
+                u16::from_le_bytes(unit)
+            } else {
+                u16::from_be_bytes(unit)
+            }
+        })
+        .collect();
+    let units = units.strip_prefix(&[0xFEFF]).unwrap_or(&units);
+    let text = String::from_utf16(units).map_err(|e| e.to_string())?;
+    Ok(text.into())
+}
+
+/// Bytes per line of the hex view, from the settings or fitted to the viewport
+fn get_hex_width(cx: &App) -> usize {
+    let store = cx.global::<ZedisGlobalStore>().read(cx);
+    if let Some(hex_width) = store.hex_bytes_per_line() {
+        return hex_width;
+    }
+    match store.content_width().unwrap_or_default() {
+        w if w < px(VIEWPORT_MEDIUM) => HEX_WIDTH_NARROW,
+        w if w < px(VIEWPORT_WIDE) => HEX_WIDTH_MEDIUM,
+        _ => HEX_WIDTH_WIDE,
+    }
+}
+
 /// Extract string value from Redis value, with hex fallback for binary data
 ///
 /// If the value is a string, returns Text(SharedString).
-/// If the value is binary data, formats it as a hex dump with appropriate width
-/// based on viewport size and returns Hex(SharedString).
+/// If the value is binary data, formats it as a hex dump with `hex_width`
+/// bytes per line and returns Hex(SharedString).
 ///
 /// # Arguments
 /// * `value` - Optional Redis value to extract string from
-/// * `cx` - App context for viewport size calculation
+/// * `hex_width` - Bytes per line of the hex dump
+/// * `soft_wrap` - Whether the hex rows wrap when wider than the viewport
 ///
 /// # Returns
-/// String representation (either original string or hex dump)
-fn format_byte_editor_data(value: &Arc<RedisBytesValue>, cx: &App) -> ByteEditorData {
+/// String representation (either original string or hex dump),
+/// and the decode error if the value can not be shown in the selected view mode
+fn format_byte_editor_data(
+    value: &Arc<RedisBytesValue>,
+    hex_width: usize,
+    soft_wrap: bool,
+) -> (ByteEditorData, Option<SharedString>) {
     if value.bytes.is_empty() {
-        return ByteEditorData::Text(SharedString::default());
+        return (ByteEditorData::Text(SharedString::default()), None);
     }
 
     let create_hex_view = || {
-        let width = cx
-            .global::<ZedisGlobalStore>()
-            .read(cx)
-            .content_width()
-            .unwrap_or_default();
-
-        let hex_width = match width {
-            w if w < px(VIEWPORT_MEDIUM) => HEX_WIDTH_NARROW,
-            w if w < px(VIEWPORT_WIDE) => HEX_WIDTH_MEDIUM,
-            _ => HEX_WIDTH_WIDE,
-        };
-
         let cfg = HexConfig {
             title: false,
             width: hex_width,
@@ -128,19 +198,42 @@ fn format_byte_editor_data(value: &Arc<RedisBytesValue>, cx: &App) -> ByteEditor
         };
 
         let hex_data = config_hex(&value.bytes, cfg);
-        ByteEditorData::Hex(HexViewerListDelegate::new(&hex_data))
+        ByteEditorData::Hex(HexViewerListDelegate::new(&hex_data, soft_wrap))
     };
 
-    match value.view_mode {
+    let data = match value.view_mode {
         ViewMode::Hex => create_hex_view(),
 
+        // Explicit only, MessagePack can not be detected reliably
+        ViewMode::MessagePack => match decode_messagepack(&value.bytes) {
+            Ok(text) => ByteEditorData::Text(text),
+            Err(err) => return (create_hex_view(), Some(err.into())),
+        },
+
+        ViewMode::Base64 => ByteEditorData::Text(BASE64.encode(&value.bytes).into()),
+
+        ViewMode::Utf16Le | ViewMode::Utf16Be => {
+            match decode_utf16(&value.bytes, value.view_mode == ViewMode::Utf16Le) {
+                Ok(text) => ByteEditorData::Text(text),
+                Err(err) => return (create_hex_view(), Some(err.into())),
+            }
+        }
+
         ViewMode::Plain => {
             let text = String::from_utf8_lossy(&value.bytes).to_string().into();
             ByteEditorData::Text(text)
         }
 
+        // Decompressed UTF-8 text, binary data after decompression stays hex.
+        // Other values are shown like Auto, as the mode is remembered per key type
+        ViewMode::Decompressed if value.is_compressed() => match &value.text {
+            Some(text) => ByteEditorData::Text(text.clone()),
+            None => create_hex_view(),
+        },
+
         _ => {
-            if value.is_image() {
+            // Tiny or truncated images fall back to the hex view instead of a broken image
+            if value.is_decodable_image() {
                 let format = match value.format {
                     DataFormat::Png => gpui::ImageFormat::Png,
                     DataFormat::Webp => gpui::ImageFormat::Webp,
@@ -149,25 +242,26 @@ fn format_byte_editor_data(value: &Arc<RedisBytesValue>, cx: &App) -> ByteEditor
                     _ => gpui::ImageFormat::Jpeg,
                 };
                 let data = Image::from_bytes(format, value.bytes.to_vec());
-                return ByteEditorData::Image(Arc::new(data));
-            }
-
-            if let Some(text) = &value.text {
-                return ByteEditorData::Text(text.clone());
+                ByteEditorData::Image(Arc::new(data))
+            } else if let Some(text) = &value.text {
+                ByteEditorData::Text(text.clone())
+            } else {
+                create_hex_view()
             }
-
-            create_hex_view()
         }
-    }
+    };
+    (data, None)
 }
 #[derive(Clone)]
 struct HexViewerListDelegate {
     items: Vec<(SharedString, SharedString, SharedString)>,
     selected_index: Option<IndexPath>,
+    /// Wrap the ascii column below the hex data when the row is too wide
+    soft_wrap: bool,
 }
 
 impl HexViewerListDelegate {
-    fn new(data: &str) -> Self {
+    fn new(data: &str, soft_wrap: bool) -> Self {
         let items = data
             .split("\n")
             .flat_map(|item| {
@@ -183,6 +277,7 @@ impl HexViewerListDelegate {
         Self {
             items,
             selected_index: None,
+            soft_wrap,
         }
     }
 }
@@ -205,17 +300,32 @@ impl ListDelegate for HexViewerListDelegate {
         } else {
             hsla(0.0892, 0.9462, 0.4373, 1.0)
         };
+        let density = cx.global::<ZedisGlobalStore>().read(cx).row_density();
+        let soft_wrap = self.soft_wrap;
         self.items.get(ix.row).map(|(address, hex_data, ascii_data)| {
-            ListItem::new(ix).py_0().px_2().child(
-                h_flex()
-                    .child(Label::new(address.clone()).text_color(address_color).mr_4())
-                    .child(
-                        Label::new(hex_data.clone())
-                            .text_color(cx.theme().muted_foreground)
-                            .mr_6(),
-                    )
-                    .child(Label::new(ascii_data.clone())),
-            )
+            ListItem::new(ix)
+                .map(|this| match density {
+                    RowDensity::Compact => this.py_0(),
+                    RowDensity::Comfortable => this.py_0p5(),
+                })
+                .px_2()
+                .child(
+                    h_flex()
+                        .map(|this| {
+                            if soft_wrap {
+                                this.flex_wrap()
+                            } else {
+                                this.whitespace_nowrap()
+                            }
+                        })
+                        .child(Label::new(address.clone()).text_color(address_color).mr_4())
+                        .child(
+                            Label::new(hex_data.clone())
+                                .text_color(cx.theme().muted_foreground)
+                                .mr_6(),
+                        )
+                        .child(Label::new(ascii_data.clone())),
+                )
         })
     }
 
@@ -228,7 +338,7 @@ impl ZedisBytesEditor {
     /// Create a new string editor with code editing capabilities
     ///
     /// Initializes a code editor with:
-    /// - JSON syntax highlighting by default
+    /// - JSON syntax highlighting until the language is detected
     /// - Line numbers and indent guides
     /// - Search functionality
     /// - Soft wrap for long lines
@@ -246,9 +356,18 @@ impl ZedisBytesEditor {
                 ServerEvent::ValueUpdated(_) => {
                     this.update_editor_data(cx);
                 }
+                ServerEvent::ValueLanguageUpdated(_) => {
+                    this.update_language(cx);
+                    cx.notify();
+                }
                 ServerEvent::SoftWrapToggled(soft_wrap) => {
-                    this.soft_wrap_changed = true;
                     this.soft_wrap = *soft_wrap;
+                    // The hex rows are rendered again with the new wrapping
+                    if let ByteEditorData::Hex(delegate) = &mut this.data {
+                        delegate.soft_wrap = *soft_wrap;
+                        this.hex_viewer_state = None;
+                    }
+                    cx.notify();
                 }
                 _ => {}
             }),
@@ -257,7 +376,7 @@ impl ZedisBytesEditor {
         let soft_wrap = server_state.read(cx).soft_wrap();
 
         // Configure code editor with JSON syntax highlighting
-        let default_language = Language::from_str(DEFAULT_LANGUAGE);
+        let default_language = Language::from_str(&DEFAULT_LANGUAGE.to_lowercase());
         let editor = cx.new(|cx| {
             InputState::new(window, cx)
                 .code_editor(default_language.name())
@@ -284,6 +403,10 @@ impl ZedisBytesEditor {
                 let original = this.data.to_string().unwrap_or_default();
 
                 this.value_modified = original != value.as_str();
+                let value_modified = this.value_modified;
+                this.server_state.update(cx, |state, _cx| {
+                    state.set_unsaved_value_edits(value_modified);
+                });
                 cx.notify();
             }
         }));
@@ -293,8 +416,12 @@ impl ZedisBytesEditor {
         let mut this = Self {
             value_modified: false,
             soft_wrap,
-            soft_wrap_changed: false,
+            applied_soft_wrap: soft_wrap,
+            hex_width: 0,
+            language: DEFAULT_LANGUAGE.into(),
+            language_changed: false,
             data: ByteEditorData::Text(SharedString::default()),
+            decode_error: None,
             hex_viewer_state: None,
             editor,
             should_update_editor: true,
@@ -329,14 +456,39 @@ impl ZedisBytesEditor {
 
         let redis_bytes_value = server_state.read(cx).value().and_then(|v| v.bytes_value());
         if let Some(redis_bytes_value) = &redis_bytes_value {
-            self.readonly = !redis_bytes_value.is_utf8_text();
-            self.data = format_byte_editor_data(redis_bytes_value, cx);
+            // Decoded or encoded views are not saved back as the original bytes
+            self.readonly = !redis_bytes_value.is_utf8_text()
+                || matches!(
+                    redis_bytes_value.view_mode,
+                    ViewMode::MessagePack | ViewMode::Base64 | ViewMode::Utf16Le | ViewMode::Utf16Be
+                );
+            self.hex_width = get_hex_width(cx);
+            (self.data, self.decode_error) = format_byte_editor_data(redis_bytes_value, self.hex_width, self.soft_wrap);
         } else {
             self.data = ByteEditorData::Text(SharedString::default());
+            self.decode_error = None;
         }
 
-        if !matches!(self.data, ByteEditorData::Hex(_)) {
-            self.hex_viewer_state = None;
+        // The list keeps its delegate, so it is created again for the new data
+        self.hex_viewer_state = None;
+        self.update_language(cx);
+    }
+
+    /// Use the language chosen by the user, or detect it from the text
+    fn update_language(&mut self, cx: &mut Context<Self>) {
+        let ByteEditorData::Text(text) = &self.data else {
+            return;
+        };
+        let language = self
+            .server_state
+            .read(cx)
+            .value()
+            .and_then(|value| value.bytes_value())
+            .and_then(|value| value.highlight_language.clone())
+            .unwrap_or_else(|| detect_language(text).into());
+        if language != self.language {
+            self.language = language;
+            self.language_changed = true;
         }
     }
 
@@ -350,10 +502,86 @@ impl ZedisBytesEditor {
         self.readonly
     }
 
+    /// Get the value as loaded from Redis, empty if it is not shown as text
+    pub fn original_value(&self) -> SharedString {
+        self.data.to_string().unwrap_or_default()
+    }
+
     /// Get the current editor value
     pub fn value(&self, cx: &mut Context<Self>) -> SharedString {
         self.editor.read(cx).value()
     }
+
+    /// Check if the value is shown as hex or image instead of text
+    pub fn is_binary(&self) -> bool {
+        matches!(self.data, ByteEditorData::Hex(_) | ByteEditorData::Image(_))
+    }
+
+    /// Check if the value is a single line too long to read without wrapping
+    fn is_long_line(&self) -> bool {
+        match &self.data {
+            ByteEditorData::Text(text) => text.len() > LONG_LINE_LENGTH && !text.contains('\n'),
+            _ => false,
+        }
+    }
+
+    /// Render the wrap toggle of single long lines, kept in the app settings
+    fn render_long_line_toggle(&self, cx: &mut Context<Self>) -> Option<impl IntoElement> {
+        if !self.is_long_line() {
+            return None;
+        }
+        let wrap_long_line = cx
+            .global::<ZedisGlobalStore>()
+            .read(cx)
+            .wrap_long_line()
+            .unwrap_or(self.soft_wrap);
+        Some(
+            h_flex()
+                .px_2()
+                .py_1()
+                .justify_end()
+                .border_b_1()
+                .border_color(cx.theme().border)
+                .child(
+                    Switch::new("bytes-editor-wrap-long-line")
+                        .checked(wrap_long_line)
+                        .label(i18n_editor(cx, "wrap_long_line"))
+                        .on_click(|checked, _window, cx| {
+                            let checked = *checked;
+                            update_app_state_and_save(cx, "save_wrap_long_line", move |state, _cx| {
+                                state.set_wrap_long_line(checked);
+                            });
+                        }),
+                ),
+        )
+    }
+
+    /// Render the value with thousands separators if it is a plain number
+    ///
+    /// Only shown when enabled in settings, the stored value is untouched.
+    fn render_number_annotation(&self, cx: &mut Context<Self>) -> Option<impl IntoElement> {
+        if !cx.global::<ZedisGlobalStore>().read(cx).number_separators() {
+            return None;
+        }
+        let value = self.editor.read(cx).value();
+        if value.len() > MAX_NUMBER_ANNOTATION_LENGTH {
+            return None;
+        }
+        let formatted = format_number_with_separators(&value)?;
+        Some(
+            h_flex()
+                .px_2()
+                .py_1()
+                .border_t_1()
+                .border_color(cx.theme().border)
+                .child(
+                    Label::new(formatted)
+                        .text_sm()
+                        .font_family(get_font_family())
+                        .text_color(cx.theme().muted_foreground),
+                ),
+        )
+    }
 }
 
 impl Render for ZedisBytesEditor {
@@ -362,13 +590,35 @@ impl Render for ZedisBytesEditor {
     /// Renders a full-width, full-height code editor with:
     /// - No borders for seamless integration
     /// - Monospace font for code readability
-    /// - Customizable font size
+    /// - Font size from the app settings
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
-        if self.soft_wrap_changed {
+        let font_size = px(cx.global::<ZedisGlobalStore>().read(cx).font_size().editor_pixels());
+        let font_family = get_editor_font_family(cx);
+        // Single long lines use their own wrap toggle once set, the server setting otherwise
+        let soft_wrap = if self.is_long_line() {
+            cx.global::<ZedisGlobalStore>()
+                .read(cx)
+                .wrap_long_line()
+                .unwrap_or(self.soft_wrap)
+        } else {
+            self.soft_wrap
+        };
+        if soft_wrap != self.applied_soft_wrap {
             self.editor.update(cx, |this, cx| {
-                this.set_soft_wrap(self.soft_wrap, window, cx);
+                this.set_soft_wrap(soft_wrap, window, cx);
             });
-            self.soft_wrap_changed = false;
+            self.applied_soft_wrap = soft_wrap;
+        }
+        // The bytes per line follow the viewport width and the settings
+        if matches!(self.data, ByteEditorData::Hex(_)) && get_hex_width(cx) != self.hex_width {
+            self.update_editor_data(cx);
+        }
+        if self.language_changed {
+            let language = Language::from_str(&self.language.to_lowercase());
+            self.editor.update(cx, |this, cx| {
+                this.set_highlighter(language.name(), cx);
+            });
+            self.language_changed = false;
         }
         match &self.data {
             ByteEditorData::Image(value) => div()
@@ -384,28 +634,77 @@ impl Render for ZedisBytesEditor {
                     .hex_viewer_state
                     .get_or_insert_with(|| cx.new(|cx| ListState::new(value.clone(), window, cx)))
                     .clone();
-                List::new(&state).font_family(get_font_family()).into_any_element()
+                let decode_error = self.decode_error.clone().map(|error| {
+                    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+                    Label::new(t!("editor.decode_failed", error = error, locale = locale).to_string())
+                        .px_2()
+                        .py_1()
+                        .text_xs()
+                        .text_color(cx.theme().red)
+                });
+                v_flex()
+                    .size_full()
+                    .children(decode_error)
+                    .child(List::new(&state).flex_1().font_family(font_family).text_size(font_size))
+                    .into_any_element()
             }
             _ => {
                 if self.should_update_editor {
                     self.should_update_editor = false;
                     let value = self.data.to_string().unwrap_or_default();
-                    self.editor.update(cx, move |this, cx| {
-                        this.set_value(value, window, cx);
-                    });
+                    // Setting the same text again would scroll back to the top, e.g. on reload
+                    if self.editor.read(cx).value() != value {
+                        self.editor.update(cx, move |this, cx| {
+                            this.set_value(value, window, cx);
+                        });
+                    }
                 }
-                Input::new(&self.editor)
-                    .flex_1()
-                    .bordered(false)
-                    .disabled(self.readonly)
-                    .appearance(false)
-                    .p_0()
-                    .w_full()
-                    .h_full()
-                    .font_family(get_font_family())
-                    .focus_bordered(false)
+                let annotation = self.render_number_annotation(cx);
+                v_flex()
+                    .size_full()
+                    .children(self.render_long_line_toggle(cx))
+                    .child(
+                        Input::new(&self.editor)
+                            .flex_1()
+                            .bordered(false)
+                            .disabled(self.readonly)
+                            .appearance(false)
+                            .p_0()
+                            .w_full()
+                            .h_full()
+                            .font_family(font_family)
+                            .text_size(font_size)
+                            .focus_bordered(false),
+                    )
+                    .children(annotation)
                     .into_any_element()
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncated_png_falls_back_to_hex() {
+        let value = Arc::new(RedisBytesValue {
+            format: DataFormat::Png,
+            bytes: b"\x89P".to_vec().into(),
+            mime: Some("image/png".into()),
+            ..Default::default()
+        });
+        let (data, err) = format_byte_editor_data(&value, HEX_WIDTH_NARROW, false);
+        assert!(matches!(data, ByteEditorData::Hex(_)));
+        assert!(err.is_none());
+
+        // The signature alone is not a decodable image either
+        let value = Arc::new(RedisBytesValue {
+            format: DataFormat::Png,
+            bytes: b"\x89PNG\r\n\x1a\n".to_vec().into(),
+            ..Default::default()
+        });
+        assert!(!value.is_decodable_image());
+    }
+}
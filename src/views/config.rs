@@ -0,0 +1,227 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    assets::CustomIconName,
+    helpers::get_font_family,
+    states::{ZedisGlobalStore, ZedisServerState, i18n_common, i18n_config},
+};
+use gpui::{Entity, SharedString, Subscription, Window, div, prelude::*, px, uniform_list};
+use gpui_component::{
+    ActiveTheme, Disableable, Icon, IconName, WindowExt,
+    button::{Button, ButtonVariants},
+    h_flex,
+    input::{Input, InputEvent, InputState},
+    label::Label,
+    v_flex,
+};
+use rust_i18n::t;
+use tracing::info;
+
+// Width of the parameter name column
+const CONFIG_NAME_WIDTH: f32 = 280.0;
+
+/// Server config viewer component
+///
+/// Features:
+/// - CONFIG GET with a glob pattern (e.g. `maxmemory*`, `save`, `appendonly`)
+/// - Edit a parameter with CONFIG SET after confirmation
+/// - Shows a notice instead of the table when CONFIG is disabled on the server
+pub struct ZedisConfigViewer {
+    /// Reference to server state for Redis operations
+    server_state: Entity<ZedisServerState>,
+
+    /// Input field state for the CONFIG GET pattern
+    pattern_state: Entity<InputState>,
+
+    /// Input field state for editing a parameter value
+    value_state: Entity<InputState>,
+
+    /// Event subscriptions for reactive updates
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ZedisConfigViewer {
+    pub fn new(server_state: Entity<ZedisServerState>, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let mut subscriptions = Vec::new();
+        subscriptions.push(cx.observe(&server_state, |_this, _model, cx| {
+            cx.notify();
+        }));
+
+        let pattern = server_state.read(cx).config().pattern().clone();
+        let pattern_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .clean_on_escape()
+                .placeholder(i18n_config(cx, "pattern_placeholder"))
+                .default_value(pattern)
+        });
+        subscriptions.push(cx.subscribe_in(&pattern_state, window, |view, _, event, _window, cx| {
+            if let InputEvent::PressEnter { .. } = &event {
+                view.handle_search(cx);
+            }
+        }));
+        let value_state = cx.new(|cx| InputState::new(window, cx));
+
+        info!("Creating new config viewer view");
+
+        let mut this = Self {
+            server_state,
+            pattern_state,
+            value_state,
+            _subscriptions: subscriptions,
+        };
+        this.handle_search(cx);
+        this
+    }
+
+    fn handle_search(&mut self, cx: &mut Context<Self>) {
+        let pattern = self.pattern_state.read(cx).value();
+        self.server_state.update(cx, |state, cx| {
+            state.load_config(pattern.trim().to_string().into(), cx);
+        });
+    }
+
+    /// Open a dialog to edit the parameter, CONFIG SET runs after confirmation
+    fn handle_edit(&mut self, name: SharedString, value: SharedString, window: &mut Window, cx: &mut Context<Self>) {
+        self.value_state.update(cx, |state, cx| {
+            state.set_value(value, window, cx);
+            state.focus(window, cx);
+        });
+        let server_state = self.server_state.clone();
+        let value_state = self.value_state.clone();
+        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale().to_string();
+        window.open_dialog(cx, move |dialog, _, cx| {
+            let server_state = server_state.clone();
+            let value_state = value_state.clone();
+            let name = name.clone();
+            let message = t!("config.update_prompt", name = name, locale = locale).to_string();
+            dialog
+                .title(i18n_config(cx, "update_title"))
+                .confirm()
+                .child(
+                    v_flex()
+                        .gap_2()
+                        .child(Label::new(message).text_sm())
+                        .child(Input::new(&value_state)),
+                )
+                .on_ok(move |_, window, cx| {
+                    let value = value_state.read(cx).value();
+                    let name = name.clone();
+                    server_state.update(cx, |state, cx| {
+                        state.set_config(name, value, cx);
+                    });
+                    window.close_dialog(cx);
+                    true
+                })
+        });
+    }
+
+    /// Render the pattern input with search button
+    fn render_pattern_input(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let loading = self.server_state.read(cx).config().loading();
+        h_flex()
+            .p_2()
+            .gap_2()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .child(Input::new(&self.pattern_state).flex_1().cleanable(true))
+            .child(
+                Button::new("config-search-btn")
+                    .outline()
+                    .loading(loading)
+                    .disabled(loading)
+                    .icon(IconName::Search)
+                    .label(i18n_config(cx, "search"))
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        this.handle_search(cx);
+                    })),
+            )
+    }
+
+    /// Render the parameters table
+    fn render_items(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let config = self.server_state.read(cx).config();
+        let message = if config.unsupported() {
+            Some(i18n_config(cx, "unsupported"))
+        } else if config.items().is_empty() && !config.loading() {
+            Some(i18n_config(cx, "no_parameters"))
+        } else {
+            None
+        };
+        if let Some(message) = message {
+            return h_flex()
+                .w_full()
+                .justify_center()
+                .gap_2()
+                .pt_5()
+                .child(Icon::new(IconName::Info).text_sm())
+                .child(Label::new(message).text_sm())
+                .into_any_element();
+        }
+        let items = config.items().to_vec();
+        let name_color = cx.theme().primary;
+        let edit_tooltip = i18n_common(cx, "update");
+        let view = cx.entity();
+        uniform_list("config-items", items.len(), move |range, _window, _cx| {
+            range
+                .filter_map(|index| {
+                    let (name, value) = items.get(index)?.clone();
+                    let view = view.clone();
+                    Some(
+                        h_flex()
+                            .id(("config-item", index))
+                            .w_full()
+                            .px_2()
+                            .py_1()
+                            .gap_3()
+                            .child(
+                                div()
+                                    .w(px(CONFIG_NAME_WIDTH))
+                                    .text_ellipsis()
+                                    .text_color(name_color)
+                                    .child(name.clone()),
+                            )
+                            .child(div().flex_1().text_ellipsis().child(value.clone()))
+                            .child(
+                                Button::new(("config-item-edit", index))
+                                    .ghost()
+                                    .icon(CustomIconName::FilePenLine)
+                                    .tooltip(edit_tooltip.clone())
+                                    .on_click(move |_, window, cx| {
+                                        let name = name.clone();
+                                        let value = value.clone();
+                                        view.update(cx, |this, cx| {
+                                            this.handle_edit(name, value, window, cx);
+                                        });
+                                    }),
+                            ),
+                    )
+                })
+                .collect()
+        })
+        .font_family(get_font_family())
+        .text_sm()
+        .size_full()
+        .into_any_element()
+    }
+}
+
+impl Render for ZedisConfigViewer {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        v_flex()
+            .size_full()
+            .child(self.render_pattern_input(cx))
+            .child(div().flex_1().w_full().child(self.render_items(cx)))
+    }
+}
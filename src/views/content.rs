@@ -13,13 +13,16 @@
 // limitations under the License.
 
 use crate::{
-    helpers::get_key_tree_widths,
-    states::{Route, ZedisGlobalStore, ZedisServerState, i18n_common, save_app_state},
-    views::{ZedisEditor, ZedisKeyTree, ZedisServers, ZedisSettingEditor, ZedisStatusBar},
+    helpers::{EditorAction, get_key_tree_widths},
+    states::{Route, ZedisGlobalStore, ZedisServerState, i18n_common, i18n_key_tree, save_app_state},
+    views::{
+        ZedisConfigViewer, ZedisDashboard, ZedisEditor, ZedisKeyTree, ZedisPubSubViewer, ZedisQuickOpen, ZedisServers,
+        ZedisSettingEditor, ZedisStatusBar,
+    },
 };
 use gpui::{Entity, Pixels, Subscription, Window, div, prelude::*, px};
 use gpui_component::{
-    ActiveTheme,
+    ActiveTheme, WindowExt,
     label::Label,
     resizable::{ResizableState, h_resizable, resizable_panel},
     skeleton::Skeleton,
@@ -33,12 +36,16 @@ const LOADING_SKELETON_SMALL_WIDTH: f32 = 100.0;
 const LOADING_SKELETON_MEDIUM_WIDTH: f32 = 220.0;
 const LOADING_SKELETON_LARGE_WIDTH: f32 = 420.0;
 const SERVERS_MARGIN: f32 = 8.0;
+const QUICK_OPEN_DIALOG_WIDTH: f32 = 600.0;
 
 /// Main content area component for the Zedis application
 ///
 /// Manages the application's main views and routing:
 /// - Server list view (Route::Home): Display and manage Redis server connections
 /// - Editor view (Route::Editor): Display key tree and value editor for selected server
+/// - Pub/Sub view (Route::PubSub): Watch messages of subscribed channels
+/// - Config view (Route::Config): View and tune server parameters
+/// - Dashboard view (Route::Dashboard): Overview of INFO and keyspace stats
 ///
 /// Views are lazily initialized and cached for performance, but cleared when
 /// no longer needed to conserve memory.
@@ -51,6 +58,9 @@ pub struct ZedisContent {
     settings: Option<Entity<ZedisSettingEditor>>,
     value_editor: Option<Entity<ZedisEditor>>,
     key_tree: Option<Entity<ZedisKeyTree>>,
+    pubsub_viewer: Option<Entity<ZedisPubSubViewer>>,
+    config_viewer: Option<Entity<ZedisConfigViewer>>,
+    dashboard: Option<Entity<ZedisDashboard>>,
     status_bar: Entity<ZedisStatusBar>,
 
     /// Persisted width of the key tree panel (resizable by user)
@@ -87,6 +97,28 @@ impl ZedisContent {
                 let _ = this.servers.take();
             }
 
+            // Clean up pub/sub view when not on pub/sub route
+            if route != Route::PubSub && this.pubsub_viewer.is_some() {
+                info!("Cleaning up pub/sub view (route changed)");
+                let _ = this.pubsub_viewer.take();
+                // Stop listening, the subscriptions belong to the view
+                this.server_state.update(cx, |state, cx| {
+                    state.close_pubsub(cx);
+                });
+            }
+
+            // Clean up config view when not on config route
+            if route != Route::Config && this.config_viewer.is_some() {
+                info!("Cleaning up config view (route changed)");
+                let _ = this.config_viewer.take();
+            }
+
+            // Clean up dashboard view when not on dashboard route
+            if route != Route::Dashboard && this.dashboard.is_some() {
+                info!("Cleaning up dashboard view (route changed)");
+                let _ = this.dashboard.take();
+            }
+
             // Clean up editor views when not on editor route
             if route != Route::Editor {
                 info!("Cleaning up key tree and value editor view (route changed)");
@@ -115,6 +147,9 @@ impl ZedisContent {
             value_editor: None,
             settings: None,
             key_tree: None,
+            pubsub_viewer: None,
+            config_viewer: None,
+            dashboard: None,
             key_tree_width,
             _subscriptions: subscriptions,
         }
@@ -145,6 +180,39 @@ impl ZedisContent {
             .clone();
         div().child(settings)
     }
+    /// Render the pub/sub viewer for the selected server
+    fn render_pubsub(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let pubsub_viewer = self
+            .pubsub_viewer
+            .get_or_insert_with(|| {
+                debug!("Creating new pub/sub viewer view");
+                cx.new(|cx| ZedisPubSubViewer::new(self.server_state.clone(), window, cx))
+            })
+            .clone();
+        div().flex_1().w_full().child(pubsub_viewer)
+    }
+    /// Render the config viewer for the selected server
+    fn render_config(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let config_viewer = self
+            .config_viewer
+            .get_or_insert_with(|| {
+                debug!("Creating new config viewer view");
+                cx.new(|cx| ZedisConfigViewer::new(self.server_state.clone(), window, cx))
+            })
+            .clone();
+        div().flex_1().w_full().child(config_viewer)
+    }
+    /// Render the dashboard for the selected server
+    fn render_dashboard(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let dashboard = self
+            .dashboard
+            .get_or_insert_with(|| {
+                debug!("Creating new dashboard view");
+                cx.new(|cx| ZedisDashboard::new(self.server_state.clone(), window, cx))
+            })
+            .clone();
+        div().flex_1().w_full().child(dashboard)
+    }
     /// Render a loading skeleton screen with animated placeholders
     ///
     /// Displayed when the application is busy (e.g., connecting to Redis server,
@@ -169,6 +237,16 @@ impl ZedisContent {
                 ),
         )
     }
+    /// Open the quick open palette to jump to a loaded key
+    fn open_quick_open(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let quick_open = cx.new(|cx| ZedisQuickOpen::new(self.server_state.clone(), window, cx));
+        window.open_dialog(cx, move |dialog, _, cx| {
+            dialog
+                .title(i18n_key_tree(cx, "quick_open_title"))
+                .w(px(QUICK_OPEN_DIALOG_WIDTH))
+                .child(quick_open.clone())
+        });
+    }
     /// Render the main editor interface with resizable panels
     ///
     /// Layout:
@@ -256,6 +334,18 @@ impl Render for ZedisContent {
         match route {
             Route::Home => base.child(self.render_servers(window, cx)).into_any_element(),
             Route::Settings => base.child(self.render_settings(window, cx)).into_any_element(),
+            Route::PubSub => base
+                .child(self.render_pubsub(window, cx))
+                .child(self.status_bar.clone())
+                .into_any_element(),
+            Route::Config => base
+                .child(self.render_config(window, cx))
+                .child(self.status_bar.clone())
+                .into_any_element(),
+            Route::Dashboard => base
+                .child(self.render_dashboard(window, cx))
+                .child(self.status_bar.clone())
+                .into_any_element(),
             _ => {
                 // Route 2: Loading state (show skeleton while connecting/loading)
                 let is_busy = self.server_state.read(cx).is_busy();
@@ -264,13 +354,31 @@ impl Render for ZedisContent {
                 base.when(is_busy, |this| this.child(self.render_loading(window, cx)))
                     .when(!is_busy, |this| {
                         this.child(
-                            div().flex_1().w_full().relative().child(
-                                div()
-                                    .absolute()
-                                    .inset_0()
-                                    .size_full()
-                                    .child(self.render_editor(window, cx)),
-                            ),
+                            div()
+                                .flex_1()
+                                .w_full()
+                                .relative()
+                                .child(
+                                    div()
+                                        .absolute()
+                                        .inset_0()
+                                        .size_full()
+                                        .child(self.render_editor(window, cx)),
+                                )
+                                .on_action(cx.listener(|this, event: &EditorAction, window, cx| match event {
+                                    // Focus the key tree search from anywhere in the editor route
+                                    EditorAction::FocusSearch => {
+                                        if let Some(key_tree) = this.key_tree.as_ref() {
+                                            key_tree.update(cx, |key_tree, cx| {
+                                                key_tree.focus_keyword(window, cx);
+                                            });
+                                        }
+                                    }
+                                    EditorAction::QuickOpen => {
+                                        this.open_quick_open(window, cx);
+                                    }
+                                    _ => {}
+                                })),
                         )
                     })
                     .child(self.status_bar.clone())
@@ -0,0 +1,308 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    assets::CustomIconName,
+    helpers::get_font_family,
+    states::{ServerEvent, ServerTask, ZedisServerState, i18n_dashboard},
+};
+use gpui::{AnyElement, Entity, SharedString, Subscription, Window, div, prelude::*, px};
+use gpui_component::{
+    ActiveTheme, Disableable, Icon, IconName,
+    button::{Button, ButtonVariants},
+    h_flex,
+    label::Label,
+    scroll::ScrollableElement,
+    v_flex,
+};
+use humansize::{DECIMAL, format_size};
+use std::time::Duration;
+use tracing::info;
+
+// Width of a stat card
+const CARD_WIDTH: f32 = 220.0;
+// Width of the first column of the keyspace and node tables
+const TABLE_NAME_WIDTH: f32 = 200.0;
+
+/// Server dashboard component
+///
+/// Features:
+/// - Card grid of uptime, memory, clients, commands and hit rate from INFO
+/// - Keyspace stats of every database
+/// - Memory of each master node in cluster mode
+///
+/// The data is refreshed by the status bar heartbeat.
+pub struct ZedisDashboard {
+    /// Reference to server state for Redis operations
+    server_state: Entity<ZedisServerState>,
+
+    /// Whether INFO is being loaded
+    loading: bool,
+
+    /// Event subscriptions for reactive updates
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ZedisDashboard {
+    pub fn new(server_state: Entity<ZedisServerState>, _window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let mut subscriptions = Vec::new();
+        subscriptions.push(
+            cx.subscribe(&server_state, |this, _server_state, event, cx| match event {
+                ServerEvent::TaskStarted(ServerTask::LoadServerInfo) => {
+                    this.loading = true;
+                    cx.notify();
+                }
+                ServerEvent::ServerRedisInfoUpdated(_) | ServerEvent::ErrorOccurred(_) => {
+                    this.loading = false;
+                    cx.notify();
+                }
+                _ => {}
+            }),
+        );
+
+        info!("Creating new dashboard view");
+
+        let mut this = Self {
+            server_state,
+            loading: false,
+            _subscriptions: subscriptions,
+        };
+        this.handle_refresh(cx);
+        this
+    }
+
+    fn handle_refresh(&mut self, cx: &mut Context<Self>) {
+        self.server_state.update(cx, |state, cx| {
+            state.load_server_info(cx);
+        });
+    }
+
+    /// Render a card with a title, a value and an optional description
+    fn render_card(
+        &self,
+        title: SharedString,
+        value: SharedString,
+        description: Option<SharedString>,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        v_flex()
+            .w(px(CARD_WIDTH))
+            .p_3()
+            .gap_1()
+            .border_1()
+            .border_color(cx.theme().border)
+            .rounded(cx.theme().radius)
+            .child(Label::new(title).text_sm().text_color(cx.theme().muted_foreground))
+            .child(Label::new(value).text_xl().text_color(cx.theme().primary))
+            .when_some(description, |this, description| {
+                this.child(
+                    Label::new(description)
+                        .text_xs()
+                        .text_color(cx.theme().muted_foreground),
+                )
+            })
+            .into_any_element()
+    }
+
+    /// Render a section title followed by rows of name and values
+    fn render_table(
+        &self,
+        title: SharedString,
+        header: Vec<SharedString>,
+        rows: Vec<Vec<SharedString>>,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let render_row = |row: Vec<SharedString>, color| {
+            let mut cells = row.into_iter();
+            h_flex()
+                .w_full()
+                .px_2()
+                .py_1()
+                .gap_3()
+                .text_color(color)
+                .child(
+                    div()
+                        .w(px(TABLE_NAME_WIDTH))
+                        .text_ellipsis()
+                        .child(cells.next().unwrap_or_default()),
+                )
+                .children(cells.map(|cell| div().flex_1().text_ellipsis().child(cell)))
+        };
+        v_flex()
+            .w_full()
+            .gap_1()
+            .child(Label::new(title).text_sm())
+            .child(
+                v_flex()
+                    .w_full()
+                    .border_1()
+                    .border_color(cx.theme().border)
+                    .rounded(cx.theme().radius)
+                    .font_family(get_font_family())
+                    .text_sm()
+                    .child(render_row(header, cx.theme().muted_foreground))
+                    .children(rows.into_iter().map(|row| render_row(row, cx.theme().foreground))),
+            )
+            .into_any_element()
+    }
+}
+
+impl Render for ZedisDashboard {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let toolbar = h_flex()
+            .p_2()
+            .gap_2()
+            .justify_end()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .child(
+                Button::new("dashboard-refresh-btn")
+                    .outline()
+                    .loading(self.loading)
+                    .disabled(self.loading)
+                    .icon(CustomIconName::RotateCw)
+                    .label(i18n_dashboard(cx, "refresh"))
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        this.handle_refresh(cx);
+                    })),
+            );
+        let Some(info) = self.server_state.read(cx).redis_info().cloned() else {
+            return v_flex().size_full().child(toolbar).child(
+                h_flex()
+                    .w_full()
+                    .justify_center()
+                    .gap_2()
+                    .pt_5()
+                    .child(Icon::new(IconName::Info).text_sm())
+                    .child(Label::new(i18n_dashboard(cx, "no_data")).text_sm()),
+            );
+        };
+
+        let uptime = humantime::format_duration(Duration::from_secs(info.uptime_in_seconds)).to_string();
+        let memory_description = (info.maxmemory > 0).then(|| {
+            format!(
+                "{} {}",
+                i18n_dashboard(cx, "maxmemory"),
+                format_size(info.maxmemory, DECIMAL)
+            )
+            .into()
+        });
+        let cards = vec![
+            self.render_card(
+                i18n_dashboard(cx, "uptime"),
+                uptime.into(),
+                Some(format!("Redis {} ({})", info.redis_version, info.role).into()),
+                cx,
+            ),
+            self.render_card(
+                i18n_dashboard(cx, "used_memory"),
+                info.used_memory_human.clone().into(),
+                memory_description,
+                cx,
+            ),
+            self.render_card(
+                i18n_dashboard(cx, "connected_clients"),
+                info.connected_clients.to_string().into(),
+                Some(format!("{} {}", i18n_dashboard(cx, "blocked_clients"), info.blocked_clients).into()),
+                cx,
+            ),
+            self.render_card(
+                i18n_dashboard(cx, "total_commands_processed"),
+                info.total_commands_processed.to_string().into(),
+                Some(format!("{} ops/s", info.instantaneous_ops_per_sec).into()),
+                cx,
+            ),
+            self.render_card(
+                i18n_dashboard(cx, "hit_rate"),
+                format!("{:.2}%", info.hit_rate()).into(),
+                Some(format!("{} / {}", info.keyspace_hits, info.keyspace_misses).into()),
+                cx,
+            ),
+            self.render_card(
+                i18n_dashboard(cx, "total_keys"),
+                info.total_keys().to_string().into(),
+                Some(format!("{} {}", i18n_dashboard(cx, "evicted_keys"), info.evicted_keys).into()),
+                cx,
+            ),
+        ];
+
+        let mut keyspace: Vec<_> = info.keyspace.iter().collect();
+        keyspace.sort_unstable_by(|a, b| a.0.cmp(b.0));
+        let keyspace_rows = keyspace
+            .into_iter()
+            .map(|(db, stats)| {
+                vec![
+                    db.clone().into(),
+                    stats.keys.to_string().into(),
+                    stats.expires.to_string().into(),
+                    humantime::format_duration(Duration::from_millis(stats.avg_ttl))
+                        .to_string()
+                        .into(),
+                ]
+            })
+            .collect();
+        let keyspace_table = self.render_table(
+            i18n_dashboard(cx, "keyspace"),
+            vec![
+                i18n_dashboard(cx, "db"),
+                i18n_dashboard(cx, "keys"),
+                i18n_dashboard(cx, "expires"),
+                i18n_dashboard(cx, "avg_ttl"),
+            ],
+            keyspace_rows,
+            cx,
+        );
+
+        // Per node memory is only useful with more than one master
+        let nodes_table = (info.nodes.len() > 1).then(|| {
+            let rows = info
+                .nodes
+                .iter()
+                .map(|node| {
+                    let maxmemory = if node.maxmemory > 0 {
+                        format_size(node.maxmemory, DECIMAL)
+                    } else {
+                        "-".to_string()
+                    };
+                    vec![
+                        node.addr.clone().into(),
+                        node.used_memory_human.clone().into(),
+                        maxmemory.into(),
+                    ]
+                })
+                .collect();
+            self.render_table(
+                i18n_dashboard(cx, "nodes"),
+                vec![
+                    i18n_dashboard(cx, "node"),
+                    i18n_dashboard(cx, "used_memory"),
+                    i18n_dashboard(cx, "maxmemory"),
+                ],
+                rows,
+                cx,
+            )
+        });
+
+        v_flex().size_full().child(toolbar).child(
+            div().flex_1().w_full().overflow_y_scrollbar().child(
+                v_flex()
+                    .p_3()
+                    .gap_4()
+                    .child(h_flex().flex_wrap().gap_3().children(cards))
+                    .child(keyspace_table)
+                    .children(nodes_table),
+            ),
+        )
+    }
+}
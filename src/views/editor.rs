@@ -14,14 +14,19 @@
 
 use crate::{
     assets::CustomIconName,
-    helpers::{EditorAction, humanize_keystroke, validate_ttl},
-    states::{KeyType, ServerEvent, ZedisGlobalStore, ZedisServerState, i18n_common, i18n_editor},
-    views::{ZedisBytesEditor, ZedisHashEditor, ZedisListEditor, ZedisSetEditor, ZedisZsetEditor},
+    components::{FormDialog, FormField, open_add_form_dialog},
+    helpers::{EditorAction, humanize_keystroke, validate_expire, validate_long_string},
+    states::{ExpireCondition, KeyType, ServerEvent, ZedisGlobalStore, ZedisServerState, i18n_common, i18n_editor},
+    views::{
+        ZedisBytesEditor, ZedisHashEditor, ZedisListEditor, ZedisSetEditor, ZedisStreamEditor, ZedisValueDiff,
+        ZedisZsetEditor,
+    },
 };
-use gpui::{ClipboardItem, Entity, SharedString, Subscription, Window, div, prelude::*, px};
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use gpui::{App, ClipboardItem, Corner, Entity, SharedString, Subscription, Window, div, prelude::*, px};
 use gpui_component::{
     ActiveTheme, Disableable, Icon, IconName, WindowExt,
-    button::Button,
+    button::{Button, ButtonVariants, DropdownButton},
     h_flex,
     input::{Input, InputEvent, InputState},
     label::Label,
@@ -29,14 +34,34 @@ use gpui_component::{
     scroll::ScrollableElement,
     v_flex,
 };
+use home::home_dir;
 use humansize::{DECIMAL, format_size};
 use rust_i18n::t;
-use std::time::{Duration, Instant};
+use std::{
+    rc::Rc,
+    time::{Duration, Instant},
+};
 use tracing::{debug, info};
 
 // Constants
 const RECENTLY_SELECTED_THRESHOLD_MS: u64 = 300;
 const TTL_INPUT_MAX_WIDTH: f32 = 130.0;
+const ENCODING_BADGE_FADE_ALPHA: f32 = 0.8; // Background transparency for the encoding badge
+const ENCODING_BADGE_BORDER_FADE_ALPHA: f32 = 0.5; // Border transparency for the encoding badge
+const VALUE_DIFF_DIALOG_WIDTH: f32 = 900.0;
+
+/// Keep the suggested file name portable, e.g. user:1 -> user_1
+fn portable_file_name(key: &str) -> String {
+    key.chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '.' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
 
 /// Main editor component for displaying and editing Redis key values
 /// Supports different key types (String, List, etc.) with type-specific editors
@@ -50,10 +75,13 @@ pub struct ZedisEditor {
     set_editor: Option<Entity<ZedisSetEditor>>,
     zset_editor: Option<Entity<ZedisZsetEditor>>,
     hash_editor: Option<Entity<ZedisHashEditor>>,
+    stream_editor: Option<Entity<ZedisStreamEditor>>,
 
     /// TTL editing state
     ttl_edit_mode: bool,
     ttl_input_state: Entity<InputState>,
+    /// EXPIRE condition (NX/XX/GT/LT) used when updating the TTL
+    expire_condition: ExpireCondition,
 
     /// Track when a key was selected to handle loading states smoothly
     selected_key_at: Option<Instant>,
@@ -70,9 +98,9 @@ impl ZedisEditor {
         // Initialize TTL input field with placeholder
         let ttl_input_state = cx.new(|cx| {
             InputState::new(window, cx)
-                .validate(|s, _cx| validate_ttl(s))
+                .validate(|s, _cx| validate_expire(s))
                 .clean_on_escape()
-                .placeholder(i18n_common(cx, "ttl_placeholder"))
+                .placeholder(i18n_editor(cx, "ttl_placeholder"))
         });
 
         // Subscribe to server events to track when keys are selected
@@ -107,8 +135,10 @@ impl ZedisEditor {
             set_editor: None,
             zset_editor: None,
             hash_editor: None,
+            stream_editor: None,
             ttl_edit_mode: false,
             ttl_input_state,
+            expire_condition: ExpireCondition::default(),
             _subscriptions: subscriptions,
             selected_key_at: None,
         }
@@ -130,13 +160,197 @@ impl ZedisEditor {
 
         self.ttl_edit_mode = false;
         let ttl = self.ttl_input_state.read(cx).value();
+        let condition = self.expire_condition;
+
+        self.server_state.update(cx, move |state, cx| {
+            state.update_key_ttl(key, ttl, condition, cx);
+        });
+        cx.notify();
+    }
 
+    /// Remove the expiration of the currently selected key
+    fn persist_key(&mut self, cx: &mut Context<Self>) {
+        let Some(key) = self.server_state.read(cx).key() else {
+            return;
+        };
         self.server_state.update(cx, move |state, cx| {
-            state.update_key_ttl(key, ttl, cx);
+            state.persist_key(key, cx);
         });
         cx.notify();
     }
 
+    /// Export the currently selected key with its type and TTL to a JSON file
+    fn export_key(&mut self, cx: &mut Context<Self>) {
+        let Some(key) = self.server_state.read(cx).key() else {
+            return;
+        };
+        let directory = home_dir().unwrap_or_default();
+        let path = cx.prompt_for_new_path(&directory, Some(&format!("{}.json", portable_file_name(&key))));
+        let server_state = self.server_state.clone();
+        cx.spawn(async move |_this, cx| {
+            let Ok(Ok(Some(path))) = path.await else {
+                return;
+            };
+            let _ = server_state.update(cx, |state, cx| {
+                state.export_key(key, path, cx);
+            });
+        })
+        .detach();
+    }
+
+    /// Export the raw value of the selected key to a file
+    fn export_value(&mut self, cx: &mut Context<Self>) {
+        let server_state = self.server_state.read(cx);
+        let Some(key) = server_state.key() else {
+            return;
+        };
+        let extension = match server_state.value().map(|value| value.key_type()) {
+            Some(KeyType::Hash) => "json",
+            Some(KeyType::List | KeyType::Set) => "txt",
+            Some(KeyType::String) => match server_state.value().and_then(|value| value.bytes_value()) {
+                Some(value) if value.is_utf8_text() => "txt",
+                _ => "bin",
+            },
+            _ => return,
+        };
+        let directory = home_dir().unwrap_or_default();
+        let path = cx.prompt_for_new_path(&directory, Some(&format!("{}.{extension}", portable_file_name(&key))));
+        let server_state = self.server_state.clone();
+        cx.spawn(async move |_this, cx| {
+            let Ok(Ok(Some(path))) = path.await else {
+                return;
+            };
+            let _ = server_state.update(cx, |state, cx| {
+                state.export_value(key, path, cx);
+            });
+        })
+        .detach();
+    }
+
+    /// Copy the value of the string editor, binary values are copied as base64
+    fn copy_value(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(bytes_editor) = self.bytes_editor.clone() else {
+            return;
+        };
+        let (content, message) = if bytes_editor.read(cx).is_binary() {
+            let Some(value) = self.server_state.read(cx).value().and_then(|value| value.bytes_value()) else {
+                return;
+            };
+            (
+                BASE64.encode(&value.bytes),
+                i18n_editor(cx, "copied_value_base64_to_clipboard"),
+            )
+        } else {
+            let value = bytes_editor.update(cx, |state, cx| state.value(cx));
+            (value.to_string(), i18n_editor(cx, "copied_value_to_clipboard"))
+        };
+        cx.write_to_clipboard(ClipboardItem::new_string(content));
+        window.push_notification(Notification::info(message), cx);
+    }
+
+    /// Copy the loaded value as redis-cli commands, built without a round-trip
+    fn copy_as_command(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let server_state = self.server_state.read(cx);
+        let Some(key) = server_state.key() else {
+            return;
+        };
+        let Some(commands) = server_state.value().and_then(|value| value.to_redis_commands(&key)) else {
+            return;
+        };
+        cx.write_to_clipboard(ClipboardItem::new_string(commands));
+        window.push_notification(Notification::info(i18n_editor(cx, "copied_command_to_clipboard")), cx);
+    }
+
+    /// Rename the currently selected key, RENAMENX keeps an existing target key
+    fn rename_key(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(key) = self.server_state.read(cx).key() else {
+            return;
+        };
+        let fields = vec![
+            FormField::new(i18n_editor(cx, "rename_mode")).with_options(vec![
+                i18n_editor(cx, "rename_mode_overwrite"),
+                i18n_editor(cx, "rename_mode_nx"),
+            ]),
+            FormField::new(i18n_editor(cx, "new_key"))
+                .with_placeholder(i18n_common(cx, "key_placeholder"))
+                .with_value(key.clone())
+                .with_focus()
+                .with_validate(validate_long_string),
+        ];
+        let server_state = self.server_state.clone();
+        let handle_submit = Rc::new(move |values: Vec<SharedString>, window: &mut Window, cx: &mut App| {
+            if values.len() != 2 {
+                return false;
+            }
+            let new_key: SharedString = values[1].trim().to_string().into();
+            if new_key.is_empty() {
+                return false;
+            }
+            let nx = values[0].parse::<usize>().unwrap_or(0) == 1;
+            let key = key.clone();
+            server_state.update(cx, |state, cx| {
+                state.rename_key(key, new_key, nx, cx);
+            });
+            window.close_dialog(cx);
+            true
+        });
+
+        open_add_form_dialog(
+            FormDialog {
+                title: i18n_editor(cx, "rename_key_title"),
+                fields,
+                handle_submit,
+            },
+            window,
+            cx,
+        );
+    }
+
+    /// Copy the currently selected key to a new name, REPLACE overwrites an existing target key
+    fn copy_key_to(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(key) = self.server_state.read(cx).key() else {
+            return;
+        };
+        let fields = vec![
+            FormField::new(i18n_editor(cx, "copy_mode")).with_options(vec![
+                i18n_editor(cx, "copy_mode_keep"),
+                i18n_editor(cx, "copy_mode_replace"),
+            ]),
+            FormField::new(i18n_editor(cx, "new_key"))
+                .with_placeholder(i18n_common(cx, "key_placeholder"))
+                .with_value(key.clone())
+                .with_focus()
+                .with_validate(validate_long_string),
+        ];
+        let server_state = self.server_state.clone();
+        let handle_submit = Rc::new(move |values: Vec<SharedString>, window: &mut Window, cx: &mut App| {
+            if values.len() != 2 {
+                return false;
+            }
+            let new_key: SharedString = values[1].trim().to_string().into();
+            if new_key.is_empty() || new_key == key {
+                return false;
+            }
+            let replace = values[0].parse::<usize>().unwrap_or(0) == 1;
+            let key = key.clone();
+            server_state.update(cx, |state, cx| {
+                state.copy_key(key, new_key, replace, cx);
+            });
+            window.close_dialog(cx);
+            true
+        });
+
+        open_add_form_dialog(
+            FormDialog {
+                title: i18n_editor(cx, "copy_key_title"),
+                fields,
+                handle_submit,
+            },
+            window,
+            cx,
+        );
+    }
+
     /// Delete the currently selected key with confirmation dialog
     fn delete_key(&mut self, window: &mut Window, cx: &mut Context<Self>) {
         let Some(key) = self.server_state.read(cx).key() else {
@@ -164,11 +378,8 @@ impl ZedisEditor {
         });
     }
     fn reload(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
-        let Some(key) = self.server_state.read(cx).key() else {
-            return;
-        };
         self.server_state.update(cx, move |state, cx| {
-            state.select_key(key, cx);
+            state.reload_key(cx);
         });
     }
     fn save(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
@@ -190,6 +401,20 @@ impl ZedisEditor {
             });
         });
     }
+    /// Show the changes of the edited value against the stored value
+    fn open_value_diff(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(editor) = self.bytes_editor.as_ref() else {
+            return;
+        };
+        let (original, value) = editor.update(cx, |state, cx| (state.original_value(), state.value(cx)));
+        let value_diff = cx.new(|cx| ZedisValueDiff::new(&original, &value, window, cx));
+        window.open_dialog(cx, move |dialog, _, cx| {
+            dialog
+                .title(i18n_editor(cx, "diff_title"))
+                .w(px(VALUE_DIFF_DIALOG_WIDTH))
+                .child(value_diff.clone())
+        });
+    }
     fn toggle_ttl_edit_mode(&mut self, window: &mut Window, cx: &mut Context<Self>) {
         let server_state = self.server_state.read(cx);
         let Some(value) = server_state.value() else {
@@ -213,6 +438,52 @@ impl ZedisEditor {
         });
         cx.notify();
     }
+    /// Render the EXPIRE condition (NX/XX/GT/LT) dropdown of the TTL input
+    fn render_expire_condition_dropdown(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let condition = self.expire_condition;
+        let label = condition.as_option().unwrap_or("--");
+        DropdownButton::new("zedis-editor-ttl-condition")
+            .button(
+                Button::new("zedis-editor-ttl-condition-btn")
+                    .ghost()
+                    .px_1()
+                    .label(label)
+                    .tooltip(i18n_editor(cx, "expire_condition_tooltip")),
+            )
+            .dropdown_menu_with_anchor(Corner::TopRight, move |menu, _, _| {
+                [
+                    (ExpireCondition::Always, "expire_condition_always"),
+                    (ExpireCondition::Nx, "expire_condition_nx"),
+                    (ExpireCondition::Xx, "expire_condition_xx"),
+                    (ExpireCondition::Gt, "expire_condition_gt"),
+                    (ExpireCondition::Lt, "expire_condition_lt"),
+                ]
+                .into_iter()
+                .fold(menu, |menu, (item, key)| {
+                    menu.menu_element_with_check(condition == item, Box::new(item), move |_, cx| {
+                        Label::new(i18n_editor(cx, key)).ml_2().text_xs()
+                    })
+                })
+            })
+    }
+    /// Render the OBJECT ENCODING badge in the color of the key type
+    fn render_encoding_badge(encoding: SharedString, key_type: KeyType) -> impl IntoElement {
+        let color = key_type.color();
+        let mut bg = color;
+        bg.fade_out(ENCODING_BADGE_FADE_ALPHA);
+        let mut border = color;
+        border.fade_out(ENCODING_BADGE_BORDER_FADE_ALPHA);
+
+        Label::new(encoding)
+            .text_xs()
+            .bg(bg)
+            .text_color(color)
+            .border_1()
+            .px_1()
+            .mr_2()
+            .rounded_sm()
+            .border_color(border)
+    }
     /// Render the key information bar with actions (copy, save, TTL, delete)
     fn render_select_key(&self, cx: &mut Context<Self>) -> impl IntoElement {
         let server_state = self.server_state.read(cx);
@@ -223,7 +494,14 @@ impl ZedisEditor {
         let mut is_busy = false;
         let mut btns = vec![];
         let mut ttl = SharedString::default();
+        let mut ttl_tooltip = i18n_editor(cx, "update_ttl_tooltip");
         let mut size = SharedString::default();
+        let mut element_count = None;
+        let mut has_expiry = false;
+        let mut memory_usage = None;
+        let mut cluster_slot = None;
+        let mut encoding_badge = None;
+        let value_key_type = server_state.value().map(|value| value.key_type());
 
         // Extract value information if available
         if let Some(value) = server_state.value() {
@@ -250,14 +528,55 @@ impl ZedisEditor {
             .join(" ")
             .into();
 
+            // Show the absolute expiry time in the tooltip
+            let expiry: SharedString = match value.ttl().map(|ttl| ttl.num_seconds()) {
+                Some(-2) => i18n_common(cx, "expired"),
+                Some(seconds) if seconds < 0 => i18n_editor(cx, "no_expiry"),
+                Some(_) => {
+                    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+                    let datetime = value
+                        .expire_datetime()
+                        .map(|datetime| datetime.format("%Y-%m-%d %H:%M:%S").to_string())
+                        .unwrap_or_default();
+                    t!("editor.expire_at", datetime = datetime, locale = locale)
+                        .to_string()
+                        .into()
+                }
+                None => SharedString::default(),
+            };
+            if !expiry.is_empty() {
+                ttl_tooltip = format!("{expiry}\n{ttl_tooltip}").into();
+            }
+
+            has_expiry = value.ttl().is_some_and(|ttl| ttl.num_seconds() >= 0);
             size = format_size(value.size() as u64, DECIMAL).into();
+            memory_usage = value.memory_usage().map(|bytes| format_size(bytes, DECIMAL));
+            cluster_slot = value.cluster_slot();
+            encoding_badge = value
+                .encoding()
+                .map(|encoding| Self::render_encoding_badge(encoding, value.key_type()));
+            element_count = value.element_count();
         }
 
         // Show loading only if busy and not recently selected (avoid flashing)
         let should_show_loading = is_busy && !self.is_selected_key_recently();
+        // Collections only load one page, so show the element count first
+        if let Some(count) = element_count {
+            let count_label = i18n_editor(cx, "element_count");
+            btns.push(
+                Label::new(format!("{count_label} : {count}"))
+                    .ml_2()
+                    .text_sm()
+                    .into_any_element(),
+            );
+        }
         // Add size label if available
         if !size.is_empty() {
-            let size_label = i18n_common(cx, "size");
+            let size_label = if element_count.is_some() {
+                i18n_editor(cx, "loaded_size")
+            } else {
+                i18n_common(cx, "size")
+            };
             btns.push(
                 Label::new(format!("{size_label} : {size}"))
                     .ml_2()
@@ -265,6 +584,26 @@ impl ZedisEditor {
                     .into_any_element(),
             );
         }
+        // Memory usage is only reported by Redis 4.0+
+        if let Some(memory_usage) = memory_usage {
+            btns.push(
+                Label::new(format!("{} : {memory_usage}", i18n_editor(cx, "memory_usage")))
+                    .ml_2()
+                    .text_sm()
+                    .into_any_element(),
+            );
+        }
+        // Owning slot and master, only in cluster mode
+        if let Some((slot, node)) = cluster_slot {
+            let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+            let node = node.unwrap_or_else(|| "--".into());
+            btns.push(
+                Label::new(t!("editor.cluster_slot", slot = slot, node = node, locale = locale).to_string())
+                    .ml_2()
+                    .text_sm()
+                    .into_any_element(),
+            );
+        }
 
         // Add save button for string editor if value is modified
         if let Some(bytes_editor) = &self.bytes_editor {
@@ -278,6 +617,36 @@ impl ZedisEditor {
             };
             tooltip = format!("{tooltip} ({})", humanize_keystroke("cmd-s")).into();
 
+            // Copy the value, binary values as base64
+            let copy_tooltip = if state.is_binary() {
+                i18n_editor(cx, "copy_value_base64_tooltip")
+            } else {
+                i18n_editor(cx, "copy_value_tooltip")
+            };
+            btns.push(
+                Button::new("zedis-editor-copy-value")
+                    .ml_2()
+                    .outline()
+                    .disabled(should_show_loading)
+                    .tooltip(copy_tooltip)
+                    .icon(IconName::Copy)
+                    .on_click(cx.listener(move |this, _event, window, cx| {
+                        this.copy_value(window, cx);
+                    }))
+                    .into_any_element(),
+            );
+            btns.push(
+                Button::new("zedis-editor-diff-value")
+                    .ml_2()
+                    .disabled(!value_modified || should_show_loading)
+                    .outline()
+                    .tooltip(i18n_editor(cx, "diff_tooltip"))
+                    .icon(CustomIconName::FileDiff)
+                    .on_click(cx.listener(move |this, _event, window, cx| {
+                        this.open_value_diff(window, cx);
+                    }))
+                    .into_any_element(),
+            );
             btns.push(
                 Button::new("zedis-editor-save-key")
                     .ml_2()
@@ -301,31 +670,137 @@ impl ZedisEditor {
                     .ml_2()
                     .max_w(px(TTL_INPUT_MAX_WIDTH))
                     .suffix(
-                        Button::new("zedis-editor-ttl-update-btn")
-                            .icon(Icon::new(IconName::Check))
-                            .on_click(cx.listener(move |this, _event, window, cx| {
-                                this.handle_update_ttl(window, cx);
-                            })),
+                        h_flex().child(self.render_expire_condition_dropdown(cx)).child(
+                            Button::new("zedis-editor-ttl-update-btn")
+                                .icon(Icon::new(IconName::Check))
+                                .on_click(cx.listener(move |this, _event, window, cx| {
+                                    this.handle_update_ttl(window, cx);
+                                })),
+                        ),
                     )
                     .into_any_element()
             } else {
                 // Show TTL button that switches to edit mode on click
-                Button::new("zedis-editor-ttl-btn")
-                    .ml_2()
+                let ttl_btn = Button::new("zedis-editor-ttl-btn")
                     .outline()
                     .w(px(TTL_INPUT_MAX_WIDTH))
                     .disabled(should_show_loading)
-                    .tooltip(i18n_editor(cx, "update_ttl_tooltip"))
+                    .tooltip(ttl_tooltip)
                     .label(ttl.clone())
                     .icon(CustomIconName::Clock3)
                     .on_click(cx.listener(move |this, _event, window, cx| {
                         this.toggle_ttl_edit_mode(window, cx);
-                    }))
-                    .into_any_element()
+                    }));
+                if has_expiry {
+                    // Keys with a finite TTL can be made permanent again
+                    DropdownButton::new("zedis-editor-ttl-dropdown")
+                        .ml_2()
+                        .outline()
+                        .button(ttl_btn)
+                        .dropdown_menu_with_anchor(Corner::TopRight, move |menu, _, cx| {
+                            menu.menu(i18n_editor(cx, "persist_key"), Box::new(EditorAction::PersistTtl))
+                        })
+                        .into_any_element()
+                } else {
+                    ttl_btn.ml_2().into_any_element()
+                }
             };
             btns.push(ttl_btn);
         }
 
+        // export the key with its type and TTL
+        btns.push(
+            Button::new("zedis-editor-export-key")
+                .ml_2()
+                .outline()
+                .disabled(should_show_loading)
+                .tooltip(i18n_editor(cx, "export_key_tooltip"))
+                .icon(CustomIconName::FileDown)
+                .on_click(cx.listener(move |this, _event, _window, cx| {
+                    this.export_key(cx);
+                }))
+                .into_any_element(),
+        );
+
+        // export the raw value, only for the types with a plain file format
+        if matches!(
+            value_key_type,
+            Some(KeyType::String | KeyType::List | KeyType::Set | KeyType::Hash)
+        ) {
+            btns.push(
+                Button::new("zedis-editor-export-value")
+                    .ml_2()
+                    .outline()
+                    .disabled(should_show_loading)
+                    .tooltip(i18n_editor(cx, "export_value_tooltip"))
+                    .icon(CustomIconName::HardDriveDownload)
+                    .on_click(cx.listener(move |this, _event, _window, cx| {
+                        this.export_value(cx);
+                    }))
+                    .into_any_element(),
+            );
+        }
+
+        // copy the value as redis-cli commands
+        btns.push(
+            Button::new("zedis-editor-copy-as-command")
+                .ml_2()
+                .outline()
+                .disabled(should_show_loading)
+                .tooltip(i18n_editor(cx, "copy_as_command_tooltip"))
+                .icon(CustomIconName::SquareTerminal)
+                .on_click(cx.listener(move |this, _event, window, cx| {
+                    this.copy_as_command(window, cx);
+                }))
+                .into_any_element(),
+        );
+
+        // rename the key
+        btns.push(
+            Button::new("zedis-editor-rename-key")
+                .ml_2()
+                .outline()
+                .disabled(should_show_loading)
+                .tooltip(i18n_editor(cx, "rename_key_tooltip"))
+                .icon(CustomIconName::FilePenLine)
+                .on_click(cx.listener(move |this, _event, window, cx| {
+                    if is_busy {
+                        return;
+                    }
+                    this.rename_key(window, cx);
+                }))
+                .into_any_element(),
+        );
+
+        // copy the key to a new name
+        btns.push(
+            Button::new("zedis-editor-copy-key-to")
+                .ml_2()
+                .outline()
+                .disabled(should_show_loading)
+                .tooltip(i18n_editor(cx, "copy_key_to_tooltip"))
+                .icon(CustomIconName::CopyPlus)
+                .on_click(cx.listener(move |this, _event, window, cx| {
+                    this.copy_key_to(window, cx);
+                }))
+                .into_any_element(),
+        );
+
+        // reveal in key tree
+        btns.push(
+            Button::new("zedis-editor-reveal-key")
+                .ml_2()
+                .outline()
+                .tooltip(i18n_editor(cx, "reveal_key_tooltip"))
+                .icon(CustomIconName::LocateFixed)
+                .on_click(cx.listener(move |this, _event, _window, cx| {
+                    this.server_state.update(cx, |state, cx| {
+                        state.reveal_key(cx);
+                    });
+                }))
+                .into_any_element(),
+        );
+
         let reload_tooltip: SharedString = format!(
             "{} ({})",
             i18n_editor(cx, "reload_key_tooltip"),
@@ -391,6 +866,7 @@ impl ZedisEditor {
                     .mx_2()
                     .child(Label::new(key).text_ellipsis().whitespace_nowrap()),
             )
+            .children(encoding_badge)
             .children(btns)
     }
     /// Clean up unused editors when switching between key types
@@ -410,6 +886,9 @@ impl ZedisEditor {
         if key_type != KeyType::Hash {
             let _ = self.hash_editor.take();
         }
+        if key_type != KeyType::Stream {
+            let _ = self.stream_editor.take();
+        }
     }
 
     /// Render the appropriate editor based on the key type
@@ -457,6 +936,62 @@ impl ZedisEditor {
                 });
                 editor.clone().into_any_element()
             }
+            KeyType::Stream => {
+                self.reset_editors(KeyType::Stream);
+                let editor = self.stream_editor.get_or_insert_with(|| {
+                    debug!("Creating new stream editor");
+                    cx.new(|cx| ZedisStreamEditor::new(self.server_state.clone(), window, cx))
+                });
+                editor.clone().into_any_element()
+            }
+            KeyType::String if value.is_deferred() => {
+                self.reset_editors(KeyType::Unknown);
+                let message = t!(
+                    "editor.deferred_value",
+                    size = format_size(value.size() as u64, DECIMAL),
+                    locale = cx.global::<ZedisGlobalStore>().read(cx).locale()
+                )
+                .to_string();
+                v_flex()
+                    .w_full()
+                    .items_center()
+                    .gap_3()
+                    .pt_5()
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .child(Icon::new(IconName::Info).text_sm())
+                            .child(Label::new(message).text_sm()),
+                    )
+                    .child(
+                        Button::new("zedis-editor-load-deferred-value")
+                            .outline()
+                            .label(i18n_editor(cx, "load_anyway"))
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                this.server_state.update(cx, |state, cx| {
+                                    state.load_deferred_value(cx);
+                                });
+                            })),
+                    )
+                    .into_any_element()
+            }
+            key_type if value.is_unsupported() => {
+                self.reset_editors(KeyType::Unknown);
+                let message = t!(
+                    "editor.unsupported_type",
+                    key_type = key_type.name(),
+                    locale = cx.global::<ZedisGlobalStore>().read(cx).locale()
+                )
+                .to_string();
+                h_flex()
+                    .w_full()
+                    .justify_center()
+                    .gap_2()
+                    .pt_5()
+                    .child(Icon::new(IconName::Info).text_sm())
+                    .child(Label::new(message).text_sm())
+                    .into_any_element()
+            }
             _ => {
                 // Default to bytes editor for String type and other types
                 self.reset_editors(KeyType::String);
@@ -496,7 +1031,17 @@ impl Render for ZedisEditor {
                 EditorAction::UpdateTtl => {
                     this.toggle_ttl_edit_mode(window, cx);
                 }
-                _ => {}
+                EditorAction::PersistTtl => {
+                    this.persist_key(cx);
+                }
+                // Let the content view move the focus to the key tree search
+                _ => {
+                    cx.propagate();
+                }
+            }))
+            .on_action(cx.listener(|this, event: &ExpireCondition, _window, cx| {
+                this.expire_condition = *event;
+                cx.notify();
             }))
             .into_any_element()
     }
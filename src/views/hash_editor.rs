@@ -19,12 +19,16 @@
 //! - Viewing HASH field-value pairs in a two-column table
 //! - Adding new fields with values via a dialog form
 //! - Updating values of existing fields (inline editing)
+//! - Staging edits of several fields and saving them with one HSET
+//! - Editing JSON-valued fields in a full JSON editor dialog
 //! - Removing field-value pairs
+//! - Copying a field name or value to the clipboard
 //! - Filtering fields with pattern matching
 //! - Incremental loading of large HASHes with pagination
 
 use crate::{
     components::{FormDialog, FormField, ZedisKvFetcher, open_add_form_dialog},
+    helpers::pretty_json,
     states::{RedisValue, ZedisServerState, i18n_common, i18n_hash_editor},
     views::{KvTableColumn, ZedisKvTable},
 };
@@ -152,6 +156,63 @@ impl ZedisKvFetcher for ZedisHashValues {
         });
     }
 
+    /// Indicates that several field edits can be saved with one HSET.
+    fn can_batch_update(&self) -> bool {
+        true
+    }
+
+    /// Saves the staged field edits with a single HSET (HMSET before Redis 4.0).
+    ///
+    /// Each row's values are the field name and the new value, as in `handle_update_value`.
+    fn handle_batch_update_values(
+        &self,
+        rows: Vec<(SharedString, Vec<SharedString>)>,
+        _window: &mut Window,
+        cx: &mut App,
+    ) {
+        let new_values: Vec<(SharedString, SharedString)> = rows
+            .into_iter()
+            .filter_map(|(_, values)| Some((values.first()?.clone(), values.get(1)?.clone())))
+            .collect();
+
+        self.server_state.update(cx, |this, cx| {
+            this.update_hash_values(new_values, cx);
+        });
+    }
+
+    /// Returns the pretty-printed value of a field if it parses as JSON.
+    fn json_value(&self, row_ix: usize) -> Option<SharedString> {
+        let hash = self.value.hash_value()?;
+        let (_, value) = hash.values.get(row_ix)?;
+        pretty_json(value)
+    }
+
+    /// Saves the value edited in the JSON editor back to the field using Redis HSET.
+    fn handle_update_json_value(&self, row_ix: usize, value: SharedString, _window: &mut Window, cx: &mut App) {
+        let Some(hash) = self.value.hash_value() else {
+            return;
+        };
+        let Some((field, _)) = hash.values.get(row_ix).cloned() else {
+            return;
+        };
+
+        self.server_state.update(cx, |this, cx| {
+            this.update_hash_value(field, value, cx);
+        });
+    }
+
+    /// Returns the field name of a row for the copy button.
+    fn copy_field(&self, row_ix: usize) -> Option<SharedString> {
+        let hash = self.value.hash_value()?;
+        hash.values.get(row_ix).map(|(field, _)| field.clone())
+    }
+
+    /// Returns the value of a row for the copy button.
+    fn copy_value(&self, row_ix: usize) -> Option<SharedString> {
+        let hash = self.value.hash_value()?;
+        hash.values.get(row_ix).map(|(_, value)| value.clone())
+    }
+
     /// Opens a dialog to add a new field-value pair to the HASH.
     ///
     /// Creates a form with field and value input fields and handles submission
@@ -16,35 +16,140 @@ use crate::{
     assets::CustomIconName,
     components::{FormDialog, FormField, open_add_form_dialog},
     connection::QueryMode,
-    helpers::{EditorAction, validate_long_string, validate_ttl},
-    states::{KeyType, ServerEvent, ZedisGlobalStore, ZedisServerState, i18n_common, i18n_key_tree},
+    helpers::{
+        EditorAction, KeyTreeAction, fast_contains_ignore_case, humanize_keystroke, unix_ts, validate_long_string,
+        validate_ttl,
+    },
+    states::{
+        ImportValueMode, ImportValueRequest, KeyType, ServerEvent, ZedisGlobalStore, ZedisServerState, i18n_common,
+        i18n_key_tree, update_app_state_and_save,
+    },
 };
 use ahash::{AHashMap, AHashSet};
 use gpui::{
-    App, AppContext, Corner, Entity, Hsla, SharedString, Subscription, WeakEntity, Window, div, prelude::*, px,
+    Action, App, AppContext, Corner, Entity, Focusable, Hsla, PathPromptOptions, ScrollStrategy, SharedString,
+    Subscription, Task, WeakEntity, Window, div, prelude::*, px,
 };
 use gpui_component::IndexPath;
 use gpui_component::list::{List, ListDelegate, ListItem, ListState};
 use gpui_component::{
-    ActiveTheme, Disableable, Icon, IconName, StyledExt, WindowExt,
+    ActiveTheme, Disableable, Icon, IconName, Sizable, StyledExt, WindowExt,
     button::{Button, ButtonVariants, DropdownButton},
     h_flex,
     input::{Input, InputEvent, InputState},
     label::Label,
     v_flex,
 };
-use std::rc::Rc;
+use parking_lot::Mutex;
+use rust_i18n::t;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use std::{path::PathBuf, rc::Rc, sync::Arc, time::Duration};
 use tracing::info;
 
 // Constants for tree layout and behavior
 const TREE_INDENT_BASE: f32 = 16.0; // Base indentation per level in pixels
 const TREE_INDENT_OFFSET: f32 = 8.0; // Additional offset for all items
+const LIVE_FILTER_DEBOUNCE: Duration = Duration::from_millis(150); // Wait for typing to pause before filtering
 const EXPANDED_ITEMS_INITIAL_CAPACITY: usize = 10;
-const AUTO_EXPAND_THRESHOLD: usize = 100; // Auto-expand tree if fewer than this many keys
 const KEY_TYPE_FADE_ALPHA: f32 = 0.8; // Background transparency for key type badges
 const KEY_TYPE_BORDER_FADE_ALPHA: f32 = 0.5; // Border transparency for key type badges
 const STRIPE_BACKGROUND_ALPHA_DARK: f32 = 0.1; // Odd row background alpha for dark theme
 const STRIPE_BACKGROUND_ALPHA_LIGHT: f32 = 0.03; // Odd row background alpha for light theme
+const MAX_ENCODING_FETCH: usize = 500; // Max keys to fetch encodings for per tree rebuild
+
+/// Key encoding mode of the tree: hidden, shown for all keys, or shown and filtered by one encoding
+#[derive(Clone, Copy, Default, PartialEq, Debug, Deserialize, JsonSchema, Action)]
+enum KeyEncodingMode {
+    #[default]
+    Off,
+    All,
+    Listpack,
+    Ziplist,
+    Quicklist,
+    Intset,
+    Hashtable,
+    Skiplist,
+    Embstr,
+    Int,
+    Raw,
+    Stream,
+}
+
+impl KeyEncodingMode {
+    const FILTERS: [KeyEncodingMode; 10] = [
+        KeyEncodingMode::Listpack,
+        KeyEncodingMode::Ziplist,
+        KeyEncodingMode::Quicklist,
+        KeyEncodingMode::Intset,
+        KeyEncodingMode::Hashtable,
+        KeyEncodingMode::Skiplist,
+        KeyEncodingMode::Embstr,
+        KeyEncodingMode::Int,
+        KeyEncodingMode::Raw,
+        KeyEncodingMode::Stream,
+    ];
+    /// Returns the encoding to filter by, None if not filtering
+    fn encoding(&self) -> Option<&'static str> {
+        match self {
+            KeyEncodingMode::Off | KeyEncodingMode::All => None,
+            KeyEncodingMode::Listpack => Some("listpack"),
+            KeyEncodingMode::Ziplist => Some("ziplist"),
+            KeyEncodingMode::Quicklist => Some("quicklist"),
+            KeyEncodingMode::Intset => Some("intset"),
+            KeyEncodingMode::Hashtable => Some("hashtable"),
+            KeyEncodingMode::Skiplist => Some("skiplist"),
+            KeyEncodingMode::Embstr => Some("embstr"),
+            KeyEncodingMode::Int => Some("int"),
+            KeyEncodingMode::Raw => Some("raw"),
+            KeyEncodingMode::Stream => Some("stream"),
+        }
+    }
+}
+
+/// How the keyword is put into the SCAN MATCH pattern: escaped to match literally, or as a raw glob
+#[derive(Clone, Copy, Default, PartialEq, Debug, Deserialize, JsonSchema, Action)]
+enum KeywordGlobMode {
+    #[default]
+    Literal,
+    Raw,
+}
+
+/// Key type filter of the scan, sent as SCAN TYPE
+#[derive(Clone, Copy, Default, PartialEq, Debug, Deserialize, JsonSchema, Action)]
+enum KeyTypeFilter {
+    #[default]
+    All,
+    String,
+    List,
+    Set,
+    Zset,
+    Hash,
+    Stream,
+}
+
+impl KeyTypeFilter {
+    const FILTERS: [KeyTypeFilter; 6] = [
+        KeyTypeFilter::String,
+        KeyTypeFilter::List,
+        KeyTypeFilter::Set,
+        KeyTypeFilter::Zset,
+        KeyTypeFilter::Hash,
+        KeyTypeFilter::Stream,
+    ];
+    /// Returns the key type to scan for, None if not filtering
+    fn key_type(&self) -> Option<KeyType> {
+        match self {
+            KeyTypeFilter::All => None,
+            KeyTypeFilter::String => Some(KeyType::String),
+            KeyTypeFilter::List => Some(KeyType::List),
+            KeyTypeFilter::Set => Some(KeyType::Set),
+            KeyTypeFilter::Zset => Some(KeyType::Zset),
+            KeyTypeFilter::Hash => Some(KeyType::Hash),
+            KeyTypeFilter::Stream => Some(KeyType::Stream),
+        }
+    }
+}
 
 #[derive(Default)]
 struct KeyTreeState {
@@ -57,8 +162,20 @@ struct KeyTreeState {
     query_mode: QueryMode,
     /// Error message to display if key loading fails
     error: Option<SharedString>,
-    /// Set of expanded folder paths (persisted during tree rebuilds)
+    /// Set of expanded folder paths (persisted during tree rebuilds and per server)
     expanded_items: AHashSet<SharedString>,
+    /// Whether the remembered folders still need to be scanned once the server is ready
+    restore_pending: bool,
+    /// Whether to show key encodings and which encoding to filter by
+    encoding_mode: KeyEncodingMode,
+    /// Key to select and scroll to once it appears in the tree
+    pending_reveal: Option<SharedString>,
+    /// Lazily converted tree of the loaded keys, reused while the keys are unchanged
+    index: Arc<Mutex<KeyTreeIndex>>,
+    /// Keys, type filter and depth the index was built for
+    index_id: SharedString,
+    /// Lowercase keyword the loaded keys are filtered by while typing
+    live_filter: SharedString,
 }
 
 #[derive(Default, Debug, Clone)]
@@ -72,98 +189,134 @@ struct KeyTreeItem {
     is_folder: bool,
 }
 
-fn new_key_tree_items(
-    mut keys: Vec<(SharedString, KeyType)>,
-    expand_all: bool,
-    expanded_items: AHashSet<SharedString>,
-    max_key_tree_depth: usize,
-) -> Vec<KeyTreeItem> {
-    keys.sort_unstable_by_key(|(k, _)| k.clone());
-    let expanded_items_set = expanded_items.iter().map(|s| s.as_str()).collect::<AHashSet<&str>>();
-    let mut items: AHashMap<SharedString, KeyTreeItem> = AHashMap::with_capacity(100);
-
-    let split_char = ":";
-
-    for (key, key_type) in keys {
-        // no colon in the key, it's a simple key
-        if !key.contains(split_char) {
-            items.insert(
-                key.clone(),
-                KeyTreeItem {
-                    id: key.clone(),
-                    label: key.clone(),
-                    key_type,
-                    ..Default::default()
-                },
-            );
-            continue;
-        }
+/// Index of the key tree, converted to tree items lazily
+///
+/// The keys are only grouped into the children of a folder the first time the
+/// folder is shown, and the sorted children are cached per folder. Expanding or
+/// collapsing a folder walks the visible items only, instead of every loaded key.
+#[derive(Default)]
+struct KeyTreeIndex {
+    /// Loaded keys the index is built from
+    keys: Vec<(SharedString, KeyType)>,
+    /// Max levels of the tree, the last level keeps the rest of the key
+    max_depth: usize,
+    /// Sorted children of the folders shown so far, the root is ""
+    ///
+    /// Folder ids end with the separator, e.g. `user:`, so they never equal the id
+    /// of a key with the same name and are the SCAN prefix of the folder.
+    children: AHashMap<SharedString, Vec<KeyTreeItem>>,
+    /// Keys (as index of `keys`) below the folders whose children are not built yet
+    pending: AHashMap<SharedString, Vec<u32>>,
+}
 
-        let mut dir = String::with_capacity(50);
-        let mut key_tree_item: Option<KeyTreeItem> = None;
-        // max levels of depth
-        for (index, k) in key.splitn(max_key_tree_depth, split_char).enumerate() {
-            // if key_tre_item is not None, it means we are in a folder
-            // because it's not the last part of the key
-            if let Some(key_tree_item) = key_tree_item.take() {
-                let entry = items.entry(key_tree_item.id.clone()).or_insert_with(|| key_tree_item);
-                entry.is_folder = true;
-                entry.children_count += 1;
-            }
+impl KeyTreeIndex {
+    fn new(keys: Vec<(SharedString, KeyType)>, max_depth: usize) -> Self {
+        let mut pending = AHashMap::new();
+        pending.insert(SharedString::default(), (0..keys.len() as u32).collect());
+        Self {
+            keys,
+            max_depth: max_depth.max(1),
+            children: AHashMap::new(),
+            pending,
+        }
+    }
 
-            let expanded = expand_all || index == 0 || expanded_items_set.contains(dir.as_str());
-            if !expanded {
-                break;
-            }
-            let name: SharedString = k.to_string().into();
-            if index != 0 {
-                dir.push_str(split_char);
+    /// Groups the keys below the folder into its sorted children, only done once per folder
+    fn build_children(&mut self, folder: &SharedString, depth: usize) {
+        let Some(members) = self.pending.remove(folder) else {
+            return;
+        };
+        let split_char = ':';
+        let prefix_len = folder.len();
+        let mut folders: AHashMap<SharedString, (KeyTreeItem, Vec<u32>)> = AHashMap::new();
+        let mut items = Vec::new();
+        for index in members {
+            let (key, key_type) = &self.keys[index as usize];
+            let rest = &key[prefix_len..];
+            // The last level keeps the rest of the key as label
+            let name = if depth + 1 < self.max_depth {
+                rest.split_once(split_char).map(|(name, _)| name)
+            } else {
+                None
             };
-            dir.push_str(k);
-
-            key_tree_item = Some(KeyTreeItem {
-                id: dir.clone().into(),
-                label: name.clone(),
-                key_type,
-                depth: index,
-                expanded,
+            let Some(name) = name else {
+                items.push(KeyTreeItem {
+                    id: key.clone(),
+                    label: rest.to_string().into(),
+                    depth,
+                    key_type: *key_type,
+                    ..Default::default()
+                });
+                continue;
+            };
+            // Only allocate the id for the first key of a folder
+            let id = &key[..prefix_len + name.len() + split_char.len_utf8()];
+            if let Some((item, folder_members)) = folders.get_mut(id) {
+                item.children_count += 1;
+                folder_members.push(index);
+                continue;
+            }
+            let id: SharedString = id.to_string().into();
+            let item = KeyTreeItem {
+                id: id.clone(),
+                label: name.to_string().into(),
+                depth,
+                key_type: *key_type,
+                children_count: 1,
+                is_folder: true,
                 ..Default::default()
-            });
+            };
+            folders.insert(id, (item, vec![index]));
         }
-        if let Some(key_tree_item) = key_tree_item.take() {
-            items.insert(key_tree_item.id.clone(), key_tree_item);
+        items.reserve(folders.len());
+        for (id, (item, folder_members)) in folders {
+            self.pending.insert(id, folder_members);
+            items.push(item);
         }
+        // Folders first, then sorted by name
+        items.sort_unstable_by(|a, b| b.is_folder.cmp(&a.is_folder).then_with(|| a.label.cmp(&b.label)));
+        self.children.insert(folder.clone(), items);
     }
 
-    let mut children_map: AHashMap<String, Vec<KeyTreeItem>> = AHashMap::new();
-
-    let mut result = Vec::with_capacity(items.len());
-
-    for item in items.into_values() {
-        let size = item.id.len() - item.label.len();
-        let parent_id = if size == 0 { "" } else { &item.id[..(size - 1)] };
-        children_map.entry(parent_id.to_string()).or_default().push(item);
+    /// Returns the visible items, the children of expanded folders follow their folder
+    fn visible_items(&mut self, expand_all: bool, expanded_items: &AHashSet<SharedString>) -> Vec<KeyTreeItem> {
+        let mut result = Vec::new();
+        self.push_children(&SharedString::default(), 0, expand_all, expanded_items, &mut result);
+        result
     }
 
-    fn build_sorted_list(parent_id: &str, map: &mut AHashMap<String, Vec<KeyTreeItem>>, result: &mut Vec<KeyTreeItem>) {
-        if let Some(mut children) = map.remove(parent_id) {
-            children.sort_unstable_by(|a, b| b.is_folder.cmp(&a.is_folder).then_with(|| a.label.cmp(&b.label)));
-
-            for child in children {
-                let child_id = child.id.to_string();
-                result.push(child);
-                build_sorted_list(&child_id, map, result);
+    fn push_children(
+        &mut self,
+        folder: &SharedString,
+        depth: usize,
+        expand_all: bool,
+        expanded_items: &AHashSet<SharedString>,
+        result: &mut Vec<KeyTreeItem>,
+    ) {
+        self.build_children(folder, depth);
+        let Some(children) = self.children.get(folder).cloned() else {
+            return;
+        };
+        for mut item in children {
+            item.expanded = item.is_folder && (expand_all || expanded_items.contains(&item.id));
+            let expanded = item.expanded;
+            let id = item.id.clone();
+            result.push(item);
+            if expanded {
+                self.push_children(&id, depth + 1, expand_all, expanded_items, result);
             }
         }
     }
-
-    build_sorted_list("", &mut children_map, &mut result);
-
-    result
 }
 
 struct KeyTreeDelegate {
     items: Vec<KeyTreeItem>,
+    /// Expiration time (unix seconds) of keys with a TTL
+    key_expire_ats: AHashMap<SharedString, i64>,
+    /// Internal encoding of keys, empty unless the encoding mode is enabled
+    key_encodings: AHashMap<SharedString, SharedString>,
+    /// Folder prefixes (e.g. `user:`) whose key types failed to resolve
+    key_type_failed_prefixes: AHashSet<SharedString>,
     selected_index: Option<IndexPath>,
     parent: WeakEntity<ZedisKeyTree>,
 }
@@ -191,6 +344,18 @@ impl KeyTreeDelegate {
             .border_color(border)
             .into_any_element()
     }
+    /// Renders a short remaining TTL hint (e.g. "3h") for keys with an expiration
+    fn render_ttl_hint(&self, key: &SharedString, cx: &App) -> impl IntoElement {
+        let Some(expire_at) = self.key_expire_ats.get(key) else {
+            return div().into_any_element();
+        };
+        let remaining = expire_at.saturating_sub(unix_ts()).max(0);
+        let ttl = humantime::format_duration(Duration::from_secs(remaining as u64)).to_string();
+        Label::new(ttl.split_whitespace().next().unwrap_or_default().to_string())
+            .text_xs()
+            .text_color(cx.theme().muted_foreground)
+            .into_any_element()
+    }
 }
 
 impl ListDelegate for KeyTreeDelegate {
@@ -228,16 +393,54 @@ impl ListDelegate for KeyTreeDelegate {
             Hsla::black().alpha(STRIPE_BACKGROUND_ALPHA_LIGHT)
         };
 
-        // Show child count for folders
+        // Show encoding annotation for keys when enabled
+        let encoding_label = match self.key_encodings.get(&entry.id) {
+            Some(encoding) if !entry.is_folder && !encoding.is_empty() => Label::new(encoding.clone())
+                .text_xs()
+                .text_color(cx.theme().muted_foreground)
+                .into_any_element(),
+            _ => div().into_any_element(),
+        };
+
+        // Retry resolving the key types of a folder after TYPE failed
+        let folder_prefix = entry.id.clone();
+        let retry_btn = if entry.is_folder && self.key_type_failed_prefixes.contains(&folder_prefix) {
+            let parent = self.parent.clone();
+            Button::new(("key-tree-retry-types", ix.row))
+                .ghost()
+                .xsmall()
+                .tooltip(i18n_key_tree(cx, "retry_key_types_tooltip"))
+                .icon(Icon::new(CustomIconName::RotateCw).text_color(cx.theme().red))
+                .on_click(move |_, _window, cx| {
+                    cx.stop_propagation();
+                    let prefix = folder_prefix.clone();
+                    let _ = parent.update(cx, move |view: &mut ZedisKeyTree, cx| {
+                        view.server_state.update(cx, |state, cx| {
+                            state.retry_key_types(prefix, cx);
+                        });
+                    });
+                })
+                .into_any_element()
+        } else {
+            div().into_any_element()
+        };
+
+        // Show child count for folders and TTL hint for keys
         let count_label = if entry.is_folder {
             Label::new(entry.children_count.to_string())
                 .text_sm()
                 .text_color(cx.theme().muted_foreground)
+                .into_any_element()
         } else {
-            Label::new("")
+            self.render_ttl_hint(&entry.id, cx).into_any_element()
         };
 
-        let bg = if ix.row.is_multiple_of(2) { even_bg } else { odd_bg };
+        let zebra_stripes = cx.global::<ZedisGlobalStore>().read(cx).zebra_stripes();
+        let bg = if !zebra_stripes || ix.row.is_multiple_of(2) {
+            even_bg
+        } else {
+            odd_bg
+        };
 
         let parent = self.parent.clone();
         let id = entry.id.clone();
@@ -246,6 +449,7 @@ impl ListDelegate for KeyTreeDelegate {
             ListItem::new(ix)
                 .w_full()
                 .bg(bg)
+                .selected(self.selected_index == Some(ix))
                 .py_1()
                 .px_2()
                 .pl(px(TREE_INDENT_BASE) * entry.depth + px(TREE_INDENT_OFFSET))
@@ -254,11 +458,18 @@ impl ListDelegate for KeyTreeDelegate {
                         .gap_2()
                         .child(icon)
                         .child(div().flex_1().text_ellipsis().child(entry.label.clone()))
+                        .child(encoding_label)
+                        .child(retry_btn)
                         .child(count_label),
                 )
-                .on_click(move |_, _window, cx| {
+                .on_click(move |_, window, cx| {
                     let id = id.clone();
                     let _ = parent.update(cx, move |view: &mut ZedisKeyTree, cx| {
+                        // Keep the keyboard selection on the clicked row
+                        view.key_tree_list_state.update(cx, |state, cx| {
+                            window.focus(&state.focus_handle(cx));
+                            state.set_selected_index(Some(ix), window, cx);
+                        });
                         view.select_item(id, is_folder, cx);
                     });
                 }),
@@ -268,6 +479,21 @@ impl ListDelegate for KeyTreeDelegate {
     fn set_selected_index(&mut self, ix: Option<IndexPath>, _window: &mut Window, _cx: &mut Context<ListState<Self>>) {
         self.selected_index = ix;
     }
+
+    fn confirm(&mut self, _secondary: bool, _window: &mut Window, cx: &mut Context<ListState<Self>>) {
+        let Some(item) = self.selected_index.and_then(|ix| self.items.get(ix.row)) else {
+            return;
+        };
+        let id = item.id.clone();
+        let is_folder = item.is_folder;
+        let parent = self.parent.clone();
+        // The tree rebuild updates the list state, which is borrowed here
+        cx.defer(move |cx| {
+            let _ = parent.update(cx, move |view: &mut ZedisKeyTree, cx| {
+                view.select_item(id, is_folder, cx);
+            });
+        });
+    }
 }
 
 /// Key tree view component for browsing and filtering Redis keys
@@ -279,6 +505,7 @@ impl ListDelegate for KeyTreeDelegate {
 /// - Real-time filtering and search
 /// - Expandable/collapsible folders
 /// - Visual feedback for selected keys
+/// - Arrow keys and Enter to navigate while the tree is focused
 pub struct ZedisKeyTree {
     state: KeyTreeState,
 
@@ -294,6 +521,9 @@ pub struct ZedisKeyTree {
     /// Input field state for keyword filtering
     keyword_state: Entity<InputState>,
 
+    /// Debounced update of the live filter, replaced on each keystroke
+    live_filter_task: Option<Task<()>>,
+
     /// Event subscriptions for reactive updates
     _subscriptions: Vec<Subscription>,
 }
@@ -308,14 +538,31 @@ impl ZedisKeyTree {
 
         // Subscribe to server state changes to rebuild tree when keys change
         subscriptions.push(cx.observe(&server_state, |this, _model, cx| {
+            this.restore_expanded_items(cx);
             this.update_key_tree(false, cx);
         }));
-        subscriptions.push(cx.subscribe(&server_state, |this, _server_state, event, cx| {
-            if let ServerEvent::KeyCollapseAll = event {
-                this.state.expanded_items.clear();
-                this.update_key_tree(true, cx);
-            }
-        }));
+        subscriptions.push(
+            cx.subscribe(&server_state, |this, _server_state, event, cx| match event {
+                ServerEvent::ServerSelected(server_id) => {
+                    this.state.expanded_items = cx
+                        .global::<ZedisGlobalStore>()
+                        .read(cx)
+                        .expanded_folders(server_id)
+                        .into_iter()
+                        .collect();
+                    this.state.restore_pending = true;
+                }
+                ServerEvent::KeyCollapseAll => {
+                    this.state.expanded_items.clear();
+                    this.save_expanded_items(cx);
+                    this.update_key_tree(true, cx);
+                }
+                ServerEvent::KeyRevealed(key) => {
+                    this.reveal_key(key.clone(), cx);
+                }
+                _ => {}
+            }),
+        );
 
         // Initialize keyword search input with placeholder
         let keyword_state = cx.new(|cx| {
@@ -333,45 +580,92 @@ impl ZedisKeyTree {
         let query_mode = server_state_value.query_mode();
 
         // Subscribe to search input events (Enter key triggers filter)
-        subscriptions.push(cx.subscribe_in(&keyword_state, window, |view, _, event, _, cx| {
-            if let InputEvent::PressEnter { .. } = &event {
-                view.handle_filter(cx);
-            }
-        }));
+        subscriptions.push(
+            cx.subscribe_in(&keyword_state, window, |view, _, event, _, cx| match event {
+                InputEvent::PressEnter { .. } => {
+                    view.handle_filter(cx);
+                }
+                // Keep the MATCH pattern hint and the live filter in sync with the keyword
+                InputEvent::Change => {
+                    view.schedule_live_filter(cx);
+                    cx.notify();
+                }
+                _ => {}
+            }),
+        );
 
         info!(server_id, "Creating new key tree view");
 
         let delegate = KeyTreeDelegate {
             items: Vec::new(),
+            key_expire_ats: AHashMap::new(),
+            key_encodings: AHashMap::new(),
+            key_type_failed_prefixes: AHashSet::new(),
             selected_index: None,
             parent: cx.entity().downgrade(),
         };
 
+        // Restore the folders expanded the last time the server was opened
+        let mut expanded_items = AHashSet::with_capacity(EXPANDED_ITEMS_INITIAL_CAPACITY);
+        expanded_items.extend(cx.global::<ZedisGlobalStore>().read(cx).expanded_folders(&server_id));
+
         let mut this = Self {
             state: KeyTreeState {
                 query_mode,
                 server_id: server_id.into(),
-                expanded_items: AHashSet::with_capacity(EXPANDED_ITEMS_INITIAL_CAPACITY),
+                expanded_items,
+                restore_pending: true,
                 ..Default::default()
             },
             key_tree_list_state: cx.new(|cx| ListState::new(delegate, window, cx)),
             keyword_state,
             server_state,
+            live_filter_task: None,
             _subscriptions: subscriptions,
         };
 
         // Initial tree build
+        this.restore_expanded_items(cx);
         this.update_key_tree(true, cx);
 
         this
     }
 
+    /// Scan the remembered folders once the server is connected, so their keys load lazily
+    fn restore_expanded_items(&mut self, cx: &mut Context<Self>) {
+        if !self.state.restore_pending || self.server_state.read(cx).is_busy() {
+            return;
+        }
+        self.state.restore_pending = false;
+        let folders: Vec<SharedString> = self.state.expanded_items.iter().cloned().collect();
+        if folders.is_empty() {
+            return;
+        }
+        self.server_state.update(cx, |state, cx| {
+            for folder in folders {
+                state.scan_prefix(folder, cx);
+            }
+        });
+    }
+
+    /// Persist the expanded folders of the current server
+    fn save_expanded_items(&self, cx: &mut Context<Self>) {
+        let server_id = self.server_state.read(cx).server_id().to_string();
+        if server_id.is_empty() {
+            return;
+        }
+        let folders: Vec<SharedString> = self.state.expanded_items.iter().cloned().collect();
+        update_app_state_and_save(cx, "save_expanded_folders", move |state, _cx| {
+            state.set_expanded_folders(&server_id, folders.clone());
+        });
+    }
+
     /// Update the key tree structure when server state changes
     ///
     /// Rebuilds the tree only if the tree ID has changed (indicating new keys loaded).
     /// Preserves expanded folder state across rebuilds. Auto-expands all folders
     /// if the total key count is below the threshold.
-    fn update_key_tree(&mut self, force_update: bool, cx: &mut Context<Self>) {
+    fn update_key_tree(&mut self, mut force_update: bool, cx: &mut Context<Self>) {
         let server_state = self.server_state.read(cx);
         let key_tree_id = server_state.key_tree_id();
 
@@ -381,7 +675,16 @@ impl ZedisKeyTree {
             "Server state updated"
         );
 
-        self.state.query_mode = server_state.query_mode();
+        // The keyword of the live filter was typed for the previous query mode
+        let query_mode = server_state.query_mode();
+        if query_mode != self.state.query_mode {
+            self.live_filter_task = None;
+            if !self.state.live_filter.is_empty() {
+                self.state.live_filter = SharedString::default();
+                force_update = true;
+            }
+        }
+        self.state.query_mode = query_mode;
 
         // Skip rebuild if tree ID hasn't changed (same keys)
         if !force_update && self.state.key_tree_id == key_tree_id {
@@ -390,32 +693,163 @@ impl ZedisKeyTree {
         self.state.key_tree_id = key_tree_id.to_string().into();
 
         // Auto-expand all folders if key count is small
-        let expand_all = server_state.scan_count() < AUTO_EXPAND_THRESHOLD;
-        let keys_snapshot: Vec<(SharedString, KeyType)> =
-            server_state.keys().iter().map(|(k, v)| (k.clone(), *v)).collect();
+        let auto_expand_threshold = cx.global::<ZedisGlobalStore>().read(cx).auto_expand_threshold();
+        let expand_all = server_state.scan_count() < auto_expand_threshold;
+        // SCAN TYPE already returns matching keys only, servers older than 6.0
+        // scan all keys and the ones of other types are hidden once resolved
+        let scan_type = server_state.scan_type();
+        let max_key_tree_depth = cx.global::<ZedisGlobalStore>().read(cx).max_key_tree_depth();
+        // Only copy the keys if they changed, expanding a folder reuses the index
+        let live_filter = self.state.live_filter.clone();
+        let index_id: SharedString = format!("{key_tree_id}:{scan_type:?}:{max_key_tree_depth}:{live_filter}").into();
+        let keys_snapshot: Option<Vec<(SharedString, KeyType)>> = (self.state.index_id != index_id).then(|| {
+            server_state
+                .keys()
+                .iter()
+                .filter(|(_, v)| scan_type.is_none_or(|t| **v == t || **v == KeyType::Unknown))
+                .filter(|(k, _)| live_filter.is_empty() || fast_contains_ignore_case(k, &live_filter))
+                .map(|(k, v)| (k.clone(), *v))
+                .collect()
+        });
+        self.state.index_id = index_id;
+        let index = self.state.index.clone();
         let expanded_items = self.state.expanded_items.clone();
+        let key_expire_ats = server_state.key_expire_ats().clone();
+        let key_type_failed_prefixes = server_state.key_type_failed_prefixes().clone();
+        let encoding_mode = self.state.encoding_mode;
+        let key_encodings = if encoding_mode == KeyEncodingMode::Off {
+            AHashMap::new()
+        } else {
+            server_state.key_encodings().clone()
+        };
+        let view = cx.entity().downgrade();
 
         self.key_tree_list_state.update(cx, move |_state, cx| {
-            let max_key_tree_depth = cx.global::<ZedisGlobalStore>().value(cx).max_key_tree_depth();
             cx.spawn(async move |handle, cx| {
+                let encodings = key_encodings.clone();
                 let task = cx.background_spawn(async move {
                     let start = std::time::Instant::now();
-                    let items = new_key_tree_items(keys_snapshot, expand_all, expanded_items, max_key_tree_depth);
+                    let mut index = index.lock();
+                    if let Some(keys) = keys_snapshot {
+                        *index = KeyTreeIndex::new(keys, max_key_tree_depth);
+                    }
+                    let mut items = index.visible_items(expand_all, &expanded_items);
+                    drop(index);
                     tracing::debug!("Key tree build time: {:?}", start.elapsed());
-                    items
+                    if encoding_mode == KeyEncodingMode::Off {
+                        return (items, vec![]);
+                    }
+                    // Only fetch encodings of keys shown in the tree (expanded levels)
+                    let missing: Vec<SharedString> = items
+                        .iter()
+                        .filter(|item| !item.is_folder && !encodings.contains_key(&item.id))
+                        .map(|item| item.id.clone())
+                        .take(MAX_ENCODING_FETCH)
+                        .collect();
+                    if let Some(encoding) = encoding_mode.encoding() {
+                        items.retain(|item| {
+                            item.is_folder || encodings.get(&item.id).is_some_and(|value| value == encoding)
+                        });
+                    }
+                    (items, missing)
                 });
 
-                let result = task.await;
+                let (items, missing) = task.await;
 
                 handle.update(cx, |this, cx| {
-                    this.delegate_mut().items = result;
+                    this.delegate_mut().items = items;
+                    this.delegate_mut().key_expire_ats = key_expire_ats;
+                    this.delegate_mut().key_encodings = key_encodings;
+                    this.delegate_mut().key_type_failed_prefixes = key_type_failed_prefixes;
                     cx.notify();
-                })
+                })?;
+                view.update(cx, |this, cx| {
+                    // Re-render to scroll to the revealed key
+                    if this.state.pending_reveal.is_some() {
+                        cx.notify();
+                    }
+                    if !missing.is_empty() {
+                        this.server_state.update(cx, |state, cx| {
+                            state.fill_key_encodings(missing, cx);
+                        });
+                    }
+                })?;
+                anyhow::Ok(())
             })
             .detach();
         });
     }
 
+    /// Count the keys matching the current keyword without loading them
+    fn handle_count_keys(&mut self, cx: &mut Context<Self>) {
+        let keyword = self.keyword_state.read(cx).value();
+        self.server_state.update(cx, move |handle, cx| {
+            handle.count_keys(keyword, cx);
+        });
+    }
+
+    /// Loaded keys shown by the tree: the type filter, the live filter and the encoding filter are applied
+    fn visible_keys(&self, cx: &App) -> Vec<SharedString> {
+        let server_state = self.server_state.read(cx);
+        let scan_type = server_state.scan_type();
+        let live_filter = &self.state.live_filter;
+        let encoding = self.state.encoding_mode.encoding();
+        let key_encodings = server_state.key_encodings();
+        server_state
+            .keys()
+            .iter()
+            .filter(|(_, v)| scan_type.is_none_or(|t| **v == t || **v == KeyType::Unknown))
+            .filter(|(k, _)| live_filter.is_empty() || fast_contains_ignore_case(k, live_filter))
+            .filter(|(k, _)| encoding.is_none_or(|encoding| key_encodings.get(*k).is_some_and(|v| v == encoding)))
+            .map(|(k, _)| k.clone())
+            .collect()
+    }
+
+    /// Delete the loaded keys shown by the tree after confirmation
+    ///
+    /// Keys not scanned yet or hidden by a filter are kept, the prompt shows how many keys are deleted.
+    fn handle_delete_matching(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let keys = self.visible_keys(cx);
+        let count = keys.len();
+        if count == 0 {
+            return;
+        }
+        let pattern = self.server_state.read(cx).scan_pattern().clone();
+        let server_state = self.server_state.clone();
+        window.open_dialog(cx, move |dialog, _, cx| {
+            let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+            let message = t!(
+                "key_tree.delete_matching_prompt",
+                count = count,
+                pattern = pattern,
+                locale = locale
+            )
+            .to_string();
+            let server_state = server_state.clone();
+            let keys = keys.clone();
+            dialog
+                .confirm()
+                .title(i18n_key_tree(cx, "delete_matching_title"))
+                .child(message)
+                .on_ok(move |_, window, cx| {
+                    let keys = keys.clone();
+                    server_state.update(cx, |state, cx| {
+                        state.delete_keys_matching(keys, cx);
+                    });
+                    window.close_dialog(cx);
+                    true
+                })
+        });
+    }
+
+    /// Preview the first page of the scan for the current keyword
+    fn handle_preview(&mut self, cx: &mut Context<Self>) {
+        let keyword = self.keyword_state.read(cx).value();
+        self.server_state.update(cx, move |handle, cx| {
+            handle.preview_scan(keyword, cx);
+        });
+    }
+
     /// Handle filter/search action when user submits keyword
     ///
     /// Delegates to server state to perform the actual filtering based on
@@ -432,8 +866,160 @@ impl ZedisKeyTree {
         });
     }
 
+    /// Import a key from a JSON file written by the editor export
+    fn handle_import_key(&mut self, cx: &mut Context<Self>) {
+        let paths = cx.prompt_for_paths(PathPromptOptions {
+            files: true,
+            directories: false,
+            multiple: false,
+            prompt: None,
+        });
+        let server_state = self.server_state.clone();
+        cx.spawn(async move |_this, cx| {
+            let Ok(Ok(Some(paths))) = paths.await else {
+                return;
+            };
+            let Some(path) = paths.into_iter().next() else {
+                return;
+            };
+            let _ = server_state.update(cx, |state, cx| {
+                state.import_key(path, cx);
+            });
+        })
+        .detach();
+    }
+
+    /// Import a local file as the value of a key
+    fn handle_import_value(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let paths = cx.prompt_for_paths(PathPromptOptions {
+            files: true,
+            directories: false,
+            multiple: false,
+            prompt: None,
+        });
+        cx.spawn_in(window, async move |this, cx| {
+            let Ok(Ok(Some(paths))) = paths.await else {
+                return;
+            };
+            let Some(path) = paths.into_iter().next() else {
+                return;
+            };
+            let _ = this.update_in(cx, |this, window, cx| {
+                this.open_import_value_dialog(path, window, cx);
+            });
+        })
+        .detach();
+    }
+
+    /// Choose the key and how the file is written, the file name is the default key
+    fn open_import_value_dialog(&mut self, path: PathBuf, window: &mut Window, cx: &mut Context<Self>) {
+        let modes = [ImportValueMode::String, ImportValueMode::List, ImportValueMode::Set];
+        let key = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let fields = vec![
+            FormField::new(i18n_key_tree(cx, "import_value_mode")).with_options(vec![
+                i18n_key_tree(cx, "import_value_mode_string"),
+                i18n_key_tree(cx, "import_value_mode_list"),
+                i18n_key_tree(cx, "import_value_mode_set"),
+            ]),
+            FormField::new(i18n_common(cx, "key"))
+                .with_placeholder(i18n_common(cx, "key_placeholder"))
+                .with_value(key.into())
+                .with_focus()
+                .with_validate(validate_long_string),
+        ];
+        let server_state = self.server_state.clone();
+        let handle_submit = Rc::new(move |values: Vec<SharedString>, window: &mut Window, cx: &mut App| {
+            if values.len() != 2 {
+                return false;
+            }
+            let key: SharedString = values[1].trim().to_string().into();
+            if key.is_empty() {
+                return false;
+            }
+            let mode = values[0]
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| modes.get(index))
+                .copied()
+                .unwrap_or_default();
+            let request = ImportValueRequest {
+                key,
+                path: path.clone(),
+                mode,
+            };
+            server_state.update(cx, |state, cx| {
+                state.import_value(request, false, cx);
+            });
+            window.close_dialog(cx);
+            true
+        });
+        open_add_form_dialog(
+            FormDialog {
+                title: i18n_key_tree(cx, "import_value_title"),
+                fields,
+                handle_submit,
+            },
+            window,
+            cx,
+        );
+    }
+
+    /// Focus the keyword search input
+    pub fn focus_keyword(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.keyword_state.update(cx, |state, cx| {
+            state.focus(window, cx);
+        });
+    }
+
+    /// Filter the loaded keys by the keyword once typing pauses
+    ///
+    /// Only the keys in memory are filtered, Enter still runs the SCAN for keys
+    /// not loaded yet. Exact mode reads a single key, so it is not filtered.
+    fn schedule_live_filter(&mut self, cx: &mut Context<Self>) {
+        let enabled = cx.global::<ZedisGlobalStore>().read(cx).live_filter();
+        if !enabled || self.state.query_mode == QueryMode::Exact {
+            self.live_filter_task = None;
+            self.set_live_filter(SharedString::default(), cx);
+            return;
+        }
+        let keyword: SharedString = self.keyword_state.read(cx).value().trim().to_lowercase().into();
+        self.live_filter_task = Some(cx.spawn(async move |this, cx| {
+            cx.background_executor().timer(LIVE_FILTER_DEBOUNCE).await;
+            let _ = this.update(cx, |this, cx| {
+                this.set_live_filter(keyword, cx);
+            });
+        }));
+    }
+
+    fn set_live_filter(&mut self, keyword: SharedString, cx: &mut Context<Self>) {
+        if self.state.live_filter == keyword {
+            return;
+        }
+        self.state.live_filter = keyword;
+        self.update_key_tree(true, cx);
+    }
+
+    /// Clear the keyword and go back to the full keyspace
+    fn handle_reset_filter(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        // Don't reset while scanning, the running scan would refill the tree
+        if self.server_state.read(cx).scaning() {
+            return;
+        }
+        self.keyword_state.update(cx, |state, cx| {
+            state.set_value(SharedString::default(), window, cx);
+        });
+        self.live_filter_task = None;
+        self.set_live_filter(SharedString::default(), cx);
+        self.server_state.update(cx, |handle, cx| {
+            handle.reset_filter(cx);
+        });
+    }
+
     fn handle_add_key(&mut self, window: &mut Window, cx: &mut Context<Self>) {
-        let category_list = ["String", "List", "Set", "Zset", "Hash"];
+        let category_list = ["String", "List", "Set", "Zset", "Hash", "Stream"];
         let fields = vec![
             FormField::new(i18n_key_tree(cx, "category"))
                 .with_options(category_list.iter().map(|s| s.to_string().into()).collect()),
@@ -477,14 +1063,24 @@ impl ZedisKeyTree {
         if server_state.scaning() {
             return None;
         }
-        if !self.state.is_empty && self.state.error.is_none() {
+        let unsupported_value = server_state
+            .value()
+            .filter(|value| self.state.query_mode == QueryMode::Exact && value.is_unsupported());
+        if !self.state.is_empty && self.state.error.is_none() && unsupported_value.is_none() {
             return None;
         }
 
         let mut text = SharedString::default();
 
         if self.state.query_mode == QueryMode::Exact {
-            if let Some(value) = server_state.value()
+            if let Some(value) = unsupported_value {
+                text = t!(
+                    "key_tree.unsupported_type",
+                    key_type = value.key_type().name(),
+                    locale = cx.global::<ZedisGlobalStore>().read(cx).locale()
+                )
+                .into();
+            } else if let Some(value) = server_state.value()
                 && value.is_expired()
             {
                 text = i18n_key_tree(cx, "key_not_exists");
@@ -519,6 +1115,49 @@ impl ZedisKeyTree {
         )
     }
 
+    /// Expand all folders containing the key and scroll to it once the tree is rebuilt
+    fn reveal_key(&mut self, key: SharedString, cx: &mut Context<Self>) {
+        let max_key_tree_depth = cx.global::<ZedisGlobalStore>().read(cx).max_key_tree_depth();
+        let parts: Vec<&str> = key.splitn(max_key_tree_depth, ":").collect();
+        let mut folder = String::new();
+        for part in parts.iter().take(parts.len().saturating_sub(1)) {
+            folder.push_str(part);
+            folder.push(':');
+            let folder: SharedString = folder.clone().into();
+            if self.state.expanded_items.insert(folder.clone()) {
+                self.server_state.update(cx, |state, cx| {
+                    state.scan_prefix(folder, cx);
+                });
+            }
+        }
+        self.save_expanded_items(cx);
+        self.state.pending_reveal = Some(key);
+        self.update_key_tree(true, cx);
+    }
+
+    /// Select and scroll to the pending revealed key if it is in the tree
+    fn apply_pending_reveal(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(key) = self.state.pending_reveal.as_ref() else {
+            return;
+        };
+        let Some(index) = self
+            .key_tree_list_state
+            .read(cx)
+            .delegate()
+            .items
+            .iter()
+            .position(|item| !item.is_folder && &item.id == key)
+        else {
+            return;
+        };
+        self.state.pending_reveal = None;
+        self.key_tree_list_state.update(cx, |state, cx| {
+            let ix = IndexPath::new(index);
+            state.set_selected_index(Some(ix), window, cx);
+            state.scroll_to_item(ix, ScrollStrategy::Center, window, cx);
+        });
+    }
+
     fn select_item(&mut self, item_id: SharedString, is_folder: bool, cx: &mut Context<Self>) {
         if is_folder {
             if self.state.expanded_items.contains(&item_id) {
@@ -528,9 +1167,10 @@ impl ZedisKeyTree {
                 // User clicked a collapsed folder -> expand it and load data
                 self.state.expanded_items.insert(item_id.clone());
                 self.server_state.update(cx, |state, cx| {
-                    state.scan_prefix(format!("{}:", item_id.as_str()).into(), cx);
+                    state.scan_prefix(item_id.clone(), cx);
                 });
             }
+            self.save_expanded_items(cx);
             self.update_key_tree(true, cx);
         } else {
             let is_selected = self.server_state.read(cx).key().as_ref() == Some(&item_id);
@@ -543,6 +1183,149 @@ impl ZedisKeyTree {
         }
     }
 
+    /// Returns the row and the item of the keyboard selection
+    fn selected_tree_item(&self, cx: &App) -> Option<(usize, KeyTreeItem)> {
+        let delegate = self.key_tree_list_state.read(cx).delegate();
+        let row = delegate.selected_index?.row;
+        delegate.items.get(row).map(|item| (row, item.clone()))
+    }
+
+    /// Move the keyboard selection to the row and scroll it into view
+    fn select_row(&mut self, row: usize, window: &mut Window, cx: &mut Context<Self>) {
+        self.key_tree_list_state.update(cx, |state, cx| {
+            let ix = IndexPath::new(row);
+            state.set_selected_index(Some(ix), window, cx);
+            state.scroll_to_item(ix, ScrollStrategy::Top, window, cx);
+        });
+    }
+
+    /// Handle the arrow keys and Enter while the tree is focused
+    ///
+    /// Right expands a folder or moves into it, left collapses a folder or
+    /// moves to its parent, Enter toggles a folder or selects a key.
+    fn handle_tree_action(&mut self, action: KeyTreeAction, window: &mut Window, cx: &mut Context<Self>) {
+        let count = self.key_tree_list_state.read(cx).delegate().items.len();
+        if count == 0 {
+            return;
+        }
+        let Some((row, item)) = self.selected_tree_item(cx) else {
+            // Start from the first row
+            self.select_row(0, window, cx);
+            return;
+        };
+        match action {
+            KeyTreeAction::SelectPrev => {
+                self.select_row(row.saturating_sub(1), window, cx);
+            }
+            KeyTreeAction::SelectNext => {
+                self.select_row((row + 1).min(count - 1), window, cx);
+            }
+            KeyTreeAction::Expand => {
+                if !item.is_folder {
+                    return;
+                }
+                if item.expanded {
+                    self.select_row((row + 1).min(count - 1), window, cx);
+                } else {
+                    self.select_item(item.id, true, cx);
+                }
+            }
+            KeyTreeAction::Collapse => {
+                if item.is_folder && item.expanded {
+                    self.select_item(item.id, true, cx);
+                    return;
+                }
+                // The parent is the closest folder above with a smaller depth
+                let parent_row = self.key_tree_list_state.read(cx).delegate().items[..row]
+                    .iter()
+                    .rposition(|parent| parent.is_folder && parent.depth < item.depth);
+                if let Some(parent_row) = parent_row {
+                    self.select_row(parent_row, window, cx);
+                }
+            }
+            KeyTreeAction::Confirm => {
+                self.select_item(item.id, item.is_folder, cx);
+            }
+        }
+    }
+
+    /// Render the MATCH pattern a search sends for the current keyword and query mode
+    fn render_match_pattern(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let keyword = self.keyword_state.read(cx).value();
+        let hint = match self.server_state.read(cx).match_pattern(&keyword) {
+            Some(pattern) => {
+                let locale = cx.global::<ZedisGlobalStore>().read(cx).locale().to_string();
+                t!("key_tree.match_pattern", pattern = pattern, locale = locale)
+                    .to_string()
+                    .into()
+            }
+            None => i18n_key_tree(cx, "match_pattern_exact"),
+        };
+        Label::new(hint)
+            .px_2()
+            .pb_1()
+            .text_xs()
+            .text_ellipsis()
+            .text_color(cx.theme().muted_foreground)
+            .border_b_1()
+            .border_color(cx.theme().border)
+    }
+
+    /// Render the running total of the count-only scan
+    ///
+    /// Shows a cancel button while counting and a close button once stopped.
+    fn render_key_count(&self, cx: &mut Context<Self>) -> Option<impl IntoElement> {
+        let key_count = self.server_state.read(cx).key_count()?.clone();
+        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale().to_string();
+        let message = t!(
+            "key_tree.count_keys_message",
+            pattern = key_count.pattern,
+            count = key_count.count,
+            locale = locale
+        );
+        let status = if key_count.running {
+            i18n_key_tree(cx, "count_keys_running")
+        } else if key_count.done {
+            i18n_key_tree(cx, "count_keys_done")
+        } else {
+            i18n_key_tree(cx, "count_keys_canceled")
+        };
+        let running = key_count.running;
+        let action_btn = Button::new("key-tree-count-action-btn")
+            .ghost()
+            .xsmall()
+            .icon(CustomIconName::X)
+            .tooltip(if running {
+                i18n_key_tree(cx, "count_keys_cancel_tooltip")
+            } else {
+                i18n_key_tree(cx, "count_keys_close_tooltip")
+            })
+            .on_click(cx.listener(move |this, _, _, cx| {
+                this.server_state.update(cx, |state, cx| {
+                    if running {
+                        state.cancel_count_keys(cx);
+                    } else {
+                        state.clear_key_count(cx);
+                    }
+                });
+            }));
+        Some(
+            h_flex()
+                .px_2()
+                .py_1()
+                .gap_2()
+                .border_b_1()
+                .border_color(cx.theme().border)
+                .child(
+                    Label::new(format!("{message} ({status})"))
+                        .text_xs()
+                        .flex_1()
+                        .text_color(cx.theme().muted_foreground),
+                )
+                .child(action_btn),
+        )
+    }
+
     /// Render the tree view or empty state message
     ///
     /// Displays:
@@ -558,7 +1341,11 @@ impl ZedisKeyTree {
             .bg(cx.theme().sidebar)
             .text_color(cx.theme().sidebar_foreground)
             .h_full()
+            .key_context("KeyTree")
             .child(List::new(&self.key_tree_list_state))
+            .on_action(cx.listener(|this, e: &KeyTreeAction, window, cx| {
+                this.handle_tree_action(*e, window, cx);
+            }))
             .into_any_element()
     }
     /// Render the search/filter input bar with query mode selector
@@ -567,16 +1354,30 @@ impl ZedisKeyTree {
     /// - Query mode dropdown (All/Prefix/Exact) with visual indicators
     /// - Search input field with placeholder
     /// - Search button (with loading state during scan)
+    /// - Reset button to clear the filter and restart the full scan
     /// - Clearable input (X button appears when text entered)
     fn render_keyword_input(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        // Settings changes refresh the windows, drop the live filter once it is turned off
+        if !cx.global::<ZedisGlobalStore>().read(cx).live_filter()
+            && (self.live_filter_task.is_some() || !self.state.live_filter.is_empty())
+        {
+            self.live_filter_task = None;
+            self.set_live_filter(SharedString::default(), cx);
+        }
         let server_state = self.server_state.read(cx);
         let scaning = server_state.scaning();
+        let scan_type = server_state.scan_type();
+        let raw_glob = server_state.raw_glob();
+        let deleting = server_state.delete_keys_progress().is_some();
+        let has_keys = server_state.scan_count() != 0;
         let server_id = server_state.server_id();
         if server_id != self.state.server_id.as_str() {
             self.state.server_id = server_id.to_string().into();
             self.keyword_state.update(cx, |state, cx| {
                 state.set_value(SharedString::default(), window, cx);
             });
+            self.live_filter_task = None;
+            self.state.live_filter = SharedString::default();
         }
         let query_mode = self.state.query_mode;
 
@@ -596,22 +1397,114 @@ impl ZedisKeyTree {
                 .menu_element_with_check(query_mode == QueryMode::Prefix, Box::new(QueryMode::Prefix), |_, cx| {
                     Label::new(i18n_key_tree(cx, "query_mode_prefix")).ml_2().text_xs()
                 })
+                .menu_element_with_check(query_mode == QueryMode::Exact, Box::new(QueryMode::Exact), |_, cx| {
+                    Label::new(i18n_key_tree(cx, "query_mode_exact")).ml_2().text_xs()
+                })
+                .separator()
+                // Toggle between a literal keyword and a raw glob pattern
                 .menu_element_with_check(
-                    query_mode == QueryMode::Exact,
-                    Box::new(QueryMode::Exact),
-                    |_, cx| Label::new(i18n_key_tree(cx, "query_mode_exact")).ml_2().text_xs(),
+                    raw_glob,
+                    Box::new(if raw_glob {
+                        KeywordGlobMode::Literal
+                    } else {
+                        KeywordGlobMode::Raw
+                    }),
+                    |_, cx| Label::new(i18n_key_tree(cx, "raw_glob")).ml_2().text_xs(),
                 )
             });
         // Search button (shows loading spinner during scan)
         let search_btn = Button::new("key-tree-search-btn")
             .ghost()
-            .tooltip(i18n_key_tree(cx, "search_tooltip"))
+            .tooltip(format!(
+                "{} ({})",
+                i18n_key_tree(cx, "search_tooltip"),
+                humanize_keystroke("cmd-l")
+            ))
             .loading(scaning)
             .disabled(scaning)
             .icon(IconName::Search)
             .on_click(cx.listener(|this, _, _, cx| {
                 this.handle_filter(cx);
             }));
+        // Reset button (clear keyword and restart the full scan)
+        let reset_btn = Button::new("key-tree-reset-btn")
+            .ghost()
+            .tooltip(i18n_key_tree(cx, "reset_filter_tooltip"))
+            .disabled(scaning)
+            .icon(CustomIconName::FunnelX)
+            .on_click(cx.listener(|this, _, window, cx| {
+                this.handle_reset_filter(window, cx);
+            }));
+        // Preview button (single SCAN page, not available for exact match)
+        let preview_btn = Button::new("key-tree-preview-btn")
+            .ghost()
+            .tooltip(i18n_key_tree(cx, "scan_preview_tooltip"))
+            .disabled(scaning || query_mode == QueryMode::Exact)
+            .icon(IconName::Eye)
+            .on_click(cx.listener(|this, _, _, cx| {
+                this.handle_preview(cx);
+            }));
+        // Count button (count matching keys without loading them)
+        let count_btn = Button::new("key-tree-count-btn")
+            .ghost()
+            .tooltip(i18n_key_tree(cx, "count_keys_tooltip"))
+            .disabled(query_mode == QueryMode::Exact)
+            .icon(CustomIconName::Sigma)
+            .on_click(cx.listener(|this, _, _, cx| {
+                this.handle_count_keys(cx);
+            }));
+        // Encoding mode dropdown (show and filter by OBJECT ENCODING)
+        let encoding_mode = self.state.encoding_mode;
+        let encoding_dropdown = DropdownButton::new("key-tree-encoding-dropdown")
+            .button(
+                Button::new("key-tree-encoding-btn")
+                    .ghost()
+                    .when(encoding_mode != KeyEncodingMode::Off, |this| this.primary())
+                    .tooltip(i18n_key_tree(cx, "encoding_tooltip"))
+                    .icon(CustomIconName::Layers),
+            )
+            .dropdown_menu_with_anchor(Corner::TopRight, move |menu, _, _| {
+                let menu = menu
+                    .menu_element_with_check(
+                        encoding_mode == KeyEncodingMode::Off,
+                        Box::new(KeyEncodingMode::Off),
+                        |_, cx| Label::new(i18n_key_tree(cx, "encoding_off")).ml_2().text_xs(),
+                    )
+                    .menu_element_with_check(
+                        encoding_mode == KeyEncodingMode::All,
+                        Box::new(KeyEncodingMode::All),
+                        |_, cx| Label::new(i18n_key_tree(cx, "encoding_all")).ml_2().text_xs(),
+                    )
+                    .separator();
+                KeyEncodingMode::FILTERS.into_iter().fold(menu, |menu, mode| {
+                    let encoding = mode.encoding().unwrap_or_default();
+                    menu.menu_element_with_check(encoding_mode == mode, Box::new(mode), move |_, _| {
+                        Label::new(encoding).ml_2().text_xs()
+                    })
+                })
+            });
+        // Key type dropdown (scan only keys of one type)
+        let type_dropdown = DropdownButton::new("key-tree-type-dropdown")
+            .button(
+                Button::new("key-tree-type-btn")
+                    .ghost()
+                    .when(scan_type.is_some(), |this| this.primary())
+                    .tooltip(i18n_key_tree(cx, "type_filter_tooltip"))
+                    .icon(CustomIconName::Funnel),
+            )
+            .dropdown_menu_with_anchor(Corner::TopRight, move |menu, _, _| {
+                let menu = menu
+                    .menu_element_with_check(scan_type.is_none(), Box::new(KeyTypeFilter::All), |_, cx| {
+                        Label::new(i18n_key_tree(cx, "type_filter_all")).ml_2().text_xs()
+                    })
+                    .separator();
+                KeyTypeFilter::FILTERS.into_iter().fold(menu, |menu, filter| {
+                    let key_type = filter.key_type().unwrap_or_default();
+                    menu.menu_element_with_check(scan_type == Some(key_type), Box::new(filter), move |_, _| {
+                        Label::new(key_type.name()).ml_2().text_xs()
+                    })
+                })
+            });
         // keyword input
         let keyword_input = Input::new(&self.keyword_state)
             .w_full()
@@ -619,13 +1512,53 @@ impl ZedisKeyTree {
             .px_0()
             .mr_2()
             .prefix(query_mode_dropdown)
-            .suffix(search_btn)
+            .suffix(
+                h_flex()
+                    .child(type_dropdown)
+                    .child(encoding_dropdown)
+                    .child(preview_btn)
+                    .child(count_btn)
+                    .child(search_btn)
+                    .child(reset_btn),
+            )
             .cleanable(true);
         h_flex()
             .p_2()
             .border_b_1()
             .border_color(cx.theme().border)
             .child(keyword_input)
+            .child(
+                // Delete every loaded key of the current filter (not for exact match)
+                Button::new("key-tree-delete-matching-btn")
+                    .outline()
+                    .mr_1()
+                    .tooltip(i18n_key_tree(cx, "delete_matching_tooltip"))
+                    .disabled(scaning || deleting || query_mode == QueryMode::Exact || !has_keys)
+                    .icon(CustomIconName::FileXCorner)
+                    .on_click(cx.listener(|this, _, window, cx| {
+                        this.handle_delete_matching(window, cx);
+                    })),
+            )
+            .child(
+                Button::new("key-tree-import-btn")
+                    .outline()
+                    .mr_1()
+                    .tooltip(i18n_key_tree(cx, "import_key_tooltip"))
+                    .icon(CustomIconName::FileUp)
+                    .on_click(cx.listener(|this, _, _window, cx| {
+                        this.handle_import_key(cx);
+                    })),
+            )
+            .child(
+                Button::new("key-tree-import-value-btn")
+                    .outline()
+                    .mr_1()
+                    .tooltip(i18n_key_tree(cx, "import_value_tooltip"))
+                    .icon(CustomIconName::HardDriveUpload)
+                    .on_click(cx.listener(|this, _, window, cx| {
+                        this.handle_import_value(window, cx);
+                    })),
+            )
             .child(
                 Button::new("key-tree-add-btn")
                     .outline()
@@ -640,26 +1573,104 @@ impl ZedisKeyTree {
 impl Render for ZedisKeyTree {
     /// Main render method - displays search bar and tree structure
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        self.apply_pending_reveal(window, cx);
         v_flex()
             .h_full()
             .w_full()
             .child(self.render_keyword_input(window, cx))
+            .child(self.render_match_pattern(cx))
+            .children(self.render_key_count(cx))
             .child(self.render_tree(cx))
             .on_action(cx.listener(|this, e: &QueryMode, _window, cx| {
                 let new_mode = *e;
 
-                // Step 1: Update server state with new query mode
+                // The local UI state follows the server state through the observer,
+                // so it is reverted as well if the mode can not be saved
                 this.server_state.update(cx, |state, cx| {
                     state.set_query_mode(new_mode, cx);
                 });
-
-                // Step 2: Update local UI state
-                this.state.query_mode = new_mode;
             }))
-            .on_action(cx.listener(move |this, event: &EditorAction, window, cx| {
-                if event == &EditorAction::Create {
+            .on_action(cx.listener(|this, e: &KeyTypeFilter, _window, cx| {
+                let key_type = e.key_type();
+                this.server_state.update(cx, |state, cx| {
+                    state.set_scan_type(key_type, cx);
+                });
+            }))
+            .on_action(cx.listener(|this, e: &KeywordGlobMode, _window, cx| {
+                let raw_glob = *e == KeywordGlobMode::Raw;
+                this.server_state.update(cx, |state, cx| {
+                    state.set_raw_glob(raw_glob, cx);
+                });
+            }))
+            .on_action(cx.listener(|this, e: &KeyEncodingMode, _window, cx| {
+                this.state.encoding_mode = *e;
+                this.update_key_tree(true, cx);
+            }))
+            .on_action(cx.listener(move |this, event: &EditorAction, window, cx| match event {
+                EditorAction::Create => {
                     this.handle_add_key(window, cx);
                 }
+                EditorAction::FocusSearch => {
+                    this.focus_keyword(window, cx);
+                }
+                // Let the content view open the quick open palette
+                _ => {
+                    cx.propagate();
+                }
             }))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    // Time to render a frame at 60 fps
+    const FRAME_BUDGET: Duration = Duration::from_millis(16);
+
+    #[test]
+    fn test_folder_and_key_of_same_name() {
+        let keys: Vec<(SharedString, KeyType)> = vec![("a".into(), KeyType::String), ("a:b".into(), KeyType::Hash)];
+        let mut index = KeyTreeIndex::new(keys, 3);
+        let items = index.visible_items(false, &AHashSet::new());
+        let ids: Vec<(&str, bool)> = items.iter().map(|item| (item.id.as_str(), item.is_folder)).collect();
+        assert_eq!(ids, vec![("a:", true), ("a", false)]);
+
+        let expanded: AHashSet<SharedString> = ["a:".into()].into_iter().collect();
+        let items = index.visible_items(false, &expanded);
+        let ids: Vec<&str> = items.iter().map(|item| item.id.as_str()).collect();
+        assert_eq!(ids, vec!["a:", "a:b", "a"]);
+        assert_eq!(items[1].label.as_str(), "b");
+    }
+
+    #[test]
+    #[ignore = "benchmark, run with `cargo test --release -- --ignored`"]
+    fn bench_key_tree_index_500k_keys() {
+        let keys: Vec<(SharedString, KeyType)> = (0..500_000)
+            .map(|i| (format!("app{}:user:{i}", i % 10).into(), KeyType::String))
+            .collect();
+
+        let start = Instant::now();
+        let mut index = KeyTreeIndex::new(keys, 3);
+        let items = index.visible_items(false, &AHashSet::new());
+        let elapsed = start.elapsed();
+        assert_eq!(items.len(), 10);
+        assert!(elapsed < FRAME_BUDGET, "build the root folders: {elapsed:?}");
+
+        // Expanding a folder only groups the keys below it
+        let expanded: AHashSet<SharedString> = ["app0:".into(), "app0:user:".into()].into_iter().collect();
+        let start = Instant::now();
+        let items = index.visible_items(false, &expanded);
+        let elapsed = start.elapsed();
+        assert_eq!(items.len(), 10 + 1 + 50_000);
+        assert!(elapsed < FRAME_BUDGET, "expand a folder of 50k keys: {elapsed:?}");
+
+        // Collapsing walks the visible items only
+        let start = Instant::now();
+        let items = index.visible_items(false, &AHashSet::new());
+        let elapsed = start.elapsed();
+        assert_eq!(items.len(), 10);
+        assert!(elapsed < FRAME_BUDGET / 4, "collapse: {elapsed:?}");
+    }
+}
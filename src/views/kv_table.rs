@@ -15,11 +15,11 @@
 use crate::{
     assets::CustomIconName,
     components::{INDEX_COLUMN_NAME, ZedisKvDelegate, ZedisKvFetcher},
-    states::{ServerEvent, ZedisGlobalStore, ZedisServerState, i18n_common, i18n_kv_table},
+    states::{RowDensity, ServerEvent, SortOrder, ZedisGlobalStore, ZedisServerState, i18n_common, i18n_kv_table},
 };
-use gpui::{Entity, SharedString, Subscription, TextAlign, Window, div, prelude::*, px};
+use gpui::{Entity, ScrollStrategy, SharedString, Subscription, TextAlign, Window, div, prelude::*, px};
 use gpui_component::{
-    ActiveTheme, Disableable, Icon, IconName, PixelsExt,
+    ActiveTheme, Disableable, Icon, IconName, PixelsExt, Sizable,
     button::{Button, ButtonVariants},
     h_flex,
     input::{Input, InputEvent, InputState},
@@ -27,10 +27,13 @@ use gpui_component::{
     table::{Table, TableState},
     v_flex,
 };
+use rust_i18n::t;
 use tracing::info;
 
 /// Width of the keyword search input field in pixels
 const KEYWORD_INPUT_WIDTH: f32 = 200.0;
+/// Width of the action column, fits the edit, JSON, copy and delete buttons
+const ACTION_COLUMN_WIDTH: f32 = 170.0;
 
 /// Defines the type of table column for different purposes.
 #[derive(Clone, Default, PartialEq, Eq)]
@@ -89,6 +92,8 @@ pub struct ZedisKvTable<T: ZedisKvFetcher> {
     loading: bool,
     /// Flag indicating the selected key has changed (triggers input reset)
     key_changed: bool,
+    /// Key of the loaded rows, the scroll position is kept while it is unchanged
+    key: Option<SharedString>,
     /// Event subscriptions for server state and input changes
     _subscriptions: Vec<Subscription>,
 }
@@ -103,7 +108,7 @@ impl<T: ZedisKvFetcher> ZedisKvTable<T> {
     ///
     /// # Logic:
     /// 1. Adds an index column at the start (80px, right-aligned)
-    /// 2. Adds an action column at the end (100px, center-aligned)
+    /// 2. Adds an action column at the end (ACTION_COLUMN_WIDTH, center-aligned)
     /// 3. Calculates remaining space for columns without fixed widths
     /// 4. Distributes remaining width evenly among flexible columns
     fn new_columns(mut columns: Vec<KvTableColumn>, window: &Window, cx: &mut Context<Self>) -> Vec<KvTableColumn> {
@@ -125,7 +130,7 @@ impl<T: ZedisKvFetcher> ZedisKvTable<T> {
         columns.push(KvTableColumn {
             column_type: KvTableColumnType::Action,
             name: i18n_common(cx, "action"),
-            width: Some(100.0),
+            width: Some(ACTION_COLUMN_WIDTH),
             align: Some(TextAlign::Center),
         });
 
@@ -184,12 +189,31 @@ impl<T: ZedisKvFetcher> ZedisKvTable<T> {
                 | ServerEvent::ValueAdded(_)
                 | ServerEvent::ValueUpdated(_) => {
                     let fetcher = Self::new_values(server_state.clone(), cx);
+                    let key = server_state.read(cx).key();
+                    let same_key = this.key == key;
+                    this.key = key;
                     this.loading = false;
                     this.done = fetcher.is_done();
                     this.items_count = fetcher.rows_count();
                     this.total_count = fetcher.count();
-                    this.table_state.update(cx, |state, _| {
+                    // Staged edits belong to the value before the reload
+                    let reloaded = matches!(event, ServerEvent::ValueLoaded(_));
+                    this.table_state.update(cx, |state, cx| {
+                        if reloaded {
+                            state.delegate_mut().discard_staged();
+                        }
+                        // Capture the first visible row before the rows are replaced,
+                        // scroll back to it for a reload of the same key, start at the top otherwise
+                        let top_row = if same_key {
+                            state.vertical_scroll_handle.logical_scroll_top_index()
+                        } else {
+                            0
+                        };
                         state.delegate_mut().set_fetcher(fetcher);
+                        state
+                            .vertical_scroll_handle
+                            .scroll_to_item_strict(top_row, ScrollStrategy::Top);
+                        cx.notify();
                     });
                 }
                 // Clear search when key selection changes
@@ -215,13 +239,22 @@ impl<T: ZedisKvFetcher> ZedisKvTable<T> {
         }));
 
         // Initialize table data and state
-        let fetcher = Self::new_values(server_state, cx);
+        let key = server_state.read(cx).key();
+        let fetcher = Self::new_values(server_state.clone(), cx);
         let done = fetcher.is_done();
         let items_count = fetcher.rows_count();
         let total_count = fetcher.count();
         let delegate = ZedisKvDelegate::new(Self::new_columns(columns, window, cx), fetcher, window, cx);
         let table_state = cx.new(|cx| TableState::new(delegate, window, cx));
 
+        // Staged edits keep the value from being reloaded when the window regains focus
+        subscriptions.push(cx.observe(&table_state, move |_this, table_state, cx| {
+            let staged = table_state.read(cx).delegate().staged_count() != 0;
+            server_state.update(cx, |state, _cx| {
+                state.set_unsaved_value_edits(staged);
+            });
+        }));
+
         info!("Creating new key value table view");
 
         Self {
@@ -232,6 +265,7 @@ impl<T: ZedisKvFetcher> ZedisKvTable<T> {
             done,
             loading: false,
             key_changed: false,
+            key,
             _subscriptions: subscriptions,
         }
     }
@@ -275,6 +309,96 @@ impl<T: ZedisKvFetcher> Render for ZedisKvTable<T> {
                 this.handle_filter(cx);
             }));
 
+        // Sort order toggle, only for values that can be sorted (e.g. ZSET scores)
+        let sort_order = self.table_state.read(cx).delegate().fetcher().sort_order();
+        let sort_btn = sort_order.map(|sort_order| {
+            let (icon, tooltip) = if sort_order == SortOrder::Asc {
+                (IconName::SortAscending, i18n_kv_table(cx, "sort_asc_tooltip"))
+            } else {
+                (IconName::SortDescending, i18n_kv_table(cx, "sort_desc_tooltip"))
+            };
+            Button::new("kv-table-sort-btn")
+                .icon(icon)
+                .tooltip(tooltip)
+                .disabled(self.loading)
+                .on_click(cx.listener(|this, _, _, cx| {
+                    this.table_state.update(cx, |state, cx| {
+                        state.delegate().fetcher().toggle_sort_order(cx);
+                    });
+                }))
+        });
+
+        // Trim button, only for values that can be trimmed (e.g. LTRIM of lists)
+        let can_trim = self.table_state.read(cx).delegate().fetcher().can_trim();
+        let trim_btn = can_trim.then(|| {
+            Button::new("kv-table-trim-btn")
+                .icon(CustomIconName::Scissors)
+                .tooltip(i18n_kv_table(cx, "trim_tooltip"))
+                .disabled(self.loading)
+                .on_click(cx.listener(|this, _, window, cx| {
+                    this.table_state.update(cx, |state, cx| {
+                        state.delegate().fetcher().handle_trim(window, cx);
+                    });
+                }))
+        });
+
+        // Batch edit toggle and save/discard of the staged edits, e.g. HSET of several hash fields
+        let (can_batch_update, batch_mode, staged_count) = {
+            let delegate = self.table_state.read(cx).delegate();
+            (
+                delegate.fetcher().can_batch_update(),
+                delegate.batch_mode(),
+                delegate.staged_count(),
+            )
+        };
+        let batch_btn = can_batch_update.then(|| {
+            let tooltip = if batch_mode {
+                i18n_kv_table(cx, "batch_edit_exit_tooltip")
+            } else {
+                i18n_kv_table(cx, "batch_edit_tooltip")
+            };
+            Button::new("kv-table-batch-edit-btn")
+                .icon(CustomIconName::FilePenLine)
+                .tooltip(tooltip)
+                .when(batch_mode, |this| this.primary())
+                .disabled(self.loading)
+                .on_click(cx.listener(move |this, _, _, cx| {
+                    this.table_state.update(cx, |state, cx| {
+                        state.delegate_mut().set_batch_mode(!batch_mode);
+                        cx.notify();
+                    });
+                }))
+        });
+        let staged_btns = (batch_mode && staged_count > 0).then(|| {
+            let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+            let label = t!("kv_table.save_staged", count = staged_count, locale = locale).to_string();
+            h_flex()
+                .gap_2()
+                .child(
+                    Button::new("kv-table-save-staged-btn")
+                        .primary()
+                        .label(label)
+                        .disabled(self.loading)
+                        .on_click(cx.listener(|this, _, window, cx| {
+                            this.table_state.update(cx, |state, cx| {
+                                state.delegate_mut().save_staged(window, cx);
+                                cx.notify();
+                            });
+                        })),
+                )
+                .child(
+                    Button::new("kv-table-discard-staged-btn")
+                        .icon(CustomIconName::X)
+                        .tooltip(i18n_kv_table(cx, "discard_staged_tooltip"))
+                        .on_click(cx.listener(|this, _, _, cx| {
+                            this.table_state.update(cx, |state, cx| {
+                                state.delegate_mut().discard_staged();
+                                cx.notify();
+                            });
+                        })),
+                )
+        });
+
         // Completion indicator icon
         let status_icon = if self.done {
             Icon::new(CustomIconName::CircleCheckBig) // All data loaded
@@ -282,6 +406,10 @@ impl<T: ZedisKvFetcher> Render for ZedisKvTable<T> {
             Icon::new(CustomIconName::CircleDotDashed) // More data available
         };
 
+        let store = cx.global::<ZedisGlobalStore>().read(cx);
+        let compact = store.row_density() == RowDensity::Compact;
+        let zebra_stripes = store.zebra_stripes();
+
         v_flex()
             .h_full()
             .w_full()
@@ -289,7 +417,8 @@ impl<T: ZedisKvFetcher> Render for ZedisKvTable<T> {
             .child(
                 div().size_full().flex_1().child(
                     Table::new(&self.table_state)
-                        .stripe(true) // Alternating row colors for better readability
+                        .when(compact, |this| this.small()) // Smaller rows in compact density
+                        .stripe(zebra_stripes) // Alternating row colors for better readability
                         .bordered(true) // Table borders
                         .scrollbar_visible(true, true), // Show both scrollbars
                 ),
@@ -309,12 +438,16 @@ impl<T: ZedisKvFetcher> Render for ZedisKvTable<T> {
                                     .tooltip(i18n_kv_table(cx, "add_value_tooltip"))
                                     .on_click(handle_add_value),
                             )
+                            .children(sort_btn)
+                            .children(trim_btn)
+                            .children(batch_btn)
                             .child(
                                 Input::new(&self.keyword_state)
                                     .w(px(KEYWORD_INPUT_WIDTH))
                                     .suffix(search_btn)
                                     .cleanable(true),
                             )
+                            .children(staged_btns)
                             .flex_1(),
                     )
                     // Right side: Status icon and count
@@ -15,14 +15,40 @@
 use crate::{
     components::{FormDialog, FormField, ZedisKvFetcher, open_add_form_dialog},
     helpers::fast_contains_ignore_case,
-    states::{RedisValue, ZedisServerState, i18n_common, i18n_list_editor},
+    states::{RedisValue, ServerEvent, ZedisGlobalStore, ZedisServerState, i18n_common, i18n_list_editor},
     views::{KvTableColumn, ZedisKvTable},
 };
-use gpui::{App, Entity, SharedString, Window, div, prelude::*};
-use gpui_component::WindowExt;
+use gpui::{App, Entity, SharedString, Subscription, Window, div, prelude::*};
+use gpui_component::{
+    WindowExt,
+    button::{Button, ButtonVariants},
+    notification::Notification,
+};
+use rust_i18n::t;
 use std::rc::Rc;
 use tracing::info;
 
+/// Parses a JSON array into list elements.
+///
+/// String elements are pushed without quotes, the others as compact JSON.
+/// Returns None if the value is not a non-empty JSON array.
+fn split_json_array(value: &str) -> Option<Vec<SharedString>> {
+    let serde_json::Value::Array(items) = serde_json::from_str(value.trim()).ok()? else {
+        return None;
+    };
+    if items.is_empty() {
+        return None;
+    }
+    let elements = items
+        .into_iter()
+        .map(|item| match item {
+            serde_json::Value::String(s) => s.into(),
+            other => other.to_string().into(),
+        })
+        .collect();
+    Some(elements)
+}
+
 /// Manages Redis List values and their display state.
 ///
 /// Handles both filtered and unfiltered views of list data, maintaining
@@ -131,6 +157,7 @@ impl ZedisKvFetcher for ZedisListValues {
     ///
     /// When a filter is active, maps the visible index to the real index
     /// in the underlying list before performing the deletion (LREM command).
+    /// The original value is passed along so a concurrent change aborts the deletion.
     fn remove(&self, index: usize, cx: &mut App) {
         // Map visible index to real index when filtering is active
         let real_index = self
@@ -139,8 +166,16 @@ impl ZedisKvFetcher for ZedisListValues {
             .and_then(|indexes| indexes.get(index).copied())
             .unwrap_or(index);
 
+        let Some(original_value) = self
+            .value
+            .list_value()
+            .and_then(|list_value| list_value.values.get(real_index).cloned())
+        else {
+            return;
+        };
+
         self.server_state.update(cx, |state, cx| {
-            state.remove_list_value(real_index, cx);
+            state.remove_list_value(real_index, original_value, cx);
         });
     }
 
@@ -155,18 +190,31 @@ impl ZedisKvFetcher for ZedisListValues {
     /// The dialog allows users to choose between:
     /// - RPUSH: Append to the end of the list
     /// - LPUSH: Prepend to the beginning of the list
+    ///
+    /// A JSON array can be split so that each element is pushed separately.
     fn handle_add_value(&self, window: &mut Window, cx: &mut App) {
         let server_state = self.server_state.clone();
 
         let handle_submit = Rc::new(move |values: Vec<SharedString>, window: &mut Window, cx: &mut App| {
-            // Expect exactly 2 values: [position_choice, value]
-            if values.len() != 2 {
+            // Expect exactly 3 values: [position_choice, split_choice, value]
+            if values.len() != 3 {
                 return false;
             }
 
-            // values[0] = RPUSH/LPUSH choice, values[1] = actual value
+            // values[1] = "1" pushes each element of a JSON array separately
+            let new_values = if values[1] == "1" {
+                let Some(elements) = split_json_array(&values[2]) else {
+                    window.push_notification(Notification::warning(i18n_list_editor(cx, "invalid_json_array")), cx);
+                    return false;
+                };
+                elements
+            } else {
+                vec![values[2].clone()]
+            };
+
+            // values[0] = RPUSH/LPUSH choice
             server_state.update(cx, |state, cx| {
-                state.push_list_value(values[1].clone(), values[0].clone(), cx);
+                state.push_list_values(new_values, values[0].clone(), cx);
             });
 
             window.close_dialog(cx);
@@ -176,6 +224,11 @@ impl ZedisKvFetcher for ZedisListValues {
         let fields = vec![
             // Position choice: RPUSH (right/end) or LPUSH (left/start)
             FormField::new(i18n_list_editor(cx, "positon")).with_options(vec!["RPUSH".into(), "LPUSH".into()]),
+            // Push the value as one element or split a JSON array into elements
+            FormField::new(i18n_list_editor(cx, "push_as")).with_options(vec![
+                i18n_list_editor(cx, "push_as_single"),
+                i18n_list_editor(cx, "push_as_json_array"),
+            ]),
             // Value input field
             FormField::new(i18n_common(cx, "value"))
                 .with_placeholder(i18n_common(cx, "value_placeholder"))
@@ -193,6 +246,147 @@ impl ZedisKvFetcher for ZedisListValues {
         );
     }
 
+    /// Lists support inserting next to an item (LINSERT command).
+    fn can_insert(&self) -> bool {
+        true
+    }
+
+    /// Opens a dialog to insert a value before or after the item at the visible index.
+    ///
+    /// The item's current value is used as the LINSERT pivot.
+    fn handle_insert_value(&self, index: usize, window: &mut Window, cx: &mut App) {
+        // Map visible index to real index when filtering is active
+        let real_index = self
+            .visible_item_indexes
+            .as_ref()
+            .and_then(|indexes| indexes.get(index).copied())
+            .unwrap_or(index);
+        let Some(pivot) = self
+            .value
+            .list_value()
+            .and_then(|list_value| list_value.values.get(real_index).cloned())
+        else {
+            return;
+        };
+        let server_state = self.server_state.clone();
+
+        let handle_submit = Rc::new(move |values: Vec<SharedString>, window: &mut Window, cx: &mut App| {
+            // Expect exactly 2 values: [position_choice, value]
+            if values.len() != 2 {
+                return false;
+            }
+
+            // values[0] = "0" inserts BEFORE the item, "1" AFTER it
+            let before = values[0] != "1";
+            server_state.update(cx, |state, cx| {
+                state.insert_list_value(real_index, pivot.clone(), values[1].clone(), before, cx);
+            });
+
+            window.close_dialog(cx);
+            true
+        });
+
+        let fields = vec![
+            FormField::new(i18n_list_editor(cx, "positon")).with_options(vec!["BEFORE".into(), "AFTER".into()]),
+            FormField::new(i18n_common(cx, "value"))
+                .with_placeholder(i18n_common(cx, "value_placeholder"))
+                .with_focus(),
+        ];
+
+        open_add_form_dialog(
+            FormDialog {
+                title: i18n_list_editor(cx, "insert_value_title"),
+                fields,
+                handle_submit,
+            },
+            window,
+            cx,
+        );
+    }
+
+    /// Lists can be trimmed to a range of items (LTRIM command).
+    fn can_trim(&self) -> bool {
+        true
+    }
+
+    /// Opens a dialog to trim the list, the trimming is confirmed before it is applied.
+    ///
+    /// Either a start/stop range or the number of last items to keep can be entered,
+    /// the latter takes precedence.
+    fn handle_trim(&self, window: &mut Window, cx: &mut App) {
+        let server_state = self.server_state.clone();
+        let size = self.count();
+
+        let handle_submit = Rc::new(move |values: Vec<SharedString>, window: &mut Window, cx: &mut App| {
+            // Expect exactly 3 values: [start, stop, keep_last]
+            if values.len() != 3 {
+                return false;
+            }
+            let parse_index = |value: &SharedString, default: i64| {
+                let value = value.trim();
+                if value.is_empty() {
+                    Some(default)
+                } else {
+                    value.parse::<i64>().ok()
+                }
+            };
+            let range = if values[2].trim().is_empty() {
+                parse_index(&values[0], 0).zip(parse_index(&values[1], -1))
+            } else {
+                values[2]
+                    .trim()
+                    .parse::<i64>()
+                    .ok()
+                    .filter(|count| *count > 0)
+                    .map(|count| (-count, -1))
+            };
+            let Some((start, stop)) = range else {
+                window.push_notification(Notification::warning(i18n_list_editor(cx, "invalid_trim_range")), cx);
+                return false;
+            };
+            window.close_dialog(cx);
+
+            // LTRIM deletes the items outside the range, confirm it first
+            let server_state = server_state.clone();
+            window.open_dialog(cx, move |dialog, _, cx| {
+                let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+                let message = t!(
+                    "list_editor.trim_prompt",
+                    start = start,
+                    stop = stop,
+                    size = size,
+                    locale = locale
+                );
+                let server_state = server_state.clone();
+                dialog.confirm().child(message.to_string()).on_ok(move |_, window, cx| {
+                    server_state.update(cx, |state, cx| {
+                        state.trim_list_value(start, stop, cx);
+                    });
+                    window.close_dialog(cx);
+                    true
+                })
+            });
+            true
+        });
+
+        let fields = vec![
+            FormField::new(i18n_list_editor(cx, "trim_start")).with_placeholder("0".into()),
+            FormField::new(i18n_list_editor(cx, "trim_stop")).with_placeholder("-1".into()),
+            FormField::new(i18n_list_editor(cx, "trim_keep_last"))
+                .with_placeholder(i18n_list_editor(cx, "trim_keep_last_placeholder")),
+        ];
+
+        open_add_form_dialog(
+            FormDialog {
+                title: i18n_list_editor(cx, "trim_title"),
+                fields,
+                handle_submit,
+            },
+            window,
+            cx,
+        );
+    }
+
     /// Updates the value at the specified visible index using LSET command.
     ///
     /// When a filter is active, maps the visible index to the real index
@@ -239,17 +433,22 @@ impl ZedisKvFetcher for ZedisListValues {
 /// Editor view for Redis List data type.
 ///
 /// Provides a table-based interface for viewing and manipulating Redis lists,
-/// supporting operations like LRANGE, LSET, LREM, LPUSH, and RPUSH.
+/// supporting operations like LRANGE, LSET, LREM, LINSERT, LTRIM, LPUSH, and RPUSH.
 ///
 /// Features:
 /// - Paginated loading of large lists
 /// - Keyword-based filtering
 /// - In-place value editing
 /// - Add values to either end of the list
-/// - Delete individual items
+/// - Insert values before or after any item
+/// - Trim the list to a range of items
+/// - Delete individual items, the deletion can be undone
 pub struct ZedisListEditor {
     /// Table component managing the list data display and interactions
     table_state: Entity<ZedisKvTable<ZedisListValues>>,
+
+    /// Event subscriptions for reactive updates
+    _subscriptions: Vec<Subscription>,
 }
 
 impl ZedisListEditor {
@@ -258,12 +457,45 @@ impl ZedisListEditor {
     /// Initializes a single-column table to display list values.
     pub fn new(server_state: Entity<ZedisServerState>, window: &mut Window, cx: &mut Context<Self>) -> Self {
         let table_state = cx.new(|cx| {
-            ZedisKvTable::<ZedisListValues>::new(vec![KvTableColumn::new("Value", None)], server_state, window, cx)
+            ZedisKvTable::<ZedisListValues>::new(
+                vec![KvTableColumn::new("Value", None)],
+                server_state.clone(),
+                window,
+                cx,
+            )
         });
 
+        // Offer to undo the deletion of a list item
+        let subscriptions = vec![
+            cx.subscribe_in(&server_state, window, |_view, server_state, event, window, cx| {
+                if let ServerEvent::ListValueRemoved(_) = event {
+                    let server_state = server_state.clone();
+                    let undo_label = i18n_list_editor(cx, "undo_remove");
+                    let notification =
+                        Notification::success(i18n_list_editor(cx, "remove_success")).action(move |_, _, cx| {
+                            let server_state = server_state.clone();
+                            Button::new("list-editor-undo-remove")
+                                .primary()
+                                .small()
+                                .label(undo_label.clone())
+                                .on_click(cx.listener(move |this, _, window, cx| {
+                                    server_state.update(cx, |state, cx| {
+                                        state.undo_remove_list_value(cx);
+                                    });
+                                    this.dismiss(window, cx);
+                                }))
+                        });
+                    window.push_notification(notification, cx);
+                }
+            }),
+        ];
+
         info!("Creating new list editor view");
 
-        Self { table_state }
+        Self {
+            table_state,
+            _subscriptions: subscriptions,
+        }
     }
 }
 
@@ -0,0 +1,271 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    assets::CustomIconName,
+    helpers::get_font_family,
+    states::{ServerEvent, ZedisGlobalStore, ZedisServerState, i18n_pubsub},
+};
+use gpui::{Entity, SharedString, Subscription, Window, div, prelude::*, uniform_list};
+use gpui_component::{
+    ActiveTheme, Disableable, Icon, IconName, Sizable,
+    button::{Button, ButtonVariants},
+    h_flex,
+    input::{Input, InputEvent, InputState},
+    label::Label,
+    v_flex,
+};
+use rust_i18n::t;
+use tracing::info;
+
+/// Pub/Sub viewer component
+///
+/// Features:
+/// - Subscribe to channels (SUBSCRIBE) or patterns (PSUBSCRIBE) sharing one dedicated connection
+/// - Count of received messages
+/// - Channel tabs to filter the message log, each with its own unsubscribe button
+/// - Scrolling message log with receive time, channel and payload
+pub struct ZedisPubSubViewer {
+    /// Reference to server state for Redis operations
+    server_state: Entity<ZedisServerState>,
+
+    /// Input field state for the channel name
+    channel_state: Entity<InputState>,
+
+    /// Channel used to filter the message log (None shows all channels)
+    selected_channel: Option<SharedString>,
+
+    /// Event subscriptions for reactive updates
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ZedisPubSubViewer {
+    pub fn new(server_state: Entity<ZedisServerState>, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let mut subscriptions = Vec::new();
+
+        subscriptions.push(
+            cx.subscribe(&server_state, |this, server_state, event, cx| match event {
+                ServerEvent::PubSubMessage(_) => {
+                    cx.notify();
+                }
+                ServerEvent::PubSubSubscriptionsUpdated => {
+                    // Reset filter if the selected channel has been unsubscribed
+                    if let Some(channel) = &this.selected_channel
+                        && !server_state.read(cx).pubsub().channels().contains(channel)
+                    {
+                        this.selected_channel = None;
+                    }
+                    cx.notify();
+                }
+                _ => {}
+            }),
+        );
+
+        let channel_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .clean_on_escape()
+                .placeholder(i18n_pubsub(cx, "channel_placeholder"))
+        });
+        channel_state.update(cx, |state, cx| {
+            state.focus(window, cx);
+        });
+
+        subscriptions.push(cx.subscribe_in(&channel_state, window, |view, _, event, window, cx| {
+            if let InputEvent::PressEnter { .. } = &event {
+                view.handle_subscribe(window, cx);
+            }
+        }));
+
+        info!("Creating new pub/sub viewer view");
+
+        Self {
+            server_state,
+            channel_state,
+            selected_channel: None,
+            _subscriptions: subscriptions,
+        }
+    }
+
+    fn handle_subscribe(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let channel = self.channel_state.read(cx).value();
+        let channel: SharedString = channel.trim().to_string().into();
+        if channel.is_empty() {
+            return;
+        }
+        self.server_state.update(cx, |state, cx| {
+            state.subscribe_channel(channel, cx);
+        });
+        self.channel_state.update(cx, |state, cx| {
+            state.set_value(SharedString::default(), window, cx);
+        });
+    }
+
+    /// Render the channel input with subscribe button
+    fn render_channel_input(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let pubsub = self.server_state.read(cx).pubsub();
+        let connecting = pubsub.connecting();
+        let received_count = pubsub.received_count();
+        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale().to_string();
+        let received = t!("pubsub.received_count", count = received_count, locale = locale).to_string();
+        h_flex()
+            .p_2()
+            .gap_2()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .child(Input::new(&self.channel_state).flex_1().cleanable(true))
+            .child(
+                Button::new("pubsub-subscribe-btn")
+                    .outline()
+                    .loading(connecting)
+                    .disabled(connecting)
+                    .icon(CustomIconName::Radio)
+                    .label(i18n_pubsub(cx, "subscribe"))
+                    .tooltip(i18n_pubsub(cx, "subscribe_tooltip"))
+                    .on_click(cx.listener(|this, _, window, cx| {
+                        this.handle_subscribe(window, cx);
+                    })),
+            )
+            .child(Label::new(received).text_sm().text_color(cx.theme().muted_foreground))
+            .child(
+                Button::new("pubsub-clear-btn")
+                    .outline()
+                    .icon(CustomIconName::FileXCorner)
+                    .tooltip(i18n_pubsub(cx, "clear_tooltip"))
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        this.server_state.update(cx, |state, cx| {
+                            state.clear_pubsub_messages(cx);
+                        });
+                    })),
+            )
+    }
+
+    /// Render one tab per subscribed channel, used to filter the message log
+    fn render_channel_tabs(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let channels = self.server_state.read(cx).pubsub().channels().to_vec();
+        let selected_channel = self.selected_channel.clone();
+        let mut tabs = h_flex()
+            .id("pubsub-channel-tabs")
+            .px_2()
+            .py_1()
+            .gap_1()
+            .flex_wrap()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .child(
+                Button::new("pubsub-channel-all")
+                    .small()
+                    .when(selected_channel.is_none(), |this| this.primary())
+                    .when(selected_channel.is_some(), |this| this.ghost())
+                    .label(i18n_pubsub(cx, "all_channels"))
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        this.selected_channel = None;
+                        cx.notify();
+                    })),
+            );
+        let unsubscribe_tooltip = i18n_pubsub(cx, "unsubscribe_tooltip");
+        for (index, channel) in channels.into_iter().enumerate() {
+            let is_selected = selected_channel.as_ref() == Some(&channel);
+            let select_channel = channel.clone();
+            tabs = tabs.child(
+                h_flex()
+                    .child(
+                        Button::new(("pubsub-channel", index))
+                            .small()
+                            .when(is_selected, |this| this.primary())
+                            .when(!is_selected, |this| this.ghost())
+                            .label(channel.clone())
+                            .on_click(cx.listener(move |this, _, _, cx| {
+                                this.selected_channel = Some(select_channel.clone());
+                                cx.notify();
+                            })),
+                    )
+                    .child(
+                        Button::new(("pubsub-unsubscribe", index))
+                            .ghost()
+                            .xsmall()
+                            .icon(CustomIconName::X)
+                            .tooltip(unsubscribe_tooltip.clone())
+                            .on_click(cx.listener(move |this, _, _, cx| {
+                                let channel = channel.clone();
+                                this.server_state.update(cx, |state, cx| {
+                                    state.unsubscribe_channel(channel, cx);
+                                });
+                            })),
+                    ),
+            );
+        }
+        tabs
+    }
+
+    /// Render the message log, filtered by the selected channel
+    fn render_messages(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let messages: Vec<_> = self
+            .server_state
+            .read(cx)
+            .pubsub()
+            .messages()
+            .iter()
+            .filter(|message| {
+                self.selected_channel
+                    .as_ref()
+                    .is_none_or(|channel| channel == &message.channel)
+            })
+            .cloned()
+            .collect();
+        if messages.is_empty() {
+            return h_flex()
+                .w_full()
+                .justify_center()
+                .gap_2()
+                .pt_5()
+                .child(Icon::new(IconName::Info).text_sm())
+                .child(Label::new(i18n_pubsub(cx, "no_messages")).text_sm())
+                .into_any_element();
+        }
+        let time_color = cx.theme().muted_foreground;
+        let channel_color = cx.theme().primary;
+        uniform_list("pubsub-messages", messages.len(), move |range, _window, _cx| {
+            range
+                .filter_map(|index| {
+                    let message = messages.get(index)?;
+                    Some(
+                        h_flex()
+                            .id(("pubsub-message", index))
+                            .w_full()
+                            .px_2()
+                            .py_1()
+                            .gap_3()
+                            .child(Label::new(message.received_at.clone()).text_color(time_color))
+                            .child(Label::new(message.channel.clone()).text_color(channel_color))
+                            .child(div().flex_1().text_ellipsis().child(message.payload.clone())),
+                    )
+                })
+                .collect()
+        })
+        .font_family(get_font_family())
+        .text_sm()
+        .size_full()
+        .into_any_element()
+    }
+}
+
+impl Render for ZedisPubSubViewer {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        v_flex()
+            .size_full()
+            .child(self.render_channel_input(cx))
+            .child(self.render_channel_tabs(cx))
+            .child(div().flex_1().w_full().child(self.render_messages(cx)))
+    }
+}
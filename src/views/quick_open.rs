@@ -0,0 +1,190 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    helpers::{fuzzy_match, get_font_family},
+    states::{ZedisServerState, i18n_key_tree},
+};
+use gpui::{Entity, KeyDownEvent, ScrollHandle, SharedString, Subscription, Window, div, prelude::*, px};
+use gpui_component::{
+    ActiveTheme, WindowExt,
+    input::{Input, InputEvent, InputState},
+    label::Label,
+    v_flex,
+};
+use tracing::info;
+
+// Max number of keys listed in the results
+const MAX_RESULTS: usize = 50;
+const RESULTS_MAX_HEIGHT: f32 = 360.0;
+
+/// Quick open palette to jump to a loaded key
+///
+/// Features:
+/// - Fuzzy search across all keys loaded so far, even in collapsed folders
+/// - Up/down to move the selection, Enter or click to open the key
+/// - The opened key is revealed in the key tree
+pub struct ZedisQuickOpen {
+    /// Reference to server state for Redis operations
+    server_state: Entity<ZedisServerState>,
+
+    /// Input field state for the search keyword
+    keyword_state: Entity<InputState>,
+
+    /// Best matching keys of the keyword
+    results: Vec<SharedString>,
+
+    /// Index of the highlighted result
+    selected_index: usize,
+
+    /// Scroll handle to keep the highlighted result visible
+    scroll_handle: ScrollHandle,
+
+    /// Event subscriptions for reactive updates
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ZedisQuickOpen {
+    pub fn new(server_state: Entity<ZedisServerState>, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let keyword_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .clean_on_escape()
+                .placeholder(i18n_key_tree(cx, "quick_open_placeholder"))
+        });
+        keyword_state.update(cx, |state, cx| {
+            state.focus(window, cx);
+        });
+
+        let subscriptions = vec![
+            cx.subscribe_in(&keyword_state, window, |view, _, event, window, cx| match event {
+                InputEvent::Change => {
+                    view.update_results(cx);
+                }
+                InputEvent::PressEnter { .. } => {
+                    view.open_selected(window, cx);
+                }
+                _ => {}
+            }),
+        ];
+
+        info!("Creating new quick open view");
+
+        let mut this = Self {
+            server_state,
+            keyword_state,
+            results: Vec::new(),
+            selected_index: 0,
+            scroll_handle: ScrollHandle::new(),
+            _subscriptions: subscriptions,
+        };
+        this.update_results(cx);
+        this
+    }
+
+    /// Match the keyword against the loaded keys, best matches first
+    fn update_results(&mut self, cx: &mut Context<Self>) {
+        let keyword = self.keyword_state.read(cx).value().trim().to_lowercase();
+        let mut matches: Vec<(usize, SharedString)> = self
+            .server_state
+            .read(cx)
+            .keys()
+            .keys()
+            .filter_map(|key| fuzzy_match(key, &keyword).map(|score| (score, key.clone())))
+            .collect();
+        matches.sort_unstable();
+        matches.truncate(MAX_RESULTS);
+        self.results = matches.into_iter().map(|(_, key)| key).collect();
+        self.selected_index = 0;
+        self.scroll_handle.scroll_to_item(0);
+        cx.notify();
+    }
+
+    fn move_selection(&mut self, down: bool, cx: &mut Context<Self>) {
+        if self.results.is_empty() {
+            return;
+        }
+        self.selected_index = if down {
+            (self.selected_index + 1).min(self.results.len() - 1)
+        } else {
+            self.selected_index.saturating_sub(1)
+        };
+        self.scroll_handle.scroll_to_item(self.selected_index);
+        cx.notify();
+    }
+
+    /// Select the key in the editor and expand its folders in the key tree
+    fn open_key(&mut self, key: SharedString, window: &mut Window, cx: &mut Context<Self>) {
+        self.server_state.update(cx, |state, cx| {
+            state.select_key(key, cx);
+            state.reveal_key(cx);
+        });
+        window.close_dialog(cx);
+    }
+
+    fn open_selected(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(key) = self.results.get(self.selected_index).cloned() {
+            self.open_key(key, window, cx);
+        }
+    }
+}
+
+impl Render for ZedisQuickOpen {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let items = self.results.iter().enumerate().map(|(index, key)| {
+            let open_key = key.clone();
+            div()
+                .id(("quick-open-item", index))
+                .px_2()
+                .py_1()
+                .rounded(cx.theme().radius)
+                .text_ellipsis()
+                .when(index == self.selected_index, |this| this.bg(cx.theme().accent))
+                .hover(|this| this.bg(cx.theme().accent))
+                .child(key.clone())
+                .on_click(cx.listener(move |this, _, window, cx| {
+                    this.open_key(open_key.clone(), window, cx);
+                }))
+        });
+        v_flex()
+            .w_full()
+            .gap_2()
+            // The input does not use up/down, they move the highlighted result
+            .capture_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                match event.keystroke.key.as_str() {
+                    "up" => this.move_selection(false, cx),
+                    "down" => this.move_selection(true, cx),
+                    _ => return,
+                }
+                cx.stop_propagation();
+            }))
+            .child(Input::new(&self.keyword_state).cleanable(true))
+            .when(self.results.is_empty(), |this| {
+                this.child(
+                    Label::new(i18n_key_tree(cx, "quick_open_no_results"))
+                        .text_sm()
+                        .text_color(cx.theme().muted_foreground),
+                )
+            })
+            .child(
+                div()
+                    .id("quick-open-results")
+                    .max_h(px(RESULTS_MAX_HEIGHT))
+                    .overflow_y_scroll()
+                    .track_scroll(&self.scroll_handle)
+                    .font_family(get_font_family())
+                    .text_sm()
+                    .children(items),
+            )
+    }
+}
@@ -13,30 +13,77 @@
 // limitations under the License.
 
 use crate::assets::CustomIconName;
-use crate::components::Card;
-use crate::connection::RedisServer;
-use crate::helpers::{validate_common_string, validate_host, validate_long_string};
-use crate::states::{Route, ZedisGlobalStore, ZedisServerState, i18n_common, i18n_servers};
-use gpui::{App, Entity, Window, div, prelude::*, px};
+use crate::components::{Card, FormDialog, FormField, open_add_form_dialog};
+use crate::connection::{RedisServer, get_connection_manager};
+use crate::helpers::{
+    format_latency, parse_hex_color, validate_common_string, validate_hex_color, validate_host, validate_long_string,
+    validate_port,
+};
+use crate::states::{Route, ServerPing, ZedisGlobalStore, ZedisServerState, i18n_common, i18n_servers};
+use gpui::{App, Entity, SharedString, Subscription, Task, Window, div, prelude::*, px};
 use gpui_component::{
-    ActiveTheme, Colorize, Icon, IconName, WindowExt,
+    ActiveTheme, Colorize, Disableable, Icon, IconName, WindowExt,
     button::{Button, ButtonVariants},
     form::{field, v_form},
+    h_flex,
     input::{Input, InputState, NumberInput},
     label::Label,
+    switch::Switch,
+    v_flex,
 };
 use rust_i18n::t;
-use std::{cell::Cell, rc::Rc};
+use std::{cell::Cell, rc::Rc, time::Duration};
 use substring::Substring;
 use tracing::info;
 
 // Constants for UI layout
 const DEFAULT_REDIS_PORT: u16 = 6379;
+// Preset accent colors for the server color label
+const SERVER_COLOR_PRESETS: [&str; 6] = ["#e5484d", "#f76b15", "#ffc53d", "#30a46c", "#0090ff", "#8e4ec6"];
+const SERVER_COLOR_SWATCH_SIZE: f32 = 20.0;
 const VIEWPORT_BREAKPOINT_SMALL: f32 = 800.0; // Single column
 const VIEWPORT_BREAKPOINT_MEDIUM: f32 = 1200.0; // Two columns
 const UPDATED_AT_SUBSTRING_LENGTH: usize = 10; // Length of date string to display
 const THEME_LIGHTEN_AMOUNT_DARK: f32 = 1.0;
 const THEME_DARKEN_AMOUNT_LIGHT: f32 = 0.02;
+// Interval of pinging the servers while the server list is shown
+const PING_INTERVAL: Duration = Duration::from_secs(30);
+const LATENCY_DOT_SIZE: f32 = 8.0;
+
+/// Ask for the password of a config that does not store it, then test the connection
+fn open_test_password_dialog(
+    server_state: Entity<ZedisServerState>,
+    server: RedisServer,
+    window: &mut Window,
+    cx: &mut App,
+) {
+    let handle_submit = Rc::new(move |values: Vec<SharedString>, window: &mut Window, cx: &mut App| {
+        let Some(password) = values.first().filter(|value| !value.is_empty()) else {
+            return false;
+        };
+        let mut server = server.clone();
+        server.password = Some(password.to_string());
+        server_state.update(cx, |state, cx| {
+            state.test_connection(server, cx);
+        });
+        window.close_dialog(cx);
+        true
+    });
+    open_add_form_dialog(
+        FormDialog {
+            title: i18n_servers(cx, "password_prompt_title"),
+            fields: vec![
+                FormField::new(i18n_common(cx, "password"))
+                    .with_placeholder(i18n_common(cx, "password_placeholder"))
+                    .with_masked()
+                    .with_focus(),
+            ],
+            handle_submit,
+        },
+        window,
+        cx,
+    );
+}
 
 /// Server management view component
 ///
@@ -45,6 +92,7 @@ const THEME_DARKEN_AMOUNT_LIGHT: f32 = 0.02;
 /// - Action buttons (edit, delete)
 /// - Add new server card
 /// - Click to connect functionality
+/// - Latency of every server, pinged periodically while the view is shown
 ///
 /// Uses a responsive grid layout that adjusts columns based on viewport width.
 pub struct ZedisServers {
@@ -55,13 +103,32 @@ pub struct ZedisServers {
     name_state: Entity<InputState>,
     host_state: Entity<InputState>,
     port_state: Entity<InputState>,
+    db_state: Entity<InputState>,
+    scan_count_state: Entity<InputState>,
+    scan_page_limit_state: Entity<InputState>,
+    connect_timeout_state: Entity<InputState>,
+    response_timeout_state: Entity<InputState>,
     username_state: Entity<InputState>,
     password_state: Entity<InputState>,
     master_name_state: Entity<InputState>,
+    proxy_state: Entity<InputState>,
+    ssh_tunnel_state: Entity<InputState>,
+    ssh_key_path_state: Entity<InputState>,
+    ssh_password_state: Entity<InputState>,
+    color_state: Entity<InputState>,
     description_state: Entity<InputState>,
+    /// Whether to ask for the password when connecting instead of storing it
+    prompt_password: Rc<Cell<bool>>,
+    /// Whether to scan and read values from the replicas
+    prefer_replica: Rc<Cell<bool>>,
 
     /// Flag indicating if we're adding a new server (vs editing existing)
     server_id: String,
+
+    /// Background task pinging the servers, stopped when the view is dropped
+    _ping_task: Task<()>,
+    /// Event subscriptions for reactive updates
+    _subscriptions: Vec<Subscription>,
 }
 
 impl ZedisServers {
@@ -81,6 +148,15 @@ impl ZedisServers {
                 .validate(|s, _cx| validate_host(s))
         });
         let port_state = cx.new(|cx| InputState::new(window, cx).placeholder(i18n_common(cx, "port_placeholder")));
+        let db_state = cx.new(|cx| InputState::new(window, cx).placeholder(i18n_servers(cx, "db_placeholder")));
+        let scan_count_state =
+            cx.new(|cx| InputState::new(window, cx).placeholder(i18n_servers(cx, "scan_count_placeholder")));
+        let scan_page_limit_state =
+            cx.new(|cx| InputState::new(window, cx).placeholder(i18n_servers(cx, "scan_page_limit_placeholder")));
+        let connect_timeout_state =
+            cx.new(|cx| InputState::new(window, cx).placeholder(i18n_servers(cx, "connect_timeout_placeholder")));
+        let response_timeout_state =
+            cx.new(|cx| InputState::new(window, cx).placeholder(i18n_servers(cx, "response_timeout_placeholder")));
         let username_state = cx.new(|cx| {
             InputState::new(window, cx)
                 .placeholder(i18n_common(cx, "username_placeholder"))
@@ -102,6 +178,47 @@ impl ZedisServers {
                 .placeholder(i18n_servers(cx, "master_name_placeholder"))
                 .validate(|s, _cx| validate_common_string(s))
         });
+        let proxy_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder(i18n_servers(cx, "proxy_placeholder"))
+                .validate(|s, _cx| validate_long_string(s))
+        });
+        let ssh_tunnel_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder(i18n_servers(cx, "ssh_tunnel_placeholder"))
+                .validate(|s, _cx| validate_long_string(s))
+        });
+        let ssh_key_path_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder(i18n_servers(cx, "ssh_key_path_placeholder"))
+                .validate(|s, _cx| validate_long_string(s))
+        });
+        let ssh_password_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder(i18n_servers(cx, "ssh_password_placeholder"))
+                .validate(|s, _cx| validate_common_string(s))
+                .masked(true)
+        });
+        let color_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder(i18n_servers(cx, "color_placeholder"))
+                .validate(|s, _cx| validate_hex_color(s))
+        });
+        // Re-render when the latencies are updated
+        let subscriptions = vec![cx.observe(&server_state, |_this, _server_state, cx| cx.notify())];
+        let ping_task = cx.spawn(async move |this, cx| {
+            loop {
+                let result = this.update(cx, |this, cx| {
+                    this.server_state.update(cx, |state, cx| {
+                        state.ping_servers(cx);
+                    });
+                });
+                if result.is_err() {
+                    break;
+                }
+                cx.background_executor().timer(PING_INTERVAL).await;
+            }
+        });
         info!("Creating new servers view");
 
         Self {
@@ -109,11 +226,25 @@ impl ZedisServers {
             name_state,
             host_state,
             port_state,
+            db_state,
+            scan_count_state,
+            scan_page_limit_state,
+            connect_timeout_state,
+            response_timeout_state,
             username_state,
             password_state,
             master_name_state,
+            proxy_state,
+            ssh_tunnel_state,
+            ssh_key_path_state,
+            ssh_password_state,
+            color_state,
             description_state,
+            prompt_password: Rc::new(Cell::new(false)),
+            prefer_replica: Rc::new(Cell::new(false)),
             server_id: String::new(),
+            _ping_task: ping_task,
+            _subscriptions: subscriptions,
         }
     }
     /// Fill input fields with server data for editing
@@ -137,10 +268,47 @@ impl ZedisServers {
                 state.set_value(server.port.to_string(), window, cx);
             });
         }
+        self.db_state.update(cx, |state, cx| {
+            let db = server.db.filter(|db| *db != 0).map(|db| db.to_string());
+            state.set_value(db.unwrap_or_default(), window, cx);
+        });
+        self.scan_count_state.update(cx, |state, cx| {
+            let scan_count = server.scan_count.map(|count| count.to_string());
+            state.set_value(scan_count.unwrap_or_default(), window, cx);
+        });
+        self.scan_page_limit_state.update(cx, |state, cx| {
+            let scan_page_limit = server.scan_page_limit.map(|limit| limit.to_string());
+            state.set_value(scan_page_limit.unwrap_or_default(), window, cx);
+        });
+        self.connect_timeout_state.update(cx, |state, cx| {
+            let timeout = server.connect_timeout_ms.map(|ms| ms.to_string());
+            state.set_value(timeout.unwrap_or_default(), window, cx);
+        });
+        self.response_timeout_state.update(cx, |state, cx| {
+            let timeout = server.response_timeout_ms.map(|ms| ms.to_string());
+            state.set_value(timeout.unwrap_or_default(), window, cx);
+        });
 
         self.password_state.update(cx, |state, cx| {
             state.set_value(server.password.clone().unwrap_or_default(), window, cx);
         });
+        self.prompt_password.set(server.prompt_password.unwrap_or(false));
+        self.prefer_replica.set(server.prefer_replica.unwrap_or(false));
+        self.proxy_state.update(cx, |state, cx| {
+            state.set_value(server.proxy.clone().unwrap_or_default(), window, cx);
+        });
+        self.ssh_tunnel_state.update(cx, |state, cx| {
+            state.set_value(server.ssh_tunnel.clone().unwrap_or_default(), window, cx);
+        });
+        self.ssh_key_path_state.update(cx, |state, cx| {
+            state.set_value(server.ssh_key_path.clone().unwrap_or_default(), window, cx);
+        });
+        self.ssh_password_state.update(cx, |state, cx| {
+            state.set_value(server.ssh_password.clone().unwrap_or_default(), window, cx);
+        });
+        self.color_state.update(cx, |state, cx| {
+            state.set_value(server.color.clone().unwrap_or_default(), window, cx);
+        });
         self.description_state.update(cx, |state, cx| {
             state.set_value(server.description.clone().unwrap_or_default(), window, cx);
         });
@@ -187,10 +355,22 @@ impl ZedisServers {
         let name_state = self.name_state.clone();
         let host_state = self.host_state.clone();
         let port_state = self.port_state.clone();
+        let db_state = self.db_state.clone();
+        let scan_count_state = self.scan_count_state.clone();
+        let scan_page_limit_state = self.scan_page_limit_state.clone();
+        let connect_timeout_state = self.connect_timeout_state.clone();
+        let response_timeout_state = self.response_timeout_state.clone();
         let username_state = self.username_state.clone();
         let password_state = self.password_state.clone();
         let master_name_state = self.master_name_state.clone();
+        let proxy_state = self.proxy_state.clone();
+        let ssh_tunnel_state = self.ssh_tunnel_state.clone();
+        let ssh_key_path_state = self.ssh_key_path_state.clone();
+        let ssh_password_state = self.ssh_password_state.clone();
+        let color_state = self.color_state.clone();
         let description_state = self.description_state.clone();
+        let prompt_password = self.prompt_password.clone();
+        let prefer_replica = self.prefer_replica.clone();
         let server_id = self.server_id.clone();
         let is_new = server_id.is_empty();
 
@@ -198,30 +378,82 @@ impl ZedisServers {
         let name_state_clone = name_state.clone();
         let host_state_clone = host_state.clone();
         let port_state_clone = port_state.clone();
+        let db_state_clone = db_state.clone();
+        let scan_count_state_clone = scan_count_state.clone();
+        let scan_page_limit_state_clone = scan_page_limit_state.clone();
+        let connect_timeout_state_clone = connect_timeout_state.clone();
+        let response_timeout_state_clone = response_timeout_state.clone();
         let username_state_clone = username_state.clone();
         let password_state_clone = password_state.clone();
         let master_name_state_clone = master_name_state.clone();
+        let proxy_state_clone = proxy_state.clone();
+        let ssh_tunnel_state_clone = ssh_tunnel_state.clone();
+        let ssh_key_path_state_clone = ssh_key_path_state.clone();
+        let ssh_password_state_clone = ssh_password_state.clone();
+        let color_state_clone = color_state.clone();
         let description_state_clone = description_state.clone();
+        let prompt_password_clone = prompt_password.clone();
+        let prefer_replica_clone = prefer_replica.clone();
         let server_id_clone = server_id.clone();
 
-        let handle_submit = Rc::new(move |window: &mut Window, cx: &mut App| {
+        // Reads the dialog fields on top of the stored config, None if a required field is invalid
+        let read_server = Rc::new(move |cx: &App| -> Option<RedisServer> {
             let name = name_state_clone.read(cx).value();
             let host = host_state_clone.read(cx).value();
-            let port = port_state_clone
+            let port_val = port_state_clone.read(cx).value();
+            // Block submit until the port is valid, the error is shown below the field
+            if name.is_empty() || host.is_empty() || !validate_port(&port_val) {
+                return None;
+            }
+            let port = port_val.parse::<u16>().unwrap_or(DEFAULT_REDIS_PORT);
+            // Database 0 is the default, no need to store it
+            let db = db_state_clone
                 .read(cx)
                 .value()
-                .parse::<u16>()
-                .unwrap_or(DEFAULT_REDIS_PORT);
-            if name.is_empty() || host.is_empty() {
-                return false;
-            }
+                .trim()
+                .parse::<u8>()
+                .ok()
+                .filter(|db| *db != 0);
+            // Empty or 0 keeps the default scan behavior
+            let scan_count = scan_count_state_clone
+                .read(cx)
+                .value()
+                .trim()
+                .parse::<u64>()
+                .ok()
+                .filter(|count| *count != 0);
+            let scan_page_limit = scan_page_limit_state_clone
+                .read(cx)
+                .value()
+                .trim()
+                .parse::<usize>()
+                .ok()
+                .filter(|limit| *limit != 0);
+            // Empty or 0 keeps the default timeouts
+            let connect_timeout_ms = connect_timeout_state_clone
+                .read(cx)
+                .value()
+                .trim()
+                .parse::<u64>()
+                .ok()
+                .filter(|ms| *ms != 0);
+            let response_timeout_ms = response_timeout_state_clone
+                .read(cx)
+                .value()
+                .trim()
+                .parse::<u64>()
+                .ok()
+                .filter(|ms| *ms != 0);
 
+            // The password is entered at connect time, never stored
+            let prompt_password = prompt_password_clone.get();
             let password_val = password_state_clone.read(cx).value();
-            let password = if password_val.is_empty() {
+            let password = if prompt_password || password_val.is_empty() {
                 None
             } else {
                 Some(password_val)
             };
+            let prefer_replica = prefer_replica_clone.get();
             let username_val = username_state_clone.read(cx).value();
             let username = if username_val.is_empty() {
                 None
@@ -234,31 +466,75 @@ impl ZedisServers {
             } else {
                 Some(master_name_val)
             };
+            let proxy_val = proxy_state_clone.read(cx).value();
+            let proxy = if proxy_val.is_empty() { None } else { Some(proxy_val) };
+            let ssh_tunnel_val = ssh_tunnel_state_clone.read(cx).value();
+            let ssh_tunnel = if ssh_tunnel_val.is_empty() {
+                None
+            } else {
+                Some(ssh_tunnel_val)
+            };
+            let ssh_key_path_val = ssh_key_path_state_clone.read(cx).value();
+            let ssh_key_path = if ssh_key_path_val.is_empty() {
+                None
+            } else {
+                Some(ssh_key_path_val)
+            };
+            let ssh_password_val = ssh_password_state_clone.read(cx).value();
+            let ssh_password = if ssh_password_val.is_empty() {
+                None
+            } else {
+                Some(ssh_password_val)
+            };
+            // Only keep complete hex colors
+            let color_val = color_state_clone.read(cx).value();
+            let color = parse_hex_color(&color_val).map(|_| color_val.trim().to_lowercase());
             let desc_val = description_state_clone.read(cx).value();
             let description = if desc_val.is_empty() { None } else { Some(desc_val) };
 
-            server_state_clone.update(cx, |state, cx| {
-                let current_server = state.server(server_id_clone.as_str()).cloned().unwrap_or_default();
-
-                state.update_or_insrt_server(
-                    RedisServer {
-                        id: server_id_clone.clone(),
-                        name: name.to_string(),
-                        host: host.to_string(),
-                        port,
-                        username: username.map(|u| u.to_string()),
-                        password: password.map(|p| p.to_string()),
-                        master_name: master_name.map(|m| m.to_string()),
-                        description: description.map(|d| d.to_string()),
-                        ..current_server
-                    },
-                    cx,
-                );
-            });
-
-            window.close_dialog(cx);
-            true
+            let current_server = server_state_clone
+                .read(cx)
+                .server(server_id_clone.as_str())
+                .cloned()
+                .unwrap_or_default();
+            Some(RedisServer {
+                id: server_id_clone.clone(),
+                name: name.to_string(),
+                host: host.to_string(),
+                port,
+                db,
+                scan_count,
+                scan_page_limit,
+                connect_timeout_ms,
+                response_timeout_ms,
+                username: username.map(|u| u.to_string()),
+                password: password.map(|p| p.to_string()),
+                prompt_password: prompt_password.then_some(true),
+                prefer_replica: prefer_replica.then_some(true),
+                master_name: master_name.map(|m| m.to_string()),
+                proxy: proxy.map(|p| p.to_string()),
+                ssh_tunnel: ssh_tunnel.map(|t| t.to_string()),
+                ssh_key_path: ssh_key_path.map(|k| k.to_string()),
+                ssh_password: ssh_password.map(|p| p.to_string()),
+                color,
+                description: description.map(|d| d.to_string()),
+                ..current_server
+            })
         });
+        let handle_submit = {
+            let read_server = read_server.clone();
+            let server_state = server_state.clone();
+            Rc::new(move |window: &mut Window, cx: &mut App| {
+                let Some(server) = read_server(cx) else {
+                    return false;
+                };
+                server_state.update(cx, |state, cx| {
+                    state.update_or_insrt_server(server, cx);
+                });
+                window.close_dialog(cx);
+                true
+            })
+        };
 
         let focus_handle_done = Cell::new(false);
         window.open_dialog(cx, move |dialog, window, cx| {
@@ -273,10 +549,23 @@ impl ZedisServers {
             let name_label = i18n_common(cx, "name");
             let host_label = i18n_common(cx, "host");
             let port_label = i18n_common(cx, "port");
+            let db_label = i18n_servers(cx, "db");
+            let scan_count_label = i18n_servers(cx, "scan_count");
+            let scan_page_limit_label = i18n_servers(cx, "scan_page_limit");
+            let connect_timeout_label = i18n_servers(cx, "connect_timeout");
+            let response_timeout_label = i18n_servers(cx, "response_timeout");
             let username_label = i18n_common(cx, "username");
             let password_label = i18n_common(cx, "password");
+            let prompt_password_label = i18n_servers(cx, "prompt_password");
+            let prefer_replica_label = i18n_servers(cx, "prefer_replica");
             let description_label = i18n_common(cx, "description");
             let master_name_label = i18n_servers(cx, "master_name");
+            let proxy_label = i18n_servers(cx, "proxy");
+            let ssh_tunnel_label = i18n_servers(cx, "ssh_tunnel");
+            let ssh_key_path_label = i18n_servers(cx, "ssh_key_path");
+            let ssh_password_label = i18n_servers(cx, "ssh_password");
+            let color_label = i18n_servers(cx, "color");
+            let port_invalid = !validate_port(&port_state.read(cx).value());
 
             dialog
                 .title(title)
@@ -296,15 +585,111 @@ impl ZedisServers {
                                 .child(Input::new(&name_state)),
                         )
                         .child(field().label(host_label).child(Input::new(&host_state)))
-                        .child(field().label(port_label).child(NumberInput::new(&port_state)))
+                        .child(
+                            field().label(port_label).child(
+                                v_flex()
+                                    .gap_1()
+                                    .child(NumberInput::new(&port_state))
+                                    .when(port_invalid, |this| {
+                                        this.child(
+                                            Label::new(i18n_servers(cx, "invalid_port"))
+                                                .text_xs()
+                                                .text_color(cx.theme().danger),
+                                        )
+                                    }),
+                            ),
+                        )
+                        .child(field().label(db_label).child(NumberInput::new(&db_state)))
                         .child(field().label(username_label).child(Input::new(&username_state)))
                         .child(
                             field()
                                 .label(password_label)
                                 // Password field with show/hide toggle
-                                .child(Input::new(&password_state).mask_toggle()),
+                                .child(
+                                    Input::new(&password_state)
+                                        .mask_toggle()
+                                        .disabled(prompt_password.get()),
+                                ),
+                        )
+                        .child(
+                            field().label(prompt_password_label).child(
+                                Switch::new("servers-prompt-password")
+                                    .checked(prompt_password.get())
+                                    .label(i18n_servers(cx, "prompt_password_description"))
+                                    .on_click({
+                                        let prompt_password = prompt_password.clone();
+                                        move |checked, window, _cx| {
+                                            prompt_password.set(*checked);
+                                            window.refresh();
+                                        }
+                                    }),
+                            ),
                         )
                         .child(field().label(master_name_label).child(Input::new(&master_name_state)))
+                        .child(
+                            field().label(prefer_replica_label).child(
+                                Switch::new("servers-prefer-replica")
+                                    .checked(prefer_replica.get())
+                                    .label(i18n_servers(cx, "prefer_replica_description"))
+                                    .on_click({
+                                        let prefer_replica = prefer_replica.clone();
+                                        move |checked, window, _cx| {
+                                            prefer_replica.set(*checked);
+                                            window.refresh();
+                                        }
+                                    }),
+                            ),
+                        )
+                        .child(field().label(proxy_label).child(Input::new(&proxy_state)))
+                        .child(field().label(ssh_tunnel_label).child(Input::new(&ssh_tunnel_state)))
+                        .child(field().label(ssh_key_path_label).child(Input::new(&ssh_key_path_state)))
+                        .child(
+                            field()
+                                .label(ssh_password_label)
+                                .child(Input::new(&ssh_password_state).mask_toggle()),
+                        )
+                        .child(
+                            field()
+                                .label(scan_count_label)
+                                .child(NumberInput::new(&scan_count_state)),
+                        )
+                        .child(
+                            field()
+                                .label(scan_page_limit_label)
+                                .child(NumberInput::new(&scan_page_limit_state)),
+                        )
+                        .child(
+                            field()
+                                .label(connect_timeout_label)
+                                .child(NumberInput::new(&connect_timeout_state)),
+                        )
+                        .child(
+                            field()
+                                .label(response_timeout_label)
+                                .child(NumberInput::new(&response_timeout_state)),
+                        )
+                        .child(
+                            field().label(color_label).child(
+                                h_flex()
+                                    .gap_2()
+                                    .child(Input::new(&color_state).flex_1().cleanable(true))
+                                    // Preset swatches fill the color input
+                                    .children(SERVER_COLOR_PRESETS.iter().enumerate().map(|(index, preset)| {
+                                        let color_state = color_state.clone();
+                                        div()
+                                            .id(("servers-color-preset", index))
+                                            .size(px(SERVER_COLOR_SWATCH_SIZE))
+                                            .rounded_full()
+                                            .cursor_pointer()
+                                            .when_some(parse_hex_color(preset), |this, color| this.bg(color))
+                                            .on_click(move |_, window, cx| {
+                                                color_state.update(cx, |state, cx| {
+                                                    state.set_value(*preset, window, cx);
+                                                });
+                                            })
+                                    })),
+                            ),
+                        )
                         .child(field().label(description_label).child(Input::new(&description_state)))
                 })
                 .on_ok({
@@ -313,18 +698,49 @@ impl ZedisServers {
                 })
                 .footer({
                     let handle = handle_submit.clone();
+                    let read_server = read_server.clone();
+                    let server_state = server_state.clone();
+                    let port_state = port_state.clone();
                     move |_, _, _, cx| {
+                        let port_invalid = !validate_port(&port_state.read(cx).value());
+                        let testing = server_state.read(cx).testing_connection();
                         let submit_label = i18n_common(cx, "submit");
                         let cancel_label = i18n_common(cx, "cancel");
 
                         vec![
+                            // Test button - connects with the unsaved field values
+                            Button::new("test-connection")
+                                .label(i18n_servers(cx, "test_connection"))
+                                .loading(testing)
+                                .disabled(testing || port_invalid)
+                                .on_click({
+                                    let read_server = read_server.clone();
+                                    let server_state = server_state.clone();
+                                    move |_, window, cx| {
+                                        let Some(server) = read_server(cx) else {
+                                            return;
+                                        };
+                                        // The password is not stored, ask for it before testing
+                                        if server.prompt_password.unwrap_or(false) {
+                                            open_test_password_dialog(server_state.clone(), server, window, cx);
+                                            return;
+                                        }
+                                        server_state.update(cx, |state, cx| {
+                                            state.test_connection(server, cx);
+                                        });
+                                    }
+                                }),
                             // Submit button - validates and saves server configuration
-                            Button::new("ok").primary().label(submit_label).on_click({
-                                let handle = handle.clone();
-                                move |_, window, cx| {
-                                    handle.clone()(window, cx);
-                                }
-                            }),
+                            Button::new("ok")
+                                .primary()
+                                .label(submit_label)
+                                .disabled(port_invalid)
+                                .on_click({
+                                    let handle = handle.clone();
+                                    move |_, window, cx| {
+                                        handle.clone()(window, cx);
+                                    }
+                                }),
                             // Cancel button - closes dialog without saving
                             Button::new("cancel").label(cancel_label).on_click(|_, window, cx| {
                                 window.close_dialog(cx);
@@ -362,6 +778,10 @@ impl Render for ZedisServers {
 
         let update_tooltip = i18n_servers(cx, "update_tooltip");
         let remove_tooltip = i18n_servers(cx, "remove_tooltip");
+        let disconnect_tooltip = i18n_servers(cx, "disconnect_tooltip");
+        let unreachable = i18n_servers(cx, "unreachable");
+        let password_required = i18n_servers(cx, "password_required");
+        let server_latencies = self.server_state.read(cx).server_latencies().clone();
 
         // Build card for each configured server
         let children: Vec<_> = self
@@ -376,6 +796,8 @@ impl Render for ZedisServers {
                 let select_server_id = server.id.clone();
                 let update_server = server.clone();
                 let remove_server_id = server.id.clone();
+                let disconnect_server_id = server.id.clone();
+                let is_connected = get_connection_manager().has_client(&server.id);
 
                 let description = server.description.as_deref().unwrap_or_default();
 
@@ -386,10 +808,18 @@ impl Render for ZedisServers {
                     String::new()
                 };
 
-                let title = format!("{} ({}:{})", server.name, server.host, server.port);
+                // Not pinged yet: no latency is shown
+                let latency = server_latencies.get(&server.id).map(|ping| match ping {
+                    ServerPing::Latency(latency) => format_latency(Some(*latency), cx),
+                    ServerPing::PasswordRequired => (password_required.clone(), cx.theme().warning),
+                    ServerPing::Unreachable => (unreachable.clone(), cx.theme().red),
+                });
+
+                let title = format!("{} ({})", server.name, server.display_address());
+                let accent = server.color.as_deref().and_then(parse_hex_color);
 
                 // Action buttons for each server card
-                let actions = vec![
+                let mut actions = vec![
                     // Edit button - opens dialog to modify server configuration
                     Button::new(("servers-card-action-select", index))
                         .ghost()
@@ -410,6 +840,22 @@ impl Render for ZedisServers {
                             this.remove_server(window, cx, &remove_server_id);
                         })),
                 ];
+                // Disconnect button - only for servers with a cached connection
+                if is_connected {
+                    actions.push(
+                        Button::new(("servers-card-action-disconnect", index))
+                            .ghost()
+                            .tooltip(disconnect_tooltip.clone())
+                            .icon(CustomIconName::Unplug)
+                            .on_click(cx.listener(move |this, _, _, cx| {
+                                cx.stop_propagation(); // Don't trigger card click
+                                this.server_state.update(cx, |state, cx| {
+                                    state.disconnect(&disconnect_server_id, cx);
+                                });
+                                cx.notify();
+                            })),
+                    );
+                }
 
                 // Card click handler - connect to server and navigate to editor
                 let handle_select_server = cx.listener(move |this, _, _, cx| {
@@ -430,19 +876,31 @@ impl Render for ZedisServers {
 
                 // Build server card with conditional footer
                 Card::new(("servers-card", index))
-                    .icon(Icon::new(CustomIconName::DatabaseZap))
+                    .icon(
+                        Icon::new(CustomIconName::DatabaseZap).when_some(accent, |this, color| this.text_color(color)),
+                    )
                     .title(title)
                     .bg(bg)
                     .when(!description.is_empty(), |this| {
                         this.description(description.to_string())
                     })
-                    .when(!updated_at.is_empty(), |this| {
+                    .when(latency.is_some() || !updated_at.is_empty(), |this| {
                         this.footer(
-                            Label::new(updated_at)
-                                .text_sm()
-                                .text_right()
-                                .whitespace_normal()
-                                .text_color(cx.theme().muted_foreground),
+                            h_flex()
+                                .w_full()
+                                .gap_2()
+                                .when_some(latency, |this, (latency, color)| {
+                                    this.child(div().size(px(LATENCY_DOT_SIZE)).rounded_full().bg(color))
+                                        .child(Label::new(latency).text_sm().text_color(color))
+                                })
+                                .child(
+                                    Label::new(updated_at)
+                                        .flex_1()
+                                        .text_sm()
+                                        .text_right()
+                                        .whitespace_normal()
+                                        .text_color(cx.theme().muted_foreground),
+                                ),
                         )
                     })
                     .actions(actions)
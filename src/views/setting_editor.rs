@@ -13,20 +13,29 @@
 // limitations under the License.
 
 use crate::{
-    helpers::get_or_create_config_dir,
-    states::{ZedisGlobalStore, i18n_settings, update_app_state_and_save},
+    helpers::{get_installed_font_names, get_or_create_config_dir},
+    states::{RowDensity, ZedisGlobalStore, i18n_settings, update_app_state_and_save},
 };
-use gpui::{Entity, Subscription, Window, prelude::*};
+use gpui::{Entity, SharedString, Subscription, Window, prelude::*};
 use gpui_component::{
+    IndexPath,
     form::{field, v_form},
     input::{Input, InputEvent, InputState, NumberInput},
     label::Label,
+    radio::RadioGroup,
+    select::{SearchableVec, Select, SelectEvent, SelectState},
+    switch::Switch,
     v_flex,
 };
 
 pub struct ZedisSettingEditor {
     max_key_tree_depth_state: Entity<InputState>,
+    auto_expand_threshold_state: Entity<InputState>,
+    max_import_size_state: Entity<InputState>,
+    max_string_load_size_state: Entity<InputState>,
+    hex_bytes_per_line_state: Entity<InputState>,
     config_dir_state: Entity<InputState>,
+    editor_font_family_state: Entity<SelectState<SearchableVec<SharedString>>>,
     _subscriptions: Vec<Subscription>,
 }
 
@@ -40,33 +49,154 @@ impl ZedisSettingEditor {
                 .default_value(max_key_tree_depth.to_string())
         });
 
+        let auto_expand_threshold = store.auto_expand_threshold();
+        let auto_expand_threshold_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder(i18n_settings(cx, "auto_expand_threshold_placeholder"))
+                .default_value(auto_expand_threshold.to_string())
+        });
+
+        let max_import_size = store.max_import_size_mb();
+        let max_import_size_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder(i18n_settings(cx, "max_import_size_placeholder"))
+                .default_value(max_import_size.to_string())
+        });
+
+        let max_string_load_size = store.max_string_load_size_mb();
+        let max_string_load_size_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder(i18n_settings(cx, "max_string_load_size_placeholder"))
+                .default_value(max_string_load_size.to_string())
+        });
+
+        let hex_bytes_per_line = store.hex_bytes_per_line();
+        let hex_bytes_per_line_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder(i18n_settings(cx, "hex_bytes_per_line_placeholder"))
+                .default_value(hex_bytes_per_line.map(|width| width.to_string()).unwrap_or_default())
+        });
+
+        // The first option resets to the platform default font
+        let default_font_family = i18n_settings(cx, "editor_font_family_default");
+        let mut font_families = vec![default_font_family.clone()];
+        font_families.extend(get_installed_font_names(cx).iter().cloned());
+        let editor_font_family = store.editor_font_family().map(|name| name.to_string());
+        let selected_font_family = editor_font_family
+            .and_then(|name| font_families.iter().position(|item| &**item == name.as_str()))
+            .unwrap_or_default();
+        let editor_font_family_state = cx.new(|cx| {
+            SelectState::new(
+                SearchableVec::new(font_families),
+                Some(IndexPath::new(selected_font_family)),
+                window,
+                cx,
+            )
+        });
+
         let config_dir = get_or_create_config_dir().unwrap_or_default();
 
         let mut subscriptions = Vec::new();
         subscriptions.push(
             cx.subscribe_in(&max_key_tree_depth_state, window, |_view, state, event, _window, cx| {
                 if let InputEvent::Blur = &event {
-                    let text = state.read(cx).value();
-                    let value = text.parse::<i64>().unwrap_or_default();
+                    // negative or invalid input resets to the default depth,
+                    // the remaining parts of deeper keys are shown in the leaf name
+                    let value = state.read(cx).value().parse::<usize>().unwrap_or_default();
                     update_app_state_and_save(cx, "save_max_key_tree_depth", move |state, _cx| {
-                        state.set_max_key_tree_depth(value as usize);
+                        state.set_max_key_tree_depth(value);
+                    });
+                }
+            }),
+        );
+        subscriptions.push(cx.subscribe_in(
+            &auto_expand_threshold_state,
+            window,
+            |_view, state, event, _window, cx| {
+                if let InputEvent::Blur = &event {
+                    // empty or invalid input resets to the default threshold
+                    let value = state.read(cx).value().parse::<usize>().ok();
+                    update_app_state_and_save(cx, "save_auto_expand_threshold", move |state, _cx| {
+                        state.set_auto_expand_threshold(value);
+                    });
+                }
+            },
+        ));
+        subscriptions.push(
+            cx.subscribe_in(&max_import_size_state, window, |_view, state, event, _window, cx| {
+                if let InputEvent::Blur = &event {
+                    // empty, zero or invalid input resets to the default size
+                    let value = state.read(cx).value().parse::<u64>().ok();
+                    update_app_state_and_save(cx, "save_max_import_size", move |state, _cx| {
+                        state.set_max_import_size_mb(value);
                     });
                 }
             }),
         );
+        subscriptions.push(cx.subscribe_in(
+            &max_string_load_size_state,
+            window,
+            |_view, state, event, _window, cx| {
+                if let InputEvent::Blur = &event {
+                    // empty, zero or invalid input resets to the default size
+                    let value = state.read(cx).value().parse::<u64>().ok();
+                    update_app_state_and_save(cx, "save_max_string_load_size", move |state, _cx| {
+                        state.set_max_string_load_size_mb(value);
+                    });
+                }
+            },
+        ));
+        subscriptions.push(
+            cx.subscribe_in(&hex_bytes_per_line_state, window, |_view, state, event, _window, cx| {
+                if let InputEvent::Blur = &event {
+                    // empty, zero or invalid input fits the hex view to the viewport
+                    let value = state.read(cx).value().parse::<usize>().ok();
+                    update_app_state_and_save(cx, "save_hex_bytes_per_line", move |state, _cx| {
+                        state.set_hex_bytes_per_line(value);
+                    });
+                }
+            }),
+        );
+        subscriptions.push(cx.subscribe_in(
+            &editor_font_family_state,
+            window,
+            move |_view, _state, event: &SelectEvent<SearchableVec<SharedString>>, _window, cx| match event {
+                SelectEvent::Confirm(value) => {
+                    let value = value
+                        .as_ref()
+                        .filter(|value| **value != default_font_family)
+                        .map(|value| value.to_string());
+                    update_app_state_and_save(cx, "save_editor_font_family", move |state, _cx| {
+                        state.set_editor_font_family(value);
+                    });
+                }
+            },
+        ));
         let config_dir_state =
             cx.new(|cx| InputState::new(window, cx).default_value(config_dir.to_string_lossy().to_string()));
 
         Self {
             _subscriptions: subscriptions,
             config_dir_state,
+            editor_font_family_state,
             max_key_tree_depth_state,
+            auto_expand_threshold_state,
+            max_import_size_state,
+            max_string_load_size_state,
+            hex_bytes_per_line_state,
         }
     }
 }
 
 impl Render for ZedisSettingEditor {
     fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let store = cx.global::<ZedisGlobalStore>().read(cx);
+        let refresh_on_focus = store.refresh_on_focus();
+        let number_separators = store.number_separators();
+        let row_density = store.row_density();
+        let zebra_stripes = store.zebra_stripes();
+        let live_filter = store.live_filter();
+        let restore_last_server = store.restore_last_server();
         v_flex()
             .p_5()
             .child(Label::new(i18n_settings(cx, "title")).text_3xl().mb_2())
@@ -79,6 +209,116 @@ impl Render for ZedisSettingEditor {
                             .label(i18n_settings(cx, "max_key_tree_depth"))
                             .child(NumberInput::new(&self.max_key_tree_depth_state)),
                     )
+                    .child(
+                        field()
+                            .label(i18n_settings(cx, "auto_expand_threshold"))
+                            .child(NumberInput::new(&self.auto_expand_threshold_state)),
+                    )
+                    .child(
+                        field()
+                            .label(i18n_settings(cx, "max_import_size"))
+                            .child(NumberInput::new(&self.max_import_size_state)),
+                    )
+                    .child(
+                        field()
+                            .label(i18n_settings(cx, "max_string_load_size"))
+                            .child(NumberInput::new(&self.max_string_load_size_state)),
+                    )
+                    .child(
+                        field()
+                            .label(i18n_settings(cx, "hex_bytes_per_line"))
+                            .child(NumberInput::new(&self.hex_bytes_per_line_state)),
+                    )
+                    .child(
+                        field().label(i18n_settings(cx, "refresh_on_focus")).child(
+                            Switch::new("refresh-on-focus")
+                                .checked(refresh_on_focus)
+                                .label(i18n_settings(cx, "refresh_on_focus_description"))
+                                .on_click(|checked, _window, cx| {
+                                    let checked = *checked;
+                                    update_app_state_and_save(cx, "save_refresh_on_focus", move |state, _cx| {
+                                        state.set_refresh_on_focus(checked);
+                                    });
+                                }),
+                        ),
+                    )
+                    .child(
+                        field().label(i18n_settings(cx, "number_separators")).child(
+                            Switch::new("number-separators")
+                                .checked(number_separators)
+                                .label(i18n_settings(cx, "number_separators_description"))
+                                .on_click(|checked, _window, cx| {
+                                    let checked = *checked;
+                                    update_app_state_and_save(cx, "save_number_separators", move |state, _cx| {
+                                        state.set_number_separators(checked);
+                                    });
+                                }),
+                        ),
+                    )
+                    .child(
+                        field().label(i18n_settings(cx, "row_density")).child(
+                            RadioGroup::horizontal("row-density")
+                                .children(vec![
+                                    i18n_settings(cx, "row_density_compact"),
+                                    i18n_settings(cx, "row_density_comfortable"),
+                                ])
+                                .selected_index(Some(if row_density == RowDensity::Compact { 0 } else { 1 }))
+                                .on_click(|index, _window, cx| {
+                                    let row_density = if *index == 0 {
+                                        RowDensity::Compact
+                                    } else {
+                                        RowDensity::Comfortable
+                                    };
+                                    update_app_state_and_save(cx, "save_row_density", move |state, _cx| {
+                                        state.set_row_density(row_density);
+                                    });
+                                }),
+                        ),
+                    )
+                    .child(
+                        field()
+                            .label(i18n_settings(cx, "editor_font_family"))
+                            .child(Select::new(&self.editor_font_family_state)),
+                    )
+                    .child(
+                        field().label(i18n_settings(cx, "zebra_stripes")).child(
+                            Switch::new("zebra-stripes")
+                                .checked(zebra_stripes)
+                                .label(i18n_settings(cx, "zebra_stripes_description"))
+                                .on_click(|checked, _window, cx| {
+                                    let checked = *checked;
+                                    update_app_state_and_save(cx, "save_zebra_stripes", move |state, _cx| {
+                                        state.set_zebra_stripes(checked);
+                                    });
+                                }),
+                        ),
+                    )
+                    .child(
+                        field().label(i18n_settings(cx, "live_filter")).child(
+                            Switch::new("live-filter")
+                                .checked(live_filter)
+                                .label(i18n_settings(cx, "live_filter_description"))
+                                .on_click(|checked, _window, cx| {
+                                    let checked = *checked;
+                                    update_app_state_and_save(cx, "save_live_filter", move |state, _cx| {
+                                        state.set_live_filter(checked);
+                                    });
+                                }),
+                        ),
+                    )
+                    .child(
+                        field().label(i18n_settings(cx, "restore_last_server")).child(
+                            Switch::new("restore-last-server")
+                                .checked(restore_last_server)
+                                .label(i18n_settings(cx, "restore_last_server_description"))
+                                .on_click(|checked, _window, cx| {
+                                    let checked = *checked;
+                                    update_app_state_and_save(cx, "save_restore_last_server", move |state, _cx| {
+                                        state.set_restore_last_server(checked);
+                                    });
+                                }),
+                        ),
+                    )
                     .child(
                         field()
                             .label(i18n_settings(cx, "config_dir"))
@@ -14,13 +14,15 @@
 
 use crate::{
     assets::CustomIconName,
-    helpers::{is_development, is_linux},
+    helpers::{is_development, is_linux, parse_hex_color},
     states::{
         FontSize, FontSizeAction, LocaleAction, Route, ServerEvent, SettingsAction, ThemeAction, ZedisGlobalStore,
         ZedisServerState, i18n_sidebar,
     },
 };
-use gpui::{Context, Corner, Entity, Pixels, SharedString, Subscription, Window, div, prelude::*, px, uniform_list};
+use gpui::{
+    Context, Corner, Entity, Hsla, Pixels, SharedString, Subscription, Window, div, prelude::*, px, uniform_list,
+};
 use gpui_component::{
     ActiveTheme, Icon, IconName, ThemeMode,
     button::{Button, ButtonVariants},
@@ -47,7 +49,7 @@ const SETTINGS_ICON_SIZE: f32 = 18.0;
 struct SidebarState {
     /// List of (server_id, server_name) tuples for display
     /// First entry is always (empty, empty) representing the home page
-    server_names: Vec<(SharedString, SharedString)>,
+    server_names: Vec<(SharedString, SharedString, Option<Hsla>)>,
 
     /// Currently selected server ID (empty string means home page)
     server_id: SharedString,
@@ -125,19 +127,21 @@ impl ZedisSidebar {
     /// Update cached server list from server state
     ///
     /// Rebuilds the server_names list with:
-    /// - First entry: (empty, empty) for home page
-    /// - Remaining entries: (server_id, server_name) for each configured server
+    /// - First entry: (empty, empty, None) for home page
+    /// - Remaining entries: (server_id, server_name, accent color) for each configured server
     fn update_server_names(&mut self, cx: &mut Context<Self>) {
         // Start with home page entry
-        let mut server_names = vec![(SharedString::default(), SharedString::default())];
+        let mut server_names = vec![(SharedString::default(), SharedString::default(), None)];
 
         let server_state = self.server_state.read(cx);
         if let Some(servers) = server_state.servers() {
-            server_names.extend(
-                servers
-                    .iter()
-                    .map(|server| (server.id.clone().into(), server.name.clone().into())),
-            );
+            server_names.extend(servers.iter().map(|server| {
+                (
+                    server.id.clone().into(),
+                    server.name.clone().into(),
+                    server.color.as_deref().and_then(parse_hex_color),
+                )
+            }));
         }
         self.state.server_names = server_names;
     }
@@ -156,7 +160,7 @@ impl ZedisSidebar {
         let current_server_id_clone = self.state.server_id.clone();
         let is_match_route = matches!(
             cx.global::<ZedisGlobalStore>().read(cx).route(),
-            Route::Home | Route::Editor
+            Route::Home | Route::Editor | Route::PubSub | Route::Config | Route::Dashboard
         );
 
         let home_label = i18n_sidebar(cx, "home");
@@ -166,7 +170,7 @@ impl ZedisSidebar {
         uniform_list("sidebar-redis-servers", servers.len(), move |range, _window, _cx| {
             range
                 .map(|index| {
-                    let (server_id, server_name) = servers.get(index).cloned().unwrap_or_default();
+                    let (server_id, server_name, accent) = servers.get(index).cloned().unwrap_or_default();
 
                     let is_home = server_id.is_empty();
                     let is_current = is_match_route && server_id == current_server_id_clone;
@@ -189,7 +193,10 @@ impl ZedisSidebar {
                         .child(
                             v_flex()
                                 .items_center()
-                                .child(Icon::new(IconName::LayoutDashboard))
+                                .child(
+                                    Icon::new(IconName::LayoutDashboard)
+                                        .when_some(accent, |this, color| this.text_color(color)),
+                                )
                                 .child(Label::new(name).text_ellipsis().text_xs()),
                         )
                         .on_click(move |_, _window, cx| {
@@ -220,6 +227,31 @@ impl ZedisSidebar {
         .size_full()
     }
 
+    /// Render the button to disconnect from the selected server
+    ///
+    /// Drops the cached connection and navigates back to the home page.
+    fn render_disconnect_button(&self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let server_id = self.state.server_id.clone();
+        div().border_t_1().border_color(cx.theme().border).child(
+            Button::new("zedis-sidebar-disconnect-btn")
+                .ghost()
+                .w_full()
+                .h(px(SETTINGS_BUTTON_HEIGHT))
+                .tooltip(i18n_sidebar(cx, "disconnect_tooltip"))
+                .child(Icon::new(CustomIconName::Unplug).size(px(SETTINGS_ICON_SIZE)))
+                .on_click(cx.listener(move |this, _, _, cx| {
+                    this.server_state.update(cx, |state, cx| {
+                        state.disconnect(&server_id, cx);
+                    });
+                    cx.update_global::<ZedisGlobalStore, ()>(|store, cx| {
+                        store.update(cx, |state, cx| {
+                            state.go_to(Route::Home, cx);
+                        });
+                    });
+                })),
+        )
+    }
+
     /// Render settings button with dropdown menu
     ///
     /// The dropdown contains two submenus:
@@ -371,10 +403,12 @@ impl Render for ZedisSidebar {
     /// Layout structure (top to bottom):
     /// 1. GitHub star button
     /// 2. Server list (scrollable, takes remaining space)
-    /// 3. Settings button (theme & language)
+    /// 3. Disconnect button (only when a server is selected)
+    /// 4. Settings button (theme & language)
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         tracing::debug!("Rendering sidebar view");
         let show_settings_button = is_linux() || is_development();
+        let show_disconnect_button = !self.state.server_id.is_empty();
 
         v_flex()
             .size_full()
@@ -387,6 +421,9 @@ impl Render for ZedisSidebar {
                 // Server list takes up remaining vertical space
                 div().flex_1().size_full().child(self.render_server_list(window, cx)),
             )
+            .when(show_disconnect_button, |this| {
+                this.child(self.render_disconnect_button(window, cx))
+            })
             .when(show_settings_button, |this| {
                 this.child(self.render_settings_button(window, cx))
             })
@@ -15,22 +15,41 @@
 use crate::{
     assets::CustomIconName,
     connection::RedisClientDescription,
+    helpers::{format_latency, parse_hex_color},
     states::{
-        ErrorMessage, ServerEvent, ServerTask, ViewMode, ZedisServerState, i18n_common, i18n_sidebar, i18n_status_bar,
+        ErrorMessage, HIGHLIGHT_LANGUAGES, Route, ServerEvent, ServerTask, ViewMode, ZedisGlobalStore,
+        ZedisServerState, i18n_common, i18n_sidebar, i18n_status_bar,
     },
+    views::ZedisAuditLog,
 };
-use gpui::{Entity, Hsla, SharedString, Subscription, Task, TextAlign, Window, div, prelude::*};
+use gpui::{ClipboardItem, Entity, Hsla, SharedString, Subscription, Task, TextAlign, Window, div, prelude::*, px};
 use gpui_component::select::{SearchableVec, Select, SelectEvent, SelectState};
 use gpui_component::{
-    ActiveTheme, Disableable, Icon, IconName, IndexPath, Sizable,
+    ActiveTheme, Disableable, Icon, IconName, IndexPath, Sizable, WindowExt,
     button::{Button, ButtonVariants},
     h_flex,
     label::Label,
+    notification::Notification,
     tooltip::Tooltip,
 };
+use rust_i18n::t;
 use std::{sync::Arc, time::Duration};
 use tracing::info;
 
+const AUDIT_LOG_DIALOG_WIDTH: f32 = 800.0;
+
+// View modes of string values, in the order of the viewer select
+const VIEW_MODES: [ViewMode; 8] = [
+    ViewMode::Auto,
+    ViewMode::Plain,
+    ViewMode::Hex,
+    ViewMode::Decompressed,
+    ViewMode::MessagePack,
+    ViewMode::Base64,
+    ViewMode::Utf16Le,
+    ViewMode::Utf16Be,
+];
+
 /// Formats the database size and scan count string "count/total".
 #[inline]
 fn format_size(dbsize: Option<u64>, scan_count: usize) -> SharedString {
@@ -41,31 +60,6 @@ fn format_size(dbsize: Option<u64>, scan_count: usize) -> SharedString {
     }
     .into()
 }
-/// Formats the latency string and determines the color based on the delay.
-#[inline]
-fn format_latency(latency: Option<Duration>, cx: &Context<ZedisStatusBar>) -> (SharedString, Hsla) {
-    if let Some(latency) = latency {
-        let ms = latency.as_millis();
-        let theme = cx.theme();
-        // Determine color based on latency thresholds
-        let color = if ms < 50 {
-            theme.green
-        } else if ms < 500 {
-            theme.yellow
-        } else {
-            theme.red
-        };
-        // Format string
-        if ms < 1000 {
-            (format!("{ms}ms").into(), color)
-        } else {
-            (format!("{:.2}s", ms as f64 / 1000.0).into(), color)
-        }
-    } else {
-        ("--".to_string().into(), cx.theme().primary)
-    }
-}
-
 /// Formats the node count and version information.
 #[inline]
 fn format_nodes(nodes: (usize, usize), version: &str) -> SharedString {
@@ -98,6 +92,8 @@ struct StatusBarServerState {
     nodes: SharedString,
     scan_finished: bool,
     soft_wrap: bool,
+    server_type: SharedString,
+    address: SharedString,
     nodes_description: SharedString,
 }
 
@@ -107,6 +103,7 @@ struct StatusBarServerState {
 struct StatusBarState {
     server_state: StatusBarServerState,
     data_format: Option<SharedString>,
+    delete_progress: Option<SharedString>,
     error: Option<ErrorMessage>,
 }
 
@@ -114,7 +111,11 @@ pub struct ZedisStatusBar {
     state: StatusBarState,
 
     viewer_mode_state: Entity<SelectState<SearchableVec<SharedString>>>,
+    language_state: Entity<SelectState<SearchableVec<SharedString>>>,
+    // Reset the viewer and language selects to the loaded value
     should_reset_viewer_mode: bool,
+    viewer_mode_index: usize,
+    language_index: usize,
     server_state: Entity<ZedisServerState>,
     heartbeat_task: Option<Task<()>>,
     _subscriptions: Vec<Subscription>,
@@ -129,6 +130,9 @@ impl ZedisStatusBar {
             match event {
                 ServerEvent::ServerSelected(_) => {
                     this.state.data_format = None;
+                    this.state.delete_progress = None;
+                    // Drop the loop of the previous server, the new one starts its own
+                    this.start_heartbeat(server_state, cx);
                 }
                 ServerEvent::ServerRedisInfoUpdated(_) => {
                     this.fill_state(server_state, cx);
@@ -150,6 +154,21 @@ impl ZedisStatusBar {
                     let state = server_state.read(cx);
                     this.state.server_state.size = format_size(state.dbsize(), state.scan_count());
                 }
+                ServerEvent::KeysDeleteProgress(deleted, total) => {
+                    let state = server_state.read(cx);
+                    this.state.server_state.size = format_size(state.dbsize(), state.scan_count());
+                    // Keep the progress only while the bulk delete is running
+                    this.state.delete_progress = state.delete_keys_progress().map(|_| {
+                        t!(
+                            "status_bar.deleting_keys",
+                            deleted = deleted,
+                            total = total,
+                            locale = cx.global::<ZedisGlobalStore>().read(cx).locale()
+                        )
+                        .to_string()
+                        .into()
+                    });
+                }
                 ServerEvent::ErrorOccurred(error) => {
                     this.state.error = Some(error.clone());
                 }
@@ -162,7 +181,19 @@ impl ZedisStatusBar {
                 ServerEvent::ValueLoaded(_) => {
                     let state = server_state.read(cx);
                     this.should_reset_viewer_mode = true;
+                    this.viewer_mode_index = 0;
+                    this.language_index = 0;
                     if let Some(value) = state.value().and_then(|item| item.bytes_value()) {
+                        this.language_index = value
+                            .highlight_language
+                            .as_ref()
+                            .and_then(|language| HIGHLIGHT_LANGUAGES.iter().position(|item| item == language))
+                            .unwrap_or_default();
+                        // The view mode may be restored from the last used one
+                        this.viewer_mode_index = VIEW_MODES
+                            .iter()
+                            .position(|mode| *mode == value.view_mode)
+                            .unwrap_or_default();
                         let mut format = value.format.as_str().to_string();
                         if let Some(mime) = &value.mime {
                             format = format!("{}({})", format, mime);
@@ -180,11 +211,12 @@ impl ZedisStatusBar {
         }));
         let viewer_mode_state = cx.new(|cx| {
             SelectState::new(
-                SearchableVec::new(vec![
-                    ViewMode::Auto.as_str().into(),
-                    ViewMode::Plain.as_str().into(),
-                    ViewMode::Hex.as_str().into(),
-                ]),
+                SearchableVec::new(
+                    VIEW_MODES
+                        .iter()
+                        .map(|mode| mode.as_str().into())
+                        .collect::<Vec<SharedString>>(),
+                ),
                 Some(IndexPath::new(0)),
                 window,
                 cx,
@@ -203,12 +235,41 @@ impl ZedisStatusBar {
                 }
             },
         ));
+        let language_state = cx.new(|cx| {
+            SelectState::new(
+                SearchableVec::new(
+                    HIGHLIGHT_LANGUAGES
+                        .iter()
+                        .map(|language| SharedString::from(*language))
+                        .collect::<Vec<SharedString>>(),
+                ),
+                Some(IndexPath::new(0)),
+                window,
+                cx,
+            )
+        });
+        subscriptions.push(cx.subscribe_in(
+            &language_state,
+            window,
+            |view, _state, event: &SelectEvent<SearchableVec<SharedString>>, _window, cx| match event {
+                SelectEvent::Confirm(value) => {
+                    if let Some(selected_value) = value {
+                        view.server_state.update(cx, |state, cx| {
+                            state.update_bytes_value_language(selected_value.clone(), cx);
+                        });
+                    }
+                }
+            },
+        ));
         let mut this = Self {
             heartbeat_task: None,
             viewer_mode_state,
+            language_state,
             server_state: server_state.clone(),
             _subscriptions: subscriptions,
             should_reset_viewer_mode: false,
+            viewer_mode_index: 0,
+            language_index: 0,
             state: StatusBarState { ..Default::default() },
         };
         this.fill_state(server_state.clone(), cx);
@@ -231,15 +292,30 @@ impl ZedisStatusBar {
             nodes: format_nodes(state.nodes(), state.version()),
             scan_finished: state.scan_completed(),
             soft_wrap: state.soft_wrap(),
+            server_type: state.nodes_description().server_type.clone(),
+            address: state
+                .server(state.server_id())
+                .map(|server| server.display_address().into())
+                .unwrap_or_default(),
             nodes_description: format_nodes_description(state.nodes_description().clone(), cx),
         };
     }
     /// Start the heartbeat task
+    ///
+    /// Replacing the task cancels the previous loop. Ticks are skipped on the
+    /// server list (Home), as there is no active connection to check.
     fn start_heartbeat(&mut self, server_state: Entity<ZedisServerState>, cx: &mut Context<Self>) {
         // start task
         self.heartbeat_task = Some(cx.spawn(async move |_this, cx| {
             loop {
                 cx.background_executor().timer(Duration::from_secs(30)).await;
+                let Ok(is_home) = cx.update(|cx| cx.global::<ZedisGlobalStore>().read(cx).route() == Route::Home)
+                else {
+                    break;
+                };
+                if is_home {
+                    continue;
+                }
                 let _ = server_state.update(cx, |state, cx| {
                     state.refresh_redis_info(cx);
                 });
@@ -250,7 +326,23 @@ impl ZedisStatusBar {
     fn render_server_status(&self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         let server_state = &self.state.server_state;
         let is_completed = server_state.scan_finished;
+        let scanning_all = self.server_state.read(cx).scanning_all();
         let nodes_description = server_state.nodes_description.clone();
+        // Show what is actually sent to the server for the current scan
+        let scan_info: SharedString = {
+            let state = self.server_state.read(cx);
+            let (requests, times) = state.scan_requests();
+            t!(
+                "status_bar.scan_info",
+                pattern = state.scan_pattern(),
+                count = state.scan_batch_count(),
+                requests = requests,
+                times = times,
+                locale = cx.global::<ZedisGlobalStore>().read(cx).locale()
+            )
+            .to_string()
+            .into()
+        };
         h_flex()
             .items_center()
             .child(
@@ -284,12 +376,79 @@ impl ZedisStatusBar {
                         });
                     })),
             )
-            .child(Label::new(server_state.size.clone()).mr_4())
+            .child(
+                // Load every remaining page, click again to stop
+                Button::new("zedis-status-bar-scan-all")
+                    .outline()
+                    .small()
+                    .disabled(is_completed && !scanning_all)
+                    .tooltip(if scanning_all {
+                        i18n_status_bar(cx, "stop_scan_all")
+                    } else {
+                        i18n_status_bar(cx, "scan_all_keys")
+                    })
+                    .mr_1()
+                    .when(scanning_all, |this| this.icon(CustomIconName::X))
+                    .when(!scanning_all, |this| this.icon(CustomIconName::ArrowDownToLine))
+                    .on_click(cx.listener(move |this, _, _window, cx| {
+                        this.server_state.update(cx, |state, cx| {
+                            if scanning_all {
+                                state.stop_scan_all(cx);
+                            } else {
+                                state.scan_all(cx);
+                            }
+                        });
+                        cx.notify();
+                    })),
+            )
+            .child(
+                div()
+                    .id("zedis-status-bar-scan-info")
+                    .child(
+                        Label::new(server_state.size.clone())
+                            .mr_4()
+                            .when(scanning_all, |this| this.text_color(cx.theme().yellow)),
+                    )
+                    .tooltip(move |window, cx| Tooltip::new(scan_info.clone()).build(window, cx)),
+            )
+            .when_some(self.state.delete_progress.clone(), |this, progress| {
+                this.child(Label::new(progress).text_color(cx.theme().yellow).mr_4())
+            })
+            .child(
+                // Address of the server, click to copy
+                div()
+                    .id("zedis-status-bar-address")
+                    .cursor_pointer()
+                    .child(Label::new(server_state.address.clone()).mr_4())
+                    .tooltip(move |window, cx| {
+                        Tooltip::new(i18n_status_bar(cx, "copy_address_tooltip")).build(window, cx)
+                    })
+                    .on_click(cx.listener(|this, _, window, cx| {
+                        let address = this.state.server_state.address.clone();
+                        cx.write_to_clipboard(ClipboardItem::new_string(address.to_string()));
+                        window.push_notification(
+                            Notification::info(i18n_status_bar(cx, "copied_address_to_clipboard")),
+                            cx,
+                        );
+                    })),
+            )
             .child(
                 div()
                     .child(
                         h_flex()
                             .child(Icon::new(CustomIconName::Network).text_color(cx.theme().primary).mr_1())
+                            // Detected server type: Standalone, Cluster or Sentinel
+                            .when(!server_state.server_type.is_empty(), |this| {
+                                this.child(
+                                    Label::new(server_state.server_type.clone())
+                                        .text_xs()
+                                        .px_1()
+                                        .mr_1()
+                                        .rounded_sm()
+                                        .bg(cx.theme().secondary)
+                                        .text_color(cx.theme().secondary_foreground),
+                                )
+                            })
                             .child(Label::new(server_state.nodes.clone()).mr_4()),
                     )
                     .id("zedis-servers")
@@ -330,6 +489,90 @@ impl ZedisStatusBar {
                     .label(server_state.clients.clone()),
             )
     }
+    /// Render the button to switch between the editor and the pub/sub viewer
+    fn render_pubsub_button(&self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let is_pubsub = cx.global::<ZedisGlobalStore>().read(cx).route() == Route::PubSub;
+        Button::new("zedis-status-bar-pubsub")
+            .ghost()
+            .small()
+            .when(is_pubsub, |this| {
+                this.icon(Icon::new(CustomIconName::Radio).text_color(cx.theme().primary))
+            })
+            .when(!is_pubsub, |this| this.icon(CustomIconName::Radio))
+            .tooltip(i18n_status_bar(cx, "pubsub_tooltip"))
+            .on_click(move |_, _window, cx| {
+                let route = if is_pubsub { Route::Editor } else { Route::PubSub };
+                cx.update_global::<ZedisGlobalStore, ()>(|store, cx| {
+                    store.update(cx, |state, cx| {
+                        state.go_to(route, cx);
+                    });
+                });
+            })
+    }
+    /// Render the button to switch between the editor and the config viewer
+    ///
+    /// Hidden when the CONFIG command is disabled on the server.
+    fn render_config_button(&self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        if self.server_state.read(cx).config().unsupported() {
+            return div().into_any_element();
+        }
+        let is_config = cx.global::<ZedisGlobalStore>().read(cx).route() == Route::Config;
+        Button::new("zedis-status-bar-config")
+            .ghost()
+            .small()
+            .when(is_config, |this| {
+                this.icon(Icon::new(CustomIconName::SlidersHorizontal).text_color(cx.theme().primary))
+            })
+            .when(!is_config, |this| this.icon(CustomIconName::SlidersHorizontal))
+            .tooltip(i18n_status_bar(cx, "config_tooltip"))
+            .on_click(move |_, _window, cx| {
+                let route = if is_config { Route::Editor } else { Route::Config };
+                cx.update_global::<ZedisGlobalStore, ()>(|store, cx| {
+                    store.update(cx, |state, cx| {
+                        state.go_to(route, cx);
+                    });
+                });
+            })
+            .into_any_element()
+    }
+    /// Render the button to switch between the editor and the dashboard
+    fn render_dashboard_button(&self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let is_dashboard = cx.global::<ZedisGlobalStore>().read(cx).route() == Route::Dashboard;
+        Button::new("zedis-status-bar-dashboard")
+            .ghost()
+            .small()
+            .when(is_dashboard, |this| {
+                this.icon(Icon::new(CustomIconName::Gauge).text_color(cx.theme().primary))
+            })
+            .when(!is_dashboard, |this| this.icon(CustomIconName::Gauge))
+            .tooltip(i18n_status_bar(cx, "dashboard_tooltip"))
+            .on_click(move |_, _window, cx| {
+                let route = if is_dashboard { Route::Editor } else { Route::Dashboard };
+                cx.update_global::<ZedisGlobalStore, ()>(|store, cx| {
+                    store.update(cx, |state, cx| {
+                        state.go_to(route, cx);
+                    });
+                });
+            })
+    }
+    /// Render the button to open the audit log of write operations
+    fn render_audit_log_button(&self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let server_state = self.server_state.clone();
+        Button::new("zedis-status-bar-audit-log")
+            .ghost()
+            .small()
+            .icon(CustomIconName::History)
+            .tooltip(i18n_status_bar(cx, "audit_log_tooltip"))
+            .on_click(move |_, window, cx| {
+                let audit_log = cx.new(|cx| ZedisAuditLog::new(server_state.clone(), window, cx));
+                window.open_dialog(cx, move |dialog, _, cx| {
+                    dialog
+                        .title(i18n_status_bar(cx, "audit_log_title"))
+                        .w(px(AUDIT_LOG_DIALOG_WIDTH))
+                        .child(audit_log.clone())
+                });
+            })
+    }
     fn render_editor_settings(&self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         let server_state = &self.state.server_state;
         Button::new("soft-wrap")
@@ -368,6 +611,15 @@ impl ZedisStatusBar {
             .child(Label::new(label).mr_1())
             .child(Select::new(&self.viewer_mode_state).appearance(false))
     }
+    fn render_language(&self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        if self.state.data_format.is_none() {
+            return h_flex();
+        };
+        let label = i18n_status_bar(cx, "language");
+        h_flex()
+            .child(Label::new(label).mr_1())
+            .child(Select::new(&self.language_state).appearance(false))
+    }
     /// Render the error message
     fn render_errors(&self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         let Some(data) = &self.state.error else {
@@ -393,10 +645,20 @@ impl Render for ZedisStatusBar {
         }
         if self.should_reset_viewer_mode {
             self.viewer_mode_state.update(cx, |state, cx| {
-                state.set_selected_index(Some(IndexPath::new(0)), window, cx);
+                state.set_selected_index(Some(IndexPath::new(self.viewer_mode_index)), window, cx);
+            });
+            self.language_state.update(cx, |state, cx| {
+                state.set_selected_index(Some(IndexPath::new(self.language_index)), window, cx);
             });
             self.should_reset_viewer_mode = false;
         }
+        // Use the server color label as the top border accent
+        let accent = self
+            .server_state
+            .read(cx)
+            .server(self.state.server_state.server_id.as_str())
+            .and_then(|server| server.color.as_deref())
+            .and_then(parse_hex_color);
         h_flex()
             .justify_between()
             .text_sm()
@@ -405,11 +667,17 @@ impl Render for ZedisStatusBar {
             .gap_2()
             .border_t_1()
             .border_color(cx.theme().border)
+            .when_some(accent, |this, color| this.border_t_2().border_color(color))
             .text_color(cx.theme().muted_foreground)
             .child(self.render_server_status(window, cx))
+            .child(self.render_dashboard_button(window, cx))
+            .child(self.render_pubsub_button(window, cx))
+            .child(self.render_config_button(window, cx))
+            .child(self.render_audit_log_button(window, cx))
             .child(self.render_editor_settings(window, cx))
             .child(self.render_data_format(window, cx))
             .child(self.render_viewer_mode(window, cx))
+            .child(self.render_language(window, cx))
             .child(self.render_errors(window, cx))
     }
 }
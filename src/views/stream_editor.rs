@@ -0,0 +1,338 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    components::{FormDialog, FormField, ZedisKvFetcher, open_add_form_dialog},
+    helpers::fast_contains_ignore_case,
+    states::{RedisValue, ZedisServerState, i18n_stream_editor},
+    views::{KvTableColumn, ZedisKvTable},
+};
+use gpui::{App, Entity, SharedString, Subscription, Window, div, prelude::*, px};
+use gpui_component::{ActiveTheme, WindowExt, h_flex, label::Label, notification::Notification, v_flex};
+use std::rc::Rc;
+use tracing::info;
+
+const ID_COLUMN_WIDTH: f32 = 200.0;
+const GROUPS_PANEL_WIDTH: f32 = 260.0;
+
+/// Parses a JSON object into the field-value pairs of a stream entry.
+///
+/// String values are added without quotes, the others as compact JSON.
+/// Returns None if the value is not a non-empty JSON object.
+fn parse_stream_fields(value: &str) -> Option<Vec<(SharedString, SharedString)>> {
+    let serde_json::Value::Object(map) = serde_json::from_str(value.trim()).ok()? else {
+        return None;
+    };
+    if map.is_empty() {
+        return None;
+    }
+    let fields = map
+        .into_iter()
+        .map(|(field, value)| {
+            let value = match value {
+                serde_json::Value::String(s) => s,
+                other => other.to_string(),
+            };
+            (field.into(), value.into())
+        })
+        .collect();
+    Some(fields)
+}
+
+/// Data adapter for Redis STREAM values to work with the KV table component.
+///
+/// Entries are readonly, they can only be added (XADD) or removed (XDEL).
+struct ZedisStreamValues {
+    /// Indexes of the entries matching the keyword (None when not filtered)
+    visible_item_indexes: Option<Vec<usize>>,
+    /// Current Redis STREAM value data
+    value: RedisValue,
+    /// Reference to server state for executing Redis operations
+    server_state: Entity<ZedisServerState>,
+}
+
+impl ZedisStreamValues {
+    /// Maps a row of the table to the index of the loaded entry.
+    fn entry_index(&self, row_ix: usize) -> Option<usize> {
+        match &self.visible_item_indexes {
+            Some(indexes) => indexes.get(row_ix).copied(),
+            None => Some(row_ix),
+        }
+    }
+    /// Returns the ID and the fields of the entry shown at the row.
+    fn entry(&self, row_ix: usize) -> Option<&(SharedString, Vec<(SharedString, SharedString)>)> {
+        let index = self.entry_index(row_ix)?;
+        self.value.stream_value()?.values.get(index)
+    }
+}
+
+impl ZedisKvFetcher for ZedisStreamValues {
+    /// Opens a dialog to add a new entry to the STREAM.
+    ///
+    /// The ID defaults to "*" (generated by Redis), the fields are entered as a JSON object.
+    fn handle_add_value(&self, window: &mut Window, cx: &mut App) {
+        let server_state = self.server_state.clone();
+
+        let handle_submit = Rc::new(move |values: Vec<SharedString>, window: &mut Window, cx: &mut App| {
+            if values.len() != 2 {
+                return false;
+            }
+            let Some(fields) = parse_stream_fields(&values[1]) else {
+                window.push_notification(Notification::warning(i18n_stream_editor(cx, "invalid_fields")), cx);
+                return false;
+            };
+
+            server_state.update(cx, |this, cx| {
+                this.add_stream_value(values[0].clone(), fields, cx);
+            });
+
+            window.close_dialog(cx);
+            true
+        });
+
+        let fields = vec![
+            FormField::new(i18n_stream_editor(cx, "id")).with_placeholder(i18n_stream_editor(cx, "id_placeholder")),
+            FormField::new(i18n_stream_editor(cx, "fields"))
+                .with_placeholder(i18n_stream_editor(cx, "fields_placeholder"))
+                .with_focus(),
+        ];
+
+        open_add_form_dialog(
+            FormDialog {
+                title: i18n_stream_editor(cx, "add_value_title"),
+                fields,
+                handle_submit,
+            },
+            window,
+            cx,
+        );
+    }
+
+    /// Returns the length of the STREAM (from Redis XLEN).
+    fn count(&self) -> usize {
+        self.value.stream_value().map_or(0, |v| v.size)
+    }
+
+    /// Creates a new data adapter, applying the keyword filter to the loaded entries.
+    fn new(server_state: Entity<ZedisServerState>, value: RedisValue) -> Self {
+        let visible_item_indexes = value.stream_value().and_then(|stream| {
+            let keyword = stream.keyword.as_ref().filter(|keyword| !keyword.is_empty())?;
+            let keyword = keyword.to_lowercase();
+            let indexes = stream
+                .values
+                .iter()
+                .enumerate()
+                .filter(|(_, (id, fields))| {
+                    fast_contains_ignore_case(id, &keyword)
+                        || fields.iter().any(|(field, value)| {
+                            fast_contains_ignore_case(field, &keyword) || fast_contains_ignore_case(value, &keyword)
+                        })
+                })
+                .map(|(index, _)| index)
+                .collect();
+            Some(indexes)
+        });
+        Self {
+            visible_item_indexes,
+            server_state,
+            value,
+        }
+    }
+
+    /// Retrieves a cell value: the entry ID or its fields as `field=value` pairs.
+    fn get(&self, row_ix: usize, col_ix: usize) -> Option<SharedString> {
+        let (id, fields) = self.entry(row_ix)?;
+        if col_ix == 0 {
+            return Some(id.clone());
+        }
+        let fields = fields
+            .iter()
+            .map(|(field, value)| format!("{field}={value}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        Some(fields.into())
+    }
+
+    /// Returns the number of visible rows, the matching entries when filtered.
+    fn rows_count(&self) -> usize {
+        match &self.visible_item_indexes {
+            Some(indexes) => indexes.len(),
+            None => self.value.stream_value().map_or(0, |v| v.values.len()),
+        }
+    }
+
+    /// Checks if all entries have been loaded.
+    fn is_done(&self) -> bool {
+        self.value.stream_value().is_some_and(|v| v.done)
+    }
+
+    /// Loads the next page of entries with XRANGE.
+    fn load_more(&self, _window: &mut Window, cx: &mut App) {
+        self.server_state.update(cx, |this, cx| {
+            this.load_more_stream_value(cx);
+        });
+    }
+
+    /// Filters the loaded entries by keyword.
+    fn filter(&self, keyword: SharedString, cx: &mut App) {
+        self.server_state.update(cx, |this, cx| {
+            this.filter_stream_value(keyword, cx);
+        });
+    }
+
+    /// Returns the entry ID for the copy field button.
+    fn copy_field(&self, row_ix: usize) -> Option<SharedString> {
+        self.entry(row_ix).map(|(id, _)| id.clone())
+    }
+
+    /// Returns the fields of the entry as a JSON object for the copy value button.
+    fn copy_value(&self, row_ix: usize) -> Option<SharedString> {
+        let (_, fields) = self.entry(row_ix)?;
+        let map: serde_json::Map<String, serde_json::Value> = fields
+            .iter()
+            .map(|(field, value)| (field.to_string(), serde_json::Value::String(value.to_string())))
+            .collect();
+        Some(serde_json::Value::Object(map).to_string().into())
+    }
+
+    /// Removes the entry at the given row with XDEL.
+    fn remove(&self, index: usize, cx: &mut App) {
+        let Some((id, _)) = self.entry(index) else {
+            return;
+        };
+        let id = id.clone();
+        self.server_state.update(cx, |this, cx| {
+            this.remove_stream_value(id, cx);
+        });
+    }
+}
+
+/// Main STREAM editor view component.
+///
+/// Shows the entries in a table and the consumer groups in a side panel.
+pub struct ZedisStreamEditor {
+    /// Reference to server state holding the stream value
+    server_state: Entity<ZedisServerState>,
+    /// The table component that renders the STREAM entries
+    table_state: Entity<ZedisKvTable<ZedisStreamValues>>,
+
+    /// Event subscriptions for reactive updates
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ZedisStreamEditor {
+    /// Creates a new STREAM editor instance with ID and fields columns.
+    pub fn new(server_state: Entity<ZedisServerState>, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let table_state = cx.new(|cx| {
+            ZedisKvTable::<ZedisStreamValues>::new(
+                vec![
+                    KvTableColumn::new("ID", Some(ID_COLUMN_WIDTH)), // Entry ID column (fixed width)
+                    KvTableColumn::new("Fields", None),              // Field-value pairs column (flexible width)
+                ],
+                server_state.clone(),
+                window,
+                cx,
+            )
+        });
+        // Re-render the consumer groups when the value is reloaded
+        let subscriptions = vec![cx.observe(&server_state, |_this, _server_state, cx| cx.notify())];
+
+        info!("Creating new STREAM editor view");
+        Self {
+            server_state,
+            table_state,
+            _subscriptions: subscriptions,
+        }
+    }
+
+    /// Renders the consumer groups from XINFO GROUPS.
+    fn render_groups(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let groups = self
+            .server_state
+            .read(cx)
+            .value()
+            .and_then(|value| value.stream_value())
+            .map(|stream| stream.groups.clone())
+            .unwrap_or_default();
+        let is_empty = groups.is_empty();
+        let muted = cx.theme().muted_foreground;
+        let items = groups.into_iter().enumerate().map(|(index, group)| {
+            v_flex()
+                .id(("stream-group-item", index))
+                .w_full()
+                .py_1()
+                .gap_1()
+                .border_b_1()
+                .border_color(cx.theme().border)
+                .child(Label::new(group.name).text_sm())
+                .child(
+                    Label::new(format!(
+                        "{}: {}  {}: {}",
+                        i18n_stream_editor(cx, "group_consumers"),
+                        group.consumers,
+                        i18n_stream_editor(cx, "group_pending"),
+                        group.pending
+                    ))
+                    .text_xs()
+                    .text_color(muted),
+                )
+                .child(
+                    Label::new(format!(
+                        "{}: {}",
+                        i18n_stream_editor(cx, "group_last_delivered_id"),
+                        group.last_delivered_id
+                    ))
+                    .text_xs()
+                    .text_color(muted),
+                )
+        });
+        v_flex()
+            .id("stream-groups")
+            .w(px(GROUPS_PANEL_WIDTH))
+            .flex_none()
+            .h_full()
+            .p_2()
+            .gap_1()
+            .border_l_1()
+            .border_color(cx.theme().border)
+            .overflow_y_scroll()
+            .child(Label::new(i18n_stream_editor(cx, "groups_title")).text_sm())
+            .when(is_empty, |this| {
+                this.child(
+                    Label::new(i18n_stream_editor(cx, "groups_empty"))
+                        .text_xs()
+                        .text_color(muted),
+                )
+            })
+            .children(items)
+    }
+}
+
+impl Render for ZedisStreamEditor {
+    /// Renders the entries table with the consumer groups panel on the right.
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        h_flex()
+            .size_full()
+            .items_start()
+            .child(
+                div()
+                    .flex_1()
+                    .h_full()
+                    .overflow_hidden()
+                    .child(self.table_state.clone()),
+            )
+            .child(self.render_groups(cx))
+            .into_any_element()
+    }
+}
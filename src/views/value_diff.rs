@@ -0,0 +1,135 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{helpers::get_editor_font_family, states::i18n_editor};
+use gpui::{SharedString, Window, div, prelude::*, px};
+use gpui_component::{ActiveTheme, h_flex, label::Label, v_flex};
+use similar::{ChangeTag, TextDiff};
+use tracing::info;
+
+// Unchanged lines shown around each change
+const CONTEXT_LINES: usize = 3;
+const LIST_MAX_HEIGHT: f32 = 480.0;
+const LINE_NUMBER_WIDTH: f32 = 48.0;
+const SIGN_WIDTH: f32 = 16.0;
+const DIFF_BACKGROUND_ALPHA: f32 = 0.15; // Background alpha for inserted and deleted lines
+
+/// A line of the diff, `None` tag separates the hunks
+struct DiffLine {
+    tag: Option<ChangeTag>,
+    old_index: Option<usize>,
+    new_index: Option<usize>,
+    text: SharedString,
+}
+
+/// Inline diff between the stored value and the edited value
+///
+/// Only the changed lines and a few lines of context are shown,
+/// so large values stay readable.
+pub struct ZedisValueDiff {
+    lines: Vec<DiffLine>,
+    inserted: usize,
+    deleted: usize,
+}
+
+impl ZedisValueDiff {
+    pub fn new(original: &str, modified: &str, _window: &mut Window, _cx: &mut Context<Self>) -> Self {
+        let diff = TextDiff::from_lines(original, modified);
+        let mut lines = Vec::new();
+        let (mut inserted, mut deleted) = (0, 0);
+        for (index, group) in diff.grouped_ops(CONTEXT_LINES).iter().enumerate() {
+            if index > 0 {
+                lines.push(DiffLine {
+                    tag: None,
+                    old_index: None,
+                    new_index: None,
+                    text: "…".into(),
+                });
+            }
+            for op in group {
+                for change in diff.iter_changes(op) {
+                    match change.tag() {
+                        ChangeTag::Insert => inserted += 1,
+                        ChangeTag::Delete => deleted += 1,
+                        ChangeTag::Equal => {}
+                    }
+                    lines.push(DiffLine {
+                        tag: Some(change.tag()),
+                        old_index: change.old_index(),
+                        new_index: change.new_index(),
+                        text: change.value().trim_end_matches(['\r', '\n']).to_string().into(),
+                    });
+                }
+            }
+        }
+
+        info!(inserted, deleted, "Creating new value diff view");
+
+        Self {
+            lines,
+            inserted,
+            deleted,
+        }
+    }
+}
+
+impl Render for ZedisValueDiff {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let line_number = |index: Option<usize>| {
+            div()
+                .w(px(LINE_NUMBER_WIDTH))
+                .flex_none()
+                .text_right()
+                .pr_2()
+                .text_color(cx.theme().muted_foreground)
+                .child(index.map(|index| (index + 1).to_string()).unwrap_or_default())
+        };
+        let rows = self.lines.iter().enumerate().map(|(index, line)| {
+            let (sign, color) = match line.tag {
+                Some(ChangeTag::Insert) => ("+", Some(cx.theme().green)),
+                Some(ChangeTag::Delete) => ("-", Some(cx.theme().red)),
+                _ => (" ", None),
+            };
+            h_flex()
+                .id(("value-diff-line", index))
+                .w_full()
+                .when_some(color, |this, color| {
+                    this.bg(color.alpha(DIFF_BACKGROUND_ALPHA)).text_color(color)
+                })
+                .when(line.tag.is_none(), |this| this.text_color(cx.theme().muted_foreground))
+                .child(line_number(line.old_index))
+                .child(line_number(line.new_index))
+                .child(div().w(px(SIGN_WIDTH)).flex_none().child(sign))
+                .child(div().flex_1().whitespace_nowrap().child(line.text.clone()))
+        });
+        let summary = if self.lines.is_empty() {
+            i18n_editor(cx, "diff_no_changes")
+        } else {
+            format!("+{} -{}", self.inserted, self.deleted).into()
+        };
+        v_flex()
+            .w_full()
+            .gap_2()
+            .child(Label::new(summary).text_sm().text_color(cx.theme().muted_foreground))
+            .child(
+                div()
+                    .id("value-diff-list")
+                    .max_h(px(LIST_MAX_HEIGHT))
+                    .overflow_scroll()
+                    .font_family(get_editor_font_family(cx))
+                    .text_sm()
+                    .children(rows),
+            )
+    }
+}
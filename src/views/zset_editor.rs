@@ -21,17 +21,21 @@
 //! - Updating scores of existing members (inline editing)
 //! - Removing members
 //! - Filtering members with pattern matching
+//! - Switching between ascending and descending score order
 //! - Incremental loading of large ZSETs with pagination
 
 use crate::{
     components::{FormDialog, FormField, ZedisKvFetcher, open_add_form_dialog},
-    states::{RedisValue, ZedisServerState, i18n_common, i18n_zset_editor},
+    states::{RedisValue, SortOrder, ZedisServerState, i18n_common, i18n_zset_editor},
     views::{KvTableColumn, ZedisKvTable},
 };
 use gpui::{App, Entity, SharedString, Window, div, prelude::*};
 use gpui_component::WindowExt;
 use std::rc::Rc;
 
+/// Condition flags offered by the add dialog, GT/LT require Redis 6.2+
+const ZADD_CONDITIONS: [&str; 4] = ["NX", "XX", "GT", "LT"];
+
 /// Data adapter for Redis ZSET values to work with the KV table component.
 ///
 /// This struct implements the `ZedisKvFetcher` trait to provide data access
@@ -132,26 +136,45 @@ impl ZedisKvFetcher for ZedisZsetValues {
         });
     }
 
+    /// Returns the score order of the loaded members.
+    fn sort_order(&self) -> Option<SortOrder> {
+        self.value.zset_value().map(|v| v.sort_order)
+    }
+
+    /// Reloads the members in the opposite score order (ZRANGE/ZREVRANGE).
+    fn toggle_sort_order(&self, cx: &mut App) {
+        self.server_state.update(cx, |this, cx| {
+            this.toggle_zset_sort_order(cx);
+        });
+    }
+
     /// Opens a dialog to add a new member to the ZSET.
     ///
-    /// Creates a form with member and score input fields and handles submission
-    /// by calling the server state's `add_zset_value` method.
+    /// Creates a form with a ZADD condition choice (NX/XX/GT/LT), member and score
+    /// input fields and handles submission by calling the server state's `add_zset_value` method.
     fn handle_add_value(&self, window: &mut Window, cx: &mut App) {
         let server_state = self.server_state.clone();
 
         // Create submission handler that validates and calls Redis ZADD
         let handle_submit = Rc::new(move |values: Vec<SharedString>, window: &mut Window, cx: &mut App| {
-            // Validate that both member and score were provided
-            if values.len() != 2 {
+            // Expect exactly 3 values: [condition_choice, member, score]
+            if values.len() != 3 {
                 return false;
             }
 
+            // Index 0 means a plain ZADD, the others map to the condition flags
+            let condition = values[0]
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| index.checked_sub(1))
+                .and_then(|index| ZADD_CONDITIONS.get(index).copied());
+
             // Parse score from string (default to 0.0 if invalid)
-            let score = values[1].parse::<f64>().unwrap_or(0.0);
+            let score = values[2].parse::<f64>().unwrap_or(0.0);
 
             // Execute the add operation on server state
             server_state.update(cx, |this, cx| {
-                this.add_zset_value(values[0].clone(), score, cx);
+                this.add_zset_value(values[1].clone(), score, condition, cx);
             });
 
             // Close the dialog on successful submission
@@ -159,8 +182,11 @@ impl ZedisKvFetcher for ZedisZsetValues {
             true
         });
 
-        // Build form with member and score input fields
+        // Build form with condition choice, member and score input fields
+        let mut conditions: Vec<SharedString> = vec![i18n_zset_editor(cx, "condition_none")];
+        conditions.extend(ZADD_CONDITIONS.iter().map(|flag| SharedString::from(*flag)));
         let fields = vec![
+            FormField::new(i18n_zset_editor(cx, "condition")).with_options(conditions),
             FormField::new(i18n_common(cx, "value"))
                 .with_placeholder(i18n_common(cx, "value_placeholder"))
                 .with_focus(),